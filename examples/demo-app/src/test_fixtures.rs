@@ -10,14 +10,17 @@
 //! - the `on_change` callback firing exactly once per programmatic content replacement.
 //! - JSON initialization, JSON replacement, and generic attribute commands.
 //! - a persistent handle reused after destroy or create failure.
+//! - two editors bound to Yjs documents, exchanging updates directly.
+//! - an editor submitted, validated, and reset with a surrounding form.
 //!
 //! The main `DemoApp` deliberately stays unrelated to any test.
 
 use leptos::prelude::*;
 use leptos::serde_json;
+use leptos::wasm_bindgen::JsCast;
 use leptos_tiptap::{
-    TiptapAttributes, TiptapContent, TiptapEditor, TiptapEditorError, TiptapEditorHandle,
-    TiptapEditorReport, TiptapExtension, TiptapHeadingLevel, TiptapMarkName,
+    TiptapAttributes, TiptapCollaborationConfig, TiptapContent, TiptapEditor, TiptapEditorError,
+    TiptapEditorHandle, TiptapEditorReport, TiptapExtension, TiptapHeadingLevel, TiptapMarkName,
     UseTiptapEditorInput, use_tiptap_editor,
 };
 
@@ -482,3 +485,141 @@ pub fn RetryHandleFixture() -> impl IntoView {
         </section>
     }
 }
+
+/// Sends everything `from` has and `to` is missing, as determined by the state vector of `to`.
+fn sync_collaboration_documents(
+    from: TiptapEditorHandle,
+    to: TiptapEditorHandle,
+) -> Result<(), TiptapEditorReport> {
+    let update = from.encode_state_as_update(Some(&to.state_vector()?))?;
+    to.apply_update(&update)
+}
+
+#[component]
+pub fn CollaborationFixture() -> impl IntoView {
+    let handle_a = TiptapEditorHandle::new();
+    let handle_b = TiptapEditorHandle::new();
+    // Yjs brings its own undo manager, which conflicts with the history extension.
+    let extensions = || {
+        TiptapExtension::all_enabled()
+            .into_iter()
+            .filter(|extension| *extension != TiptapExtension::History)
+            .collect::<Vec<_>>()
+    };
+    // Whichever editor becomes ready last catches up with the other one.
+    let on_ready = move |()| {
+        if handle_a.is_ready() && handle_b.is_ready() {
+            let _ = sync_collaboration_documents(handle_a, handle_b);
+            let _ = sync_collaboration_documents(handle_b, handle_a);
+        }
+    };
+    let forward_to = move |handle: TiptapEditorHandle| {
+        move |update: Vec<u8>| {
+            if handle.is_ready() {
+                let _ = handle.apply_update(&update);
+            }
+        }
+    };
+
+    view! {
+        <section id="collaboration">
+            <h2>"Collaboration"</h2>
+
+            <button
+                disabled=move || !handle_a.is_ready()
+                on:click=move |_| {
+                    let _ = handle_a.insert_content(TiptapContent::html("Hello from A."), None);
+                }
+            >
+                "Write A"
+            </button>
+            <button
+                disabled=move || !handle_b.is_ready()
+                on:click=move |_| {
+                    let _ = handle_b.insert_content(TiptapContent::html("Reply from B."), None);
+                }
+            >
+                "Write B"
+            </button>
+
+            <TiptapEditor
+                handle=handle_a
+                id="collaboration-a"
+                initial_content=TiptapContent::default()
+                extensions=extensions()
+                collaboration=TiptapCollaborationConfig::default()
+                on_ready=on_ready
+                on_local_update=forward_to(handle_b)
+            />
+            <TiptapEditor
+                handle=handle_b
+                id="collaboration-b"
+                initial_content=TiptapContent::default()
+                extensions=extensions()
+                collaboration=TiptapCollaborationConfig::default()
+                on_ready=on_ready
+                on_local_update=forward_to(handle_a)
+            />
+        </section>
+    }
+}
+
+#[component]
+pub fn FormFixture() -> impl IntoView {
+    let handle = TiptapEditorHandle::new();
+    let form = NodeRef::<leptos::html::Form>::new();
+    let submitted = RwSignal::new(String::new());
+    let validity = RwSignal::new(String::new());
+
+    view! {
+        <section id="form">
+            <h2>"Form"</h2>
+
+            <form
+                node_ref=form
+                on:submit=move |ev| {
+                    ev.prevent_default();
+                    let value = document()
+                        .query_selector("#form textarea[name='body']")
+                        .ok()
+                        .flatten()
+                        .map(|textarea| {
+                            textarea.unchecked_into::<leptos::web_sys::HtmlTextAreaElement>().value()
+                        })
+                        .unwrap_or_default();
+                    submitted.set(value);
+                }
+            >
+                <TiptapEditor
+                    handle=handle
+                    id="form-editor"
+                    initial_content=TiptapContent::html("<p>Initial form content.</p>")
+                    name="body"
+                    required=true
+                />
+
+                <button
+                    type="button"
+                    disabled=move || !handle.is_ready()
+                    on:click=move |_| { let _ = handle.clear_content(true); }
+                >
+                    "Clear"
+                </button>
+                <button
+                    type="button"
+                    on:click=move |_| {
+                        let valid = form.get().is_some_and(|form| form.check_validity());
+                        validity.set(if valid { "valid" } else { "invalid" }.to_owned());
+                    }
+                >
+                    "Check validity"
+                </button>
+                <button type="submit">"Submit"</button>
+                <button type="reset">"Reset"</button>
+            </form>
+
+            <pre id="form-submitted">{move || submitted.get()}</pre>
+            <pre id="form-validity">{move || validity.get()}</pre>
+        </section>
+    }
+}
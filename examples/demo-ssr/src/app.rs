@@ -1,7 +1,8 @@
 use demo_app::test_fixtures::{
-    DuplicateEditorIdFixture, ExtensionSubsetFixture, JsonBridgeFixture, MultiEditorFixture,
-    OnChangeCountingFixture, OnErrorFixture, PlaceholderFixture, RemountHandleFixture,
-    RetryHandleFixture,
+    CollaborationFixture, DuplicateEditorIdFixture, ExtensionSubsetFixture, FormFixture,
+    JsonBridgeFixture,
+    MultiEditorFixture, OnChangeCountingFixture, OnErrorFixture, PlaceholderFixture,
+    RemountHandleFixture, RetryHandleFixture,
 };
 use demo_app::DemoApp;
 use leptos::prelude::*;
@@ -50,6 +51,8 @@ pub fn App() -> impl IntoView {
                     <Route path=StaticSegment("json-bridge") view=JsonBridgeFixture/>
                     <Route path=StaticSegment("remount-handle") view=RemountHandleFixture/>
                     <Route path=StaticSegment("retry-handle") view=RetryHandleFixture/>
+                    <Route path=StaticSegment("collaboration") view=CollaborationFixture/>
+                    <Route path=StaticSegment("form") view=FormFixture/>
                 </Routes>
             </main>
         </Router>
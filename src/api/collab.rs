@@ -0,0 +1,333 @@
+use crate::protocol::{DocumentRequest, DocumentResponse};
+use crate::runtime;
+
+use super::{
+    TiptapCollabSteps, TiptapCollabUpdate, TiptapEditorError, TiptapEditorHandle,
+    TiptapEditorInstance, TiptapEditorResult,
+};
+
+impl TiptapEditorInstance {
+    /// Returns the authority version of the last steps this client received.
+    ///
+    /// Use it to ask the central authority for the steps made since.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor was created without a collaboration configuration, or
+    /// when the JS bridge rejects the request.
+    pub fn collab_version(&self) -> TiptapEditorResult<u64> {
+        self.collab_state().map(|(version, _)| version)
+    }
+
+    /// Returns the local steps that have not been confirmed by the central authority yet, or
+    /// `None` when there is nothing to send.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor was created without a collaboration configuration, or
+    /// when the JS bridge rejects the request.
+    pub fn sendable_steps(&self) -> TiptapEditorResult<Option<TiptapCollabSteps>> {
+        self.collab_state().map(|(_, sendable)| sendable)
+    }
+
+    /// Applies steps confirmed by the central authority.
+    ///
+    /// Steps made by this client confirm the matching unconfirmed local steps. All other steps
+    /// are applied to the document, rebasing the remaining unconfirmed local steps on top of them.
+    /// `client_ids` must hold the id of the client that made each step, in the same order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor was created without a collaboration configuration, when
+    /// the steps do not apply to the current document, or when the JS bridge rejects the request.
+    pub fn receive_transaction(
+        &self,
+        steps: Vec<serde_json::Value>,
+        client_ids: Vec<String>,
+    ) -> TiptapEditorResult<()> {
+        match runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::ReceiveTransaction { steps, client_ids },
+        )? {
+            DocumentResponse::Empty => Ok(()),
+            _ => Err(TiptapEditorError::BridgeError(
+                "received an unexpected response for a receive_transaction request".to_owned(),
+            )
+            .into()),
+        }
+    }
+
+    fn collab_state(&self) -> TiptapEditorResult<(u64, Option<TiptapCollabSteps>)> {
+        match runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::GetCollabState,
+        )? {
+            DocumentResponse::CollabState { version, sendable } => Ok((version, sendable)),
+            _ => Err(TiptapEditorError::BridgeError(
+                "received an unexpected response for a collaboration state request".to_owned(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Returns the authority version of the last steps this client received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn collab_version(&self) -> TiptapEditorResult<u64> {
+        self.with_instance(TiptapEditorInstance::collab_version)
+    }
+
+    /// Returns the local steps that have not been confirmed by the central authority yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn sendable_steps(&self) -> TiptapEditorResult<Option<TiptapCollabSteps>> {
+        self.with_instance(TiptapEditorInstance::sendable_steps)
+    }
+
+    /// Applies steps confirmed by the central authority.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn receive_transaction(
+        &self,
+        steps: Vec<serde_json::Value>,
+        client_ids: Vec<String>,
+    ) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.receive_transaction(steps, client_ids))
+    }
+}
+
+/// The central authority of a `prosemirror-collab` session.
+///
+/// The authority is a versioned log of confirmed steps. It does not need a `ProseMirror` schema,
+/// so it runs in any Rust server, for example behind an Axum route, or in-process in tests. It
+/// accepts submitted steps only when they were made against its current version, and tells
+/// lagging clients which steps they are missing.
+///
+/// Steps are stored as opaque JSON. Persist the document separately, for example from the
+/// `on_document_change` stream of one client, together with the authority version it belongs to.
+///
+/// # Example
+///
+/// ```
+/// use leptos_tiptap::{TiptapCollabAuthority, TiptapCollabSteps, TiptapEditorError};
+/// use serde_json::json;
+///
+/// let mut authority = TiptapCollabAuthority::new();
+/// let step = json!({"stepType": "replace", "from": 1, "to": 1});
+///
+/// let submit = |version| TiptapCollabSteps {
+///     version,
+///     steps: vec![step.clone()],
+///     client_id: "alice".to_owned(),
+/// };
+/// assert_eq!(authority.receive_steps(submit(0)).unwrap(), 1);
+///
+/// // A second submission against version 0 is stale.
+/// let err = authority.receive_steps(submit(0)).unwrap_err();
+/// assert_eq!(
+///     err.current_context(),
+///     &TiptapEditorError::CollabVersionConflict { current: 1, received: 0 },
+/// );
+///
+/// let update = authority.steps_since(0).unwrap();
+/// assert_eq!(update.version, 1);
+/// assert_eq!(update.client_ids, vec!["alice".to_owned()]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TiptapCollabAuthority {
+    first_version: u64,
+    steps: Vec<serde_json::Value>,
+    client_ids: Vec<String>,
+}
+
+impl TiptapCollabAuthority {
+    /// Creates an authority for a new document at version `0`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an authority for a persisted document at `version`, with an empty step log.
+    ///
+    /// Clients must be created with the persisted document and the same version.
+    #[must_use]
+    pub fn starting_at(version: u64) -> Self {
+        Self {
+            first_version: version,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the current version, which is the number of steps confirmed since version `0`.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.first_version + self.steps.len() as u64
+    }
+
+    /// Appends the submitted steps to the log and returns the new version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TiptapEditorError::CollabVersionConflict`] and leaves the log untouched when the
+    /// steps were not made against the current version.
+    pub fn receive_steps(&mut self, submitted: TiptapCollabSteps) -> TiptapEditorResult<u64> {
+        let current = self.version();
+        if submitted.version != current {
+            return Err(TiptapEditorError::CollabVersionConflict {
+                current,
+                received: submitted.version,
+            }
+            .into());
+        }
+
+        self.client_ids.extend(std::iter::repeat_n(
+            submitted.client_id,
+            submitted.steps.len(),
+        ));
+        self.steps.extend(submitted.steps);
+        Ok(self.version())
+    }
+
+    /// Returns the steps confirmed after `version`, which may be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TiptapEditorError::CollabStepsUnavailable`] when `version` is newer than the
+    /// current version or older than the first retained step.
+    pub fn steps_since(&self, version: u64) -> TiptapEditorResult<TiptapCollabUpdate> {
+        let offset = version
+            .checked_sub(self.first_version)
+            .and_then(|offset| usize::try_from(offset).ok())
+            .filter(|offset| *offset <= self.steps.len())
+            .ok_or(TiptapEditorError::CollabStepsUnavailable(version))?;
+
+        Ok(TiptapCollabUpdate {
+            version: self.version(),
+            steps: self.steps[offset..].to_vec(),
+            client_ids: self.client_ids[offset..].to_vec(),
+        })
+    }
+
+    /// Drops the steps confirmed up to `version` to bound the size of the log.
+    ///
+    /// Afterwards, clients older than `version` can no longer catch up and must be recreated from
+    /// a persisted document. Versions outside the retained log are clamped.
+    pub fn discard_steps_before(&mut self, version: u64) {
+        let count = version
+            .saturating_sub(self.first_version)
+            .min(self.steps.len() as u64);
+        let count = usize::try_from(count).unwrap_or(self.steps.len());
+
+        self.steps.drain(..count);
+        self.client_ids.drain(..count);
+        self.first_version += count as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    fn submit(version: u64, client_id: &str, texts: &[&str]) -> TiptapCollabSteps {
+        TiptapCollabSteps {
+            version,
+            steps: texts
+                .iter()
+                .map(|text| json!({"stepType": "replace", "text": text}))
+                .collect(),
+            client_id: client_id.to_owned(),
+        }
+    }
+
+    #[test]
+    fn accepts_steps_made_against_the_current_version() {
+        let mut authority = TiptapCollabAuthority::new();
+
+        assert_that!(
+            authority
+                .receive_steps(submit(0, "alice", &["a", "b"]))
+                .unwrap()
+        )
+        .is_equal_to(2);
+        assert_that!(authority.receive_steps(submit(2, "bob", &["c"])).unwrap()).is_equal_to(3);
+
+        let update = authority.steps_since(1).unwrap();
+        assert_that!(update.version).is_equal_to(3);
+        assert_that!(update.steps).is_equal_to(vec![
+            json!({"stepType": "replace", "text": "b"}),
+            json!({"stepType": "replace", "text": "c"}),
+        ]);
+        assert_that!(update.client_ids).is_equal_to(vec!["alice".to_owned(), "bob".to_owned()]);
+    }
+
+    #[test]
+    fn rejects_stale_steps_until_the_client_caught_up() {
+        let mut authority = TiptapCollabAuthority::new();
+        authority.receive_steps(submit(0, "alice", &["a"])).unwrap();
+
+        let err = authority
+            .receive_steps(submit(0, "bob", &["b"]))
+            .unwrap_err();
+        assert_that!(err.into_current_context()).is_equal_to(
+            TiptapEditorError::CollabVersionConflict {
+                current: 1,
+                received: 0,
+            },
+        );
+        assert_that!(authority.version()).is_equal_to(1);
+
+        // Bob applies the missing step, rebases his own step, and resubmits.
+        let missing = authority.steps_since(0).unwrap();
+        assert_that!(
+            authority
+                .receive_steps(submit(missing.version, "bob", &["b"]))
+                .unwrap()
+        )
+        .is_equal_to(2);
+    }
+
+    #[test]
+    fn reports_versions_outside_the_retained_log() {
+        let mut authority = TiptapCollabAuthority::starting_at(10);
+        authority
+            .receive_steps(submit(10, "alice", &["a", "b", "c"]))
+            .unwrap();
+
+        assert_that!(authority.steps_since(13).unwrap().steps).is_empty();
+        assert_that!(
+            authority
+                .steps_since(14)
+                .unwrap_err()
+                .into_current_context()
+        )
+        .is_equal_to(TiptapEditorError::CollabStepsUnavailable(14));
+
+        authority.discard_steps_before(12);
+
+        assert_that!(authority.version()).is_equal_to(13);
+        assert_that!(
+            authority
+                .steps_since(11)
+                .unwrap_err()
+                .into_current_context()
+        )
+        .is_equal_to(TiptapEditorError::CollabStepsUnavailable(11));
+        assert_that!(authority.steps_since(12).unwrap().steps)
+            .is_equal_to(vec![json!({"stepType": "replace", "text": "c"})]);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::protocol::{DocumentRequest, DocumentResponse};
+use crate::runtime;
+
+use super::{TiptapEditorError, TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult};
+
+impl TiptapEditorInstance {
+    /// Applies a Yjs update (v1 encoding) received from another client or from the server.
+    ///
+    /// Updates can be applied in any order and more than once. Applied updates are not reported
+    /// through `on_local_update`, so they are never echoed back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor is not bound to a collaboration document, when the update
+    /// can not be decoded, or when the JS bridge rejects the request.
+    pub fn apply_update(&self, update: &[u8]) -> TiptapEditorResult<()> {
+        match runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::ApplyCollaborationUpdate {
+                update: update.to_vec(),
+            },
+        )? {
+            DocumentResponse::Empty => Ok(()),
+            _ => Err(TiptapEditorError::BridgeError(
+                "received an unexpected response for an apply_collaboration_update request"
+                    .to_owned(),
+            )
+            .into()),
+        }
+    }
+
+    /// Returns the state vector of the collaboration document.
+    ///
+    /// Send it to a peer or to the server to receive exactly the updates this client is missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor is not bound to a collaboration document, or when the JS
+    /// bridge rejects the request.
+    pub fn state_vector(&self) -> TiptapEditorResult<Vec<u8>> {
+        match runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::GetCollaborationStateVector,
+        )? {
+            DocumentResponse::CollaborationStateVector { state_vector } => Ok(state_vector),
+            _ => Err(TiptapEditorError::BridgeError(
+                "received an unexpected response for a collaboration state vector request"
+                    .to_owned(),
+            )
+            .into()),
+        }
+    }
+
+    /// Encodes the collaboration document as a single Yjs update (v1 encoding).
+    ///
+    /// With a remote `state_vector`, only the changes the remote side is missing are encoded.
+    /// Without one, the update holds the full document, for example to persist it in `yrs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor is not bound to a collaboration document, when the state
+    /// vector can not be decoded, or when the JS bridge rejects the request.
+    pub fn encode_state_as_update(
+        &self,
+        state_vector: Option<&[u8]>,
+    ) -> TiptapEditorResult<Vec<u8>> {
+        match runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::EncodeCollaborationState {
+                state_vector: state_vector.map(<[u8]>::to_vec),
+            },
+        )? {
+            DocumentResponse::CollaborationUpdate { update } => Ok(update),
+            _ => Err(TiptapEditorError::BridgeError(
+                "received an unexpected response for an encode_collaboration_state request"
+                    .to_owned(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Applies a Yjs update (v1 encoding) received from another client or from the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn apply_update(&self, update: &[u8]) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.apply_update(update))
+    }
+
+    /// Returns the state vector of the collaboration document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn state_vector(&self) -> TiptapEditorResult<Vec<u8>> {
+        self.with_instance(TiptapEditorInstance::state_vector)
+    }
+
+    /// Encodes the collaboration document, or the part a remote `state_vector` is missing, as a
+    /// single Yjs update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn encode_state_as_update(
+        &self,
+        state_vector: Option<&[u8]>,
+    ) -> TiptapEditorResult<Vec<u8>> {
+        self.with_instance(|instance| instance.encode_state_as_update(state_vector))
+    }
+}
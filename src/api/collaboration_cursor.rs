@@ -0,0 +1,30 @@
+use crate::protocol::EditorCommand;
+
+use super::{TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult, TiptapRemoteCursor};
+
+impl TiptapEditorInstance {
+    /// Replaces the rendered cursors and selections of remote users.
+    ///
+    /// Each cursor renders a caret with a name label at its head and highlights its selection.
+    /// Until the next call, the decorations follow local document changes. Pass an empty list to
+    /// remove all remote cursors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale or when the JS bridge rejects the request.
+    pub fn set_remote_cursors(&self, cursors: Vec<TiptapRemoteCursor>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SetRemoteCursors { cursors })
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Replaces the rendered cursors and selections of remote users.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn set_remote_cursors(&self, cursors: Vec<TiptapRemoteCursor>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.set_remote_cursors(cursors))
+    }
+}
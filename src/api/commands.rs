@@ -167,6 +167,10 @@ command_methods!(
     undo() => Undo,
     #[cfg(feature = "history")]
     redo() => Redo,
+    /// Clears the undo and redo history, for example after loading a saved document with
+    /// `set_content`.
+    #[cfg(feature = "history")]
+    clear_history() => ClearHistory,
     #[cfg(feature = "strike")]
     set_strike() => SetStrike,
     #[cfg(feature = "strike")]
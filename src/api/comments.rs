@@ -0,0 +1,105 @@
+use crate::protocol::EditorCommand;
+
+use super::{TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult};
+
+impl TiptapEditorInstance {
+    /// Anchors the comment thread `thread_id` to the selected text.
+    ///
+    /// The text is marked with the comment mark, rendered as a `span.comment` element with a
+    /// `data-thread-id` attribute. Comments may overlap, and a thread may be anchored to several
+    /// ranges by adding it again. Use [`TiptapEditorHandle::comments`] to list the threads with
+    /// their current ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when the selection is empty, when the
+    /// `comments` extension is not active, or when the JS bridge rejects the request.
+    pub fn add_comment(&self, thread_id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::AddComment {
+            thread_id: thread_id.into(),
+        })
+    }
+
+    /// Removes the comment thread `thread_id` from all of its anchored text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when there is no thread `thread_id`, or
+    /// when the JS bridge rejects the request.
+    pub fn remove_comment(&self, thread_id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::RemoveComment {
+            thread_id: thread_id.into(),
+        })
+    }
+
+    /// Marks the comment thread `thread_id` as resolved.
+    ///
+    /// The thread keeps its anchors, which additionally get the `comment-resolved` class.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when there is no thread `thread_id`, or
+    /// when the JS bridge rejects the request.
+    pub fn resolve_comment(&self, thread_id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SetCommentResolved {
+            thread_id: thread_id.into(),
+            resolved: true,
+        })
+    }
+
+    /// Reopens the resolved comment thread `thread_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when there is no thread `thread_id`, or
+    /// when the JS bridge rejects the request.
+    pub fn reopen_comment(&self, thread_id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SetCommentResolved {
+            thread_id: thread_id.into(),
+            resolved: false,
+        })
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Anchors the comment thread `thread_id` to the selected text, see
+    /// [`TiptapEditorInstance::add_comment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn add_comment(&self, thread_id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.add_comment(thread_id))
+    }
+
+    /// Removes the comment thread `thread_id` from all of its anchored text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn remove_comment(&self, thread_id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.remove_comment(thread_id))
+    }
+
+    /// Marks the comment thread `thread_id` as resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn resolve_comment(&self, thread_id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.resolve_comment(thread_id))
+    }
+
+    /// Reopens the resolved comment thread `thread_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn reopen_comment(&self, thread_id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.reopen_comment(thread_id))
+    }
+}
@@ -1,5 +1,12 @@
+use super::{TiptapCollabConfig, TiptapCollabSteps};
+use super::{TiptapCollaborationConfig, TiptapSelectionRange};
 use super::{
-    TiptapContent, TiptapEditorHandle, TiptapEditorReport, TiptapExtension, TiptapSelectionState,
+    TiptapContent, TiptapCustomExtension, TiptapDecorationClick, TiptapDocumentChange,
+    TiptapDocumentChangeFormat, TiptapDraftConfig, TiptapDropEvent, TiptapEditorHandle,
+    TiptapEditorReport, TiptapExtension, TiptapFormFormat, TiptapImageUpload, TiptapInputRule,
+    TiptapMarkSpec, TiptapNodeSpec, TiptapNodeView, TiptapPasteEvent, TiptapPasteRule,
+    TiptapSelectionState, TiptapShortcuts, TiptapTransaction, TiptapTransferAction,
+    form::{TiptapFormField, form_value},
     use_tiptap_editor::{UseTiptapEditorInput, use_tiptap_editor},
 };
 use leptos::prelude::*;
@@ -114,6 +121,54 @@ pub fn TiptapEditor(
     #[prop(into, optional)]
     extensions: Option<Vec<TiptapExtension>>,
 
+    /// Extensions supplied by the application as JS modules, activated in addition to
+    /// `extensions`.
+    ///
+    /// Their commands are run through `TiptapEditorHandle::custom_command`.
+    /// This is one-time initialization input.
+    #[prop(into, optional)]
+    custom_extensions: Vec<TiptapCustomExtension>,
+
+    /// Custom nodes declared in Rust, added to the schema of the editor.
+    ///
+    /// Their commands are run through `TiptapEditorHandle::set_spec` and
+    /// `TiptapEditorHandle::toggle_spec`.
+    /// This is one-time initialization input.
+    #[prop(into, optional)]
+    node_specs: Vec<TiptapNodeSpec>,
+
+    /// Custom marks declared in Rust, added to the schema of the editor.
+    ///
+    /// Their commands are run through `TiptapEditorHandle::set_spec`,
+    /// `TiptapEditorHandle::toggle_spec`, and `TiptapEditorHandle::unset_spec`.
+    /// This is one-time initialization input.
+    #[prop(into, optional)]
+    mark_specs: Vec<TiptapMarkSpec>,
+
+    /// Leptos components rendering the nodes of `node_specs`.
+    ///
+    /// This is one-time initialization input.
+    #[prop(into, optional)]
+    node_views: Vec<TiptapNodeView>,
+
+    /// Keyboard shortcuts handled by Rust callbacks, such as `Mod-s` to save.
+    ///
+    /// This is one-time initialization input.
+    #[prop(into, optional)]
+    shortcuts: Option<TiptapShortcuts>,
+
+    /// Rules formatting text while it is typed, such as replacing `-->` with an arrow.
+    ///
+    /// This is one-time initialization input.
+    #[prop(into, optional)]
+    input_rules: Vec<TiptapInputRule>,
+
+    /// Rules formatting pasted text.
+    ///
+    /// This is one-time initialization input.
+    #[prop(into, optional)]
+    paste_rules: Vec<TiptapPasteRule>,
+
     /// Placeholder text used by the Tiptap placeholder extension during editor initialization.
     ///
     /// This is one-time initialization input and only has an effect when the placeholder extension
@@ -135,7 +190,136 @@ pub fn TiptapEditor(
     /// distinct.
     #[prop(into, optional)]
     on_selection_change: Option<Callback<TiptapSelectionState>>,
+
+    /// Called when the editor gains focus, for example to show a toolbar while editing.
+    #[prop(into, optional)]
+    on_focus: Option<Callback<()>>,
+
+    /// Called when the editor loses focus, for example to validate its content.
+    #[prop(into, optional)]
+    on_blur: Option<Callback<()>>,
+
+    /// Called with every transaction applied to the editor, for example to tell user edits apart
+    /// from programmatic ones through its origin.
+    #[prop(into, optional)]
+    on_transaction: Option<Callback<TiptapTransaction>>,
+
+    /// Called with every entry of the incremental document change stream, which is only enabled
+    /// when this callback is set.
+    ///
+    /// The first entry is a version `0` snapshot emitted when the editor becomes ready. Every
+    /// later entry carries the next version and only the difference to the previous one, so large
+    /// documents can be synchronized without shipping the full JSON on every change. Use
+    /// [`TiptapDocumentMirror`](crate::TiptapDocumentMirror) to maintain a copy of the document
+    /// and to detect missed versions.
+    #[prop(into, optional)]
+    on_document_change: Option<Callback<TiptapDocumentChange>>,
+
+    /// Wire format of the entries passed to `on_document_change`. Defaults to JSON patches.
+    ///
+    /// This is one-time initialization input.
+    #[prop(optional)]
+    document_change_format: TiptapDocumentChangeFormat,
+
+    /// Enables `prosemirror-collab` collaboration through a central authority, such as a
+    /// `TiptapCollabAuthority` on your server.
+    ///
+    /// This is one-time initialization input. `initial_content` must be the authority's document at
+    /// the configured version. Requires the `collab` feature, without which the editor fails to
+    /// mount.
+    #[prop(into, optional)]
+    collab: Option<TiptapCollabConfig>,
+
+    /// Called when local steps become sendable to the central authority, and again whenever the
+    /// unconfirmed steps change.
+    #[prop(into, optional)]
+    on_collab_sendable: Option<Callback<TiptapCollabSteps>>,
+
+    /// Binds the editor to a Yjs document through the collaboration extension.
+    ///
+    /// This is one-time initialization input. The collaboration extension must be active, and
+    /// `initial_content` is ignored in favor of the configured initial update. Requires the
+    /// `collaboration` feature, without which the editor fails to mount.
+    #[prop(into, optional)]
+    collaboration: Option<TiptapCollaborationConfig>,
+
+    /// Called with every Yjs update (v1 encoding) caused by this editor, to be forwarded to the
+    /// other clients or to a `yrs` document on your server.
+    #[prop(into, optional)]
+    on_local_update: Option<Callback<Vec<u8>>>,
+
+    /// Called with the local selection whenever its anchor or head moves, to be rendered by the
+    /// other clients through `TiptapEditorHandle::set_remote_cursors`.
+    #[prop(into, optional)]
+    on_local_selection: Option<Callback<TiptapSelectionRange>>,
+
+    /// Called when a decoration set through `TiptapEditorHandle::set_decorations` is clicked.
+    #[prop(into, optional)]
+    on_decoration_click: Option<Callback<TiptapDecorationClick>>,
+
+    /// Called with the threads of the comments at the cursor whenever the cursor enters or leaves
+    /// a commented range, with an empty list once it left all of them.
+    ///
+    /// This only has an effect when the `comments` extension is active.
+    #[prop(into, optional)]
+    on_active_comments: Option<Callback<Vec<String>>>,
+
+    /// Called with the clipboard content before it is pasted, returning whether Tiptap pastes
+    /// it, the paste is cancelled, or other content is inserted instead.
+    #[prop(into, optional)]
+    on_paste: Option<Callback<TiptapPasteEvent, TiptapTransferAction>>,
+
+    /// Called with the dropped content and its target position before it is inserted, returning
+    /// whether Tiptap inserts it, the drop is cancelled, or other content is inserted instead.
+    #[prop(into, optional)]
+    on_drop: Option<Callback<TiptapDropEvent, TiptapTransferAction>>,
+
+    /// Uploads pasted and dropped images, showing a placeholder with their progress until they
+    /// are inserted as image nodes. Needs the image extension.
+    #[prop(optional)]
+    image_upload: Option<TiptapImageUpload>,
+
+    /// Periodically saves the document and selection as a draft, keyed by `id`, which can be
+    /// recovered through `TiptapEditorHandle::restore_draft`.
+    ///
+    /// This is one-time initialization input.
+    #[prop(into, optional)]
+    drafts: Option<TiptapDraftConfig>,
+
+    /// Name under which the document is submitted with the surrounding `<form>`.
+    ///
+    /// When set, a visually hidden textarea next to the editor holds the document in
+    /// `form_format`, validates `required`, `min_length`, and `max_length` through the constraint
+    /// validation API, and restores `initial_content` when the form is reset.
+    #[prop(into, optional)]
+    name: Option<String>,
+
+    /// Serialization of the submitted document. Defaults to HTML.
+    #[prop(optional)]
+    form_format: TiptapFormFormat,
+
+    /// Rejects form submission while the document has no text. Only has an effect with `name`.
+    #[prop(into, optional)]
+    required: Signal<bool>,
+
+    /// Minimum number of text characters, excluding line breaks, for form submission. Empty
+    /// documents are only rejected when `required` is set. Only has an effect with `name`.
+    #[prop(into, optional)]
+    min_length: Option<usize>,
+
+    /// Maximum number of text characters, excluding line breaks, for form submission. Only has
+    /// an effect with `name`.
+    #[prop(into, optional)]
+    max_length: Option<usize>,
 ) -> impl IntoView {
+    let form_field = name.map(|name| TiptapFormField {
+        name,
+        format: form_format,
+        required,
+        min_length,
+        max_length,
+        initial_content: initial_content.clone(),
+    });
     let hook = use_tiptap_editor(UseTiptapEditorInput {
         id,
         handle: Some(handle),
@@ -143,10 +327,33 @@ pub fn TiptapEditor(
         on_ready,
         on_change,
         on_selection_change,
+        on_focus,
+        on_blur,
+        on_transaction,
+        on_document_change,
+        document_change_format,
         on_error,
         disabled,
         extensions,
+        custom_extensions,
+        node_specs,
+        mark_specs,
+        node_views,
+        shortcuts,
+        input_rules,
+        paste_rules,
         placeholder,
+        collab,
+        on_collab_sendable,
+        collaboration,
+        on_local_update,
+        on_local_selection,
+        on_decoration_click,
+        on_active_comments,
+        on_paste,
+        on_drop,
+        image_upload,
+        drafts,
     });
     let classes = Classes::from("leptos-tiptap-instance").merge(classes, MergeStrategy::KeepSelf);
 
@@ -156,5 +363,6 @@ pub fn TiptapEditor(
             style={styles}
             {..hook.props.into_attrs()}
         ></div>
+        {form_field.map(|field| form_value(handle, field, on_error))}
     }
 }
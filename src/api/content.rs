@@ -39,6 +39,21 @@ impl TiptapEditorInstance {
         )?)
     }
 
+    /// Returns the text content of the current editor document, with blocks separated by blank
+    /// lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the JS bridge rejects the document request or
+    /// returns a response in an unexpected format.
+    pub fn get_text(&self) -> TiptapEditorResult<String> {
+        extract_text_content(runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::GetText,
+        )?)
+    }
+
     /// Replaces the current editor document content.
     ///
     /// # Errors
@@ -111,6 +126,17 @@ impl TiptapEditorHandle {
         self.with_instance(TiptapEditorInstance::get_json)
     }
 
+    /// Returns the text content of the current editor document, with blocks separated by blank
+    /// lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn get_text(&self) -> TiptapEditorResult<String> {
+        self.with_instance(TiptapEditorInstance::get_text)
+    }
+
     /// Replaces the current editor document content.
     ///
     /// # Errors
@@ -171,6 +197,43 @@ fn extract_html_content(response: DocumentResponse) -> TiptapEditorResult<String
             "received an empty document response for an HTML document request".to_owned(),
         )
         .into()),
+        DocumentResponse::Text { .. } => Err(TiptapEditorError::BridgeError(
+            "received text content for an HTML document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::DocumentSnapshot { .. } => Err(TiptapEditorError::BridgeError(
+            "received a document snapshot for an HTML document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::CustomCommand { .. } => Err(TiptapEditorError::BridgeError(
+            "received a custom command result for an HTML document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::SearchMatches { .. } => Err(TiptapEditorError::BridgeError(
+            "received search matches for an HTML document request".to_owned(),
+        )
+        .into()),
+        #[cfg(feature = "collab")]
+        DocumentResponse::CollabState { .. } => Err(TiptapEditorError::BridgeError(
+            "received a collaboration state for an HTML document request".to_owned(),
+        )
+        .into()),
+        #[cfg(feature = "collaboration")]
+        DocumentResponse::CollaborationStateVector { .. }
+        | DocumentResponse::CollaborationUpdate { .. } => Err(TiptapEditorError::BridgeError(
+            "received a collaboration document response for an HTML document request".to_owned(),
+        )
+        .into()),
+    }
+}
+
+fn extract_text_content(response: DocumentResponse) -> TiptapEditorResult<String> {
+    match response {
+        DocumentResponse::Text { text } => Ok(text),
+        _ => Err(TiptapEditorError::BridgeError(
+            "received an unexpected response for a text document request".to_owned(),
+        )
+        .into()),
     }
 }
 
@@ -187,12 +250,67 @@ fn extract_json_content(response: DocumentResponse) -> TiptapEditorResult<serde_
             "received an empty document response for a JSON document request".to_owned(),
         )
         .into()),
+        DocumentResponse::Text { .. } => Err(TiptapEditorError::BridgeError(
+            "received text content for a JSON document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::DocumentSnapshot { .. } => Err(TiptapEditorError::BridgeError(
+            "received a document snapshot for a JSON document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::CustomCommand { .. } => Err(TiptapEditorError::BridgeError(
+            "received a custom command result for a JSON document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::SearchMatches { .. } => Err(TiptapEditorError::BridgeError(
+            "received search matches for a JSON document request".to_owned(),
+        )
+        .into()),
+        #[cfg(feature = "collab")]
+        DocumentResponse::CollabState { .. } => Err(TiptapEditorError::BridgeError(
+            "received a collaboration state for a JSON document request".to_owned(),
+        )
+        .into()),
+        #[cfg(feature = "collaboration")]
+        DocumentResponse::CollaborationStateVector { .. }
+        | DocumentResponse::CollaborationUpdate { .. } => Err(TiptapEditorError::BridgeError(
+            "received a collaboration document response for a JSON document request".to_owned(),
+        )
+        .into()),
     }
 }
 
 fn expect_empty_document_response(response: DocumentResponse) -> TiptapEditorResult<()> {
     match response {
         DocumentResponse::Empty => Ok(()),
+        DocumentResponse::Text { .. } => Err(TiptapEditorError::BridgeError(
+            "received text content for a set_content document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::DocumentSnapshot { .. } => Err(TiptapEditorError::BridgeError(
+            "received a document snapshot for a set_content document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::CustomCommand { .. } => Err(TiptapEditorError::BridgeError(
+            "received a custom command result for a set_content document request".to_owned(),
+        )
+        .into()),
+        DocumentResponse::SearchMatches { .. } => Err(TiptapEditorError::BridgeError(
+            "received search matches for a set_content document request".to_owned(),
+        )
+        .into()),
+        #[cfg(feature = "collab")]
+        DocumentResponse::CollabState { .. } => Err(TiptapEditorError::BridgeError(
+            "received a collaboration state for a set_content document request".to_owned(),
+        )
+        .into()),
+        #[cfg(feature = "collaboration")]
+        DocumentResponse::CollaborationStateVector { .. }
+        | DocumentResponse::CollaborationUpdate { .. } => Err(TiptapEditorError::BridgeError(
+            "received a collaboration document response for a set_content document request"
+                .to_owned(),
+        )
+        .into()),
         DocumentResponse::Content { content } => {
             let format = match content {
                 ContentPayload::Html(_) => ContentFormat::Html,
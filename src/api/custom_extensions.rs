@@ -0,0 +1,136 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::JsValue;
+
+use crate::protocol::{DocumentRequest, DocumentResponse};
+use crate::runtime;
+
+use super::{TiptapEditorError, TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult};
+
+/// A Tiptap extension supplied by the application as a JS module.
+///
+/// `descriptor` returns an object shaped like the descriptors of the built-in extensions:
+///
+/// - `create(context)` returns the Tiptap extension, or an array of them. `context.modules` maps
+///   module names such as `"@tiptap/core"` or `"@tiptap/pm/state"` to the modules bundled with the
+///   bridge. Build on them instead of importing your own copies, as `ProseMirror` does not support
+///   mixing instances.
+/// - `commands` optionally maps command names to `(editor, payload) => result` functions, which
+///   are called through [`TiptapEditorHandle::custom_command`]. Throwing rejects the command.
+/// - `active_keys` and `active_state(editor)` optionally contribute boolean states, which are
+///   read through [`TiptapSelectionState::custom_active`](crate::TiptapSelectionState::custom_active).
+///
+/// Import the function through `wasm-bindgen`, usually from a `module` file of your crate:
+///
+/// ```no_run
+/// use leptos_tiptap::TiptapCustomExtension;
+/// use wasm_bindgen::prelude::*;
+///
+/// #[wasm_bindgen(inline_js = r#"
+///     export function shout_extension() {
+///         return {
+///             create: ({modules}) => modules["@tiptap/core"].Extension.create({name: "shout"}),
+///             commands: {
+///                 shout: (editor, text) => editor.commands.insertContent(text.toUpperCase()),
+///             },
+///         }
+///     }
+/// "#)]
+/// extern "C" {
+///     fn shout_extension() -> JsValue;
+/// }
+///
+/// let shout = TiptapCustomExtension::new("shout", shout_extension);
+/// ```
+///
+/// `descriptor` is called whenever an editor using the extension is created.
+#[derive(Debug, Clone)]
+pub struct TiptapCustomExtension {
+    name: String,
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    descriptor: fn() -> JsValue,
+}
+
+impl TiptapCustomExtension {
+    /// Creates a custom extension registered under `name`, whose JS descriptor is returned by
+    /// `descriptor`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, descriptor: fn() -> JsValue) -> Self {
+        Self {
+            name: name.into(),
+            descriptor,
+        }
+    }
+
+    /// Returns the name the extension is registered under.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn descriptor(&self) -> JsValue {
+        (self.descriptor)()
+    }
+}
+
+impl TiptapEditorInstance {
+    /// Runs the command `name` of a custom extension with `payload`, and returns its result.
+    ///
+    /// A command returning `undefined` produces `null`, which deserializes into `()` or `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `payload` can not be serialized, when no custom extension of the
+    /// editor provides the command, when the command throws, or when its result can not be
+    /// deserialized into `R`.
+    pub fn custom_command<R: DeserializeOwned>(
+        &self,
+        name: &str,
+        payload: impl Serialize,
+    ) -> TiptapEditorResult<R> {
+        let payload = serde_json::to_value(payload).map_err(|err| {
+            TiptapEditorError::BridgeError(format!(
+                "could not serialize the payload of custom command '{name}': {err}"
+            ))
+        })?;
+
+        match runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::CustomCommand {
+                name: name.to_owned(),
+                payload,
+            },
+        )? {
+            DocumentResponse::CustomCommand { value } => {
+                serde_json::from_value(value).map_err(|err| {
+                    TiptapEditorError::InvalidBridgePayload(format!(
+                        "could not parse the result of custom command '{name}': {err}"
+                    ))
+                    .into()
+                })
+            }
+            _ => Err(TiptapEditorError::BridgeError(
+                "received an unexpected response for a custom_command request".to_owned(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Runs the command `name` of a custom extension with `payload`, and returns its result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn custom_command<R: DeserializeOwned>(
+        &self,
+        name: &str,
+        payload: impl Serialize,
+    ) -> TiptapEditorResult<R> {
+        self.with_instance(|instance| instance.custom_command(name, payload))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::protocol::EditorCommand;
+
+use super::{TiptapDecoration, TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult};
+
+impl TiptapEditorInstance {
+    /// Replaces the decorations of the layer `layer_id`, leaving other layers untouched.
+    ///
+    /// Layers let independent sources, such as a spell checker and a search, manage their
+    /// decorations separately. Until the next call, the decorations follow local document
+    /// changes. Pass an empty list to remove the layer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale or when the JS bridge rejects the request.
+    pub fn set_decorations(
+        &self,
+        layer_id: impl Into<String>,
+        decorations: Vec<TiptapDecoration>,
+    ) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SetDecorations {
+            layer_id: layer_id.into(),
+            decorations,
+        })
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Replaces the decorations of the layer `layer_id`, leaving other layers untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn set_decorations(
+        &self,
+        layer_id: impl Into<String>,
+        decorations: Vec<TiptapDecoration>,
+    ) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.set_decorations(layer_id, decorations))
+    }
+}
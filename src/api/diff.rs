@@ -0,0 +1,757 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use crate::protocol::{DiffChange, DiffHighlight, EditorCommand};
+
+use super::{TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult};
+
+/// How one top-level block of a document changed between two documents.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TiptapDiffOperation {
+    /// The block is the same in both documents.
+    Unchanged(Value),
+    /// The block only exists in the newer document.
+    Inserted(Value),
+    /// The block only exists in the older document.
+    Deleted(Value),
+    /// The block kept its node type, but its content or attributes changed.
+    Changed {
+        /// The block in the older document.
+        from: Value,
+        /// The block in the newer document.
+        to: Value,
+        /// The attributes of the block that changed, by name.
+        attributes: Vec<TiptapAttributeChange>,
+        /// The text of both blocks, compared word by word.
+        text: Vec<TiptapTextChange>,
+    },
+}
+
+/// An attribute of a block that changed between two documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiptapAttributeChange {
+    /// The name of the attribute.
+    pub name: String,
+    /// The value in the older document, if it was set.
+    pub from: Option<Value>,
+    /// The value in the newer document, if it is set.
+    pub to: Option<Value>,
+}
+
+/// A segment of the text of a changed block.
+///
+/// The text of a block is the text of all its text nodes, in document order. Nested nodes without
+/// text, such as images, are not part of it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TiptapTextChange {
+    /// Text that is the same in both blocks.
+    Unchanged(String),
+    /// Text that only exists in the newer block.
+    Inserted(String),
+    /// Text that only exists in the older block.
+    Deleted(String),
+    /// Text that exists in both blocks with other marks, for example after it was made bold.
+    Formatted {
+        /// The text.
+        text: String,
+        /// The marks of the text in the older block, as Tiptap JSON.
+        from: Vec<Value>,
+        /// The marks of the text in the newer block, as Tiptap JSON.
+        to: Vec<Value>,
+    },
+}
+
+/// The differences between two Tiptap JSON documents.
+///
+/// Top-level blocks are aligned first. Blocks of the same type that take each other's place are
+/// then compared by their attributes and word by word by their text. The operations can be
+/// rendered without a schema, or displayed in an editor through
+/// [`TiptapEditorHandle::show_diff`].
+///
+/// # Example
+///
+/// ```
+/// use leptos_tiptap::{TiptapDiffOperation, TiptapDocumentDiff, TiptapTextChange};
+/// use serde_json::json;
+///
+/// let paragraph = |text: &str| json!({"type": "paragraph", "content": [{"type": "text", "text": text}]});
+/// let diff = TiptapDocumentDiff::between(
+///     &json!({"type": "doc", "content": [paragraph("Title"), paragraph("An old idea")]}),
+///     &json!({"type": "doc", "content": [paragraph("Title"), paragraph("A new idea")]}),
+/// );
+///
+/// let TiptapDiffOperation::Changed { text, .. } = &diff.operations[1] else {
+///     panic!("the second paragraph should have changed");
+/// };
+/// assert_eq!(text, &vec![
+///     TiptapTextChange::Deleted("An old".to_owned()),
+///     TiptapTextChange::Inserted("A new".to_owned()),
+///     TiptapTextChange::Unchanged(" idea".to_owned()),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TiptapDocumentDiff {
+    /// The blocks of both documents in document order.
+    pub operations: Vec<TiptapDiffOperation>,
+}
+
+impl TiptapDocumentDiff {
+    /// Compares the documents `from` and `to`.
+    #[must_use]
+    pub fn between(from: &Value, to: &Value) -> Self {
+        let from = blocks(from);
+        let to = blocks(to);
+
+        let mut operations = Vec::new();
+        let mut deleted = Vec::new();
+        let mut inserted = Vec::new();
+        for step in align(from, to, |a, b| a == b) {
+            match step {
+                Alignment::Both(i, _) => {
+                    pair_blocks(&mut deleted, &mut inserted, &mut operations);
+                    operations.push(TiptapDiffOperation::Unchanged(from[i].clone()));
+                }
+                Alignment::From(i) => deleted.push(from[i].clone()),
+                Alignment::To(j) => inserted.push(to[j].clone()),
+            }
+        }
+        pair_blocks(&mut deleted, &mut inserted, &mut operations);
+
+        Self { operations }
+    }
+
+    /// Returns `true` when the documents differ.
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.operations
+            .iter()
+            .any(|operation| !matches!(operation, TiptapDiffOperation::Unchanged(_)))
+    }
+
+    /// Returns a document with the blocks of both documents: deleted blocks in their former place,
+    /// and changed blocks as they are in the newer document.
+    ///
+    /// This is the document [`TiptapEditorHandle::show_diff`] displays.
+    #[must_use]
+    pub fn document(&self) -> Value {
+        let content = self
+            .operations
+            .iter()
+            .map(|operation| match operation {
+                TiptapDiffOperation::Unchanged(block)
+                | TiptapDiffOperation::Inserted(block)
+                | TiptapDiffOperation::Deleted(block)
+                | TiptapDiffOperation::Changed { to: block, .. } => block.clone(),
+            })
+            .collect::<Vec<_>>();
+        serde_json::json!({"type": "doc", "content": content})
+    }
+
+    /// Locates the changes in [`document`](Self::document), by block index and by text offsets
+    /// in UTF-16 code units, as JS counts them.
+    fn highlights(&self) -> Vec<DiffHighlight> {
+        let mut highlights = Vec::new();
+        for (block, operation) in (0_u32..).zip(&self.operations) {
+            let change = match operation {
+                TiptapDiffOperation::Unchanged(_) => continue,
+                TiptapDiffOperation::Inserted(_) => DiffChange::Inserted,
+                TiptapDiffOperation::Deleted(_) => DiffChange::Deleted,
+                TiptapDiffOperation::Changed {
+                    attributes, text, ..
+                } => {
+                    let text_changed = text
+                        .iter()
+                        .any(|change| !matches!(change, TiptapTextChange::Unchanged(_)));
+                    if !attributes.is_empty() || !text_changed {
+                        highlights.push(DiffHighlight::Block {
+                            block,
+                            change: DiffChange::Changed,
+                        });
+                    }
+                    text_highlights(block, text, &mut highlights);
+                    continue;
+                }
+            };
+            highlights.push(DiffHighlight::Block { block, change });
+        }
+        highlights
+    }
+}
+
+impl TiptapEditorInstance {
+    /// Displays `diff` in place of this editor: shows [`TiptapDocumentDiff::document`] in a
+    /// separate read-only view of the `leptos-tiptap-diff` class and highlights inserted, deleted,
+    /// and changed blocks and text.
+    ///
+    /// The document of the editor is not changed, so showing a diff calls neither `on_change` nor
+    /// `on_document_change`, sends no collaboration updates, and saves no drafts. Showing another
+    /// diff replaces the shown one.
+    ///
+    /// Inserted blocks and text get the `diff-inserted` class, deleted blocks `diff-deleted`, and
+    /// blocks with changed attributes or formatted text `diff-changed`. Deleted text is shown
+    /// in a `span` element with the `diff-deleted` class where it was removed.
+    ///
+    /// The editor is read-only until [`hide_diff`](Self::hide_diff) is called. Changes to its
+    /// `editable` state apply once the diff is hidden.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale or when the JS bridge rejects the request.
+    pub fn show_diff(&self, diff: &TiptapDocumentDiff) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::ShowDiff {
+            document: diff.document(),
+            highlights: diff.highlights(),
+        })
+    }
+
+    /// Removes a diff shown through [`show_diff`](Self::show_diff), showing the editor with its
+    /// unchanged document again, and makes it editable again, if it was before. Does nothing when
+    /// no diff is shown.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale or when the JS bridge rejects the request.
+    pub fn hide_diff(&self) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::HideDiff)
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Displays `diff` in this editor, see [`TiptapEditorInstance::show_diff`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn show_diff(&self, diff: &TiptapDocumentDiff) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.show_diff(diff))
+    }
+
+    /// Hides the diff shown in this editor, see [`TiptapEditorInstance::hide_diff`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn hide_diff(&self) -> TiptapEditorResult<()> {
+        self.with_instance(TiptapEditorInstance::hide_diff)
+    }
+}
+
+fn blocks(document: &Value) -> &[Value] {
+    document
+        .get("content")
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+fn node_type(node: &Value) -> Option<&str> {
+    node.get("type").and_then(Value::as_str)
+}
+
+/// A step through two sequences: an item of both, or an item of only one of them.
+enum Alignment {
+    Both(usize, usize),
+    From(usize),
+    To(usize),
+}
+
+/// Largest number of item pairs [`align`] compares. Finding the longest common subsequence takes
+/// time and memory proportional to the number of pairs, so larger changed parts are not aligned.
+const MAX_ALIGNED_PAIRS: usize = 1 << 20;
+
+/// Aligns two sequences along their longest common subsequence.
+///
+/// Items between the common prefix and suffix are only aligned when there are at most
+/// [`MAX_ALIGNED_PAIRS`] pairs of them. Otherwise, all of them are reported as deleted and then
+/// inserted.
+fn align<T>(from: &[T], to: &[T], equal: impl Fn(&T, &T) -> bool) -> Vec<Alignment> {
+    let prefix = from.iter().zip(to).take_while(|(a, b)| equal(a, b)).count();
+    let suffix = from[prefix..]
+        .iter()
+        .rev()
+        .zip(to[prefix..].iter().rev())
+        .take_while(|(a, b)| equal(a, b))
+        .count();
+    let (from_end, to_end) = (from.len() - suffix, to.len() - suffix);
+
+    let mut steps = (0..prefix)
+        .map(|i| Alignment::Both(i, i))
+        .collect::<Vec<_>>();
+    let (rows, columns) = (from_end - prefix, to_end - prefix);
+    if rows.saturating_mul(columns) > MAX_ALIGNED_PAIRS {
+        steps.extend((prefix..from_end).map(Alignment::From));
+        steps.extend((prefix..to_end).map(Alignment::To));
+        steps.extend((0..suffix).map(|k| Alignment::Both(from_end + k, to_end + k)));
+        return steps;
+    }
+
+    // `common[i][j]` is the length of the longest common subsequence of `from[prefix + i..]` and
+    // `to[prefix + j..]`, within the changed middle part.
+    let mut common = vec![vec![0_usize; columns + 1]; rows + 1];
+    for i in (0..rows).rev() {
+        for j in (0..columns).rev() {
+            common[i][j] = if equal(&from[prefix + i], &to[prefix + j]) {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < rows || j < columns {
+        if i < rows && j < columns && equal(&from[prefix + i], &to[prefix + j]) {
+            steps.push(Alignment::Both(prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if j == columns || (i < rows && common[i + 1][j] >= common[i][j + 1]) {
+            steps.push(Alignment::From(prefix + i));
+            i += 1;
+        } else {
+            steps.push(Alignment::To(prefix + j));
+            j += 1;
+        }
+    }
+    steps.extend((0..suffix).map(|k| Alignment::Both(from_end + k, to_end + k)));
+    steps
+}
+
+/// Reports a run of deleted and inserted blocks. Blocks of the same type that share a word, or
+/// of which one has no words, are aligned as changes.
+fn pair_blocks(
+    deleted: &mut Vec<Value>,
+    inserted: &mut Vec<Value>,
+    operations: &mut Vec<TiptapDiffOperation>,
+) {
+    let similar = |from: &Value, to: &Value| {
+        if node_type(from) != node_type(to) {
+            return false;
+        }
+        let from = words(from);
+        let to = words(to);
+        from.is_empty() || to.is_empty() || from.intersection(&to).next().is_some()
+    };
+
+    for step in align(deleted, inserted, similar) {
+        operations.push(match step {
+            Alignment::Both(i, j) => TiptapDiffOperation::Changed {
+                from: deleted[i].clone(),
+                to: inserted[j].clone(),
+                attributes: attribute_changes(&deleted[i], &inserted[j]),
+                text: text_changes(&deleted[i], &inserted[j]),
+            },
+            Alignment::From(i) => TiptapDiffOperation::Deleted(deleted[i].clone()),
+            Alignment::To(j) => TiptapDiffOperation::Inserted(inserted[j].clone()),
+        });
+    }
+    deleted.clear();
+    inserted.clear();
+}
+
+fn attribute_changes(from: &Value, to: &Value) -> Vec<TiptapAttributeChange> {
+    let from = from.get("attrs").and_then(Value::as_object);
+    let to = to.get("attrs").and_then(Value::as_object);
+    let names = from
+        .into_iter()
+        .chain(to)
+        .flat_map(|attributes| attributes.keys())
+        .collect::<BTreeSet<_>>();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let from = from.and_then(|attributes| attributes.get(name));
+            let to = to.and_then(|attributes| attributes.get(name));
+            (from != to).then(|| TiptapAttributeChange {
+                name: name.clone(),
+                from: from.cloned(),
+                to: to.cloned(),
+            })
+        })
+        .collect()
+}
+
+/// A word, a run of whitespace, or another single character, with the marks of its text node.
+struct Token<'a> {
+    text: &'a str,
+    marks: &'a [Value],
+}
+
+fn tokens(node: &Value) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    collect_tokens(node, &mut tokens);
+    tokens
+}
+
+fn collect_tokens<'a>(node: &'a Value, tokens: &mut Vec<Token<'a>>) {
+    if let Some(text) = node.get("text").and_then(Value::as_str) {
+        let marks = node
+            .get("marks")
+            .and_then(Value::as_array)
+            .map_or(&[][..], Vec::as_slice);
+        tokens.extend(split_words(text).map(|text| Token { text, marks }));
+    }
+    for child in blocks(node) {
+        collect_tokens(child, tokens);
+    }
+}
+
+fn words(node: &Value) -> BTreeSet<&str> {
+    tokens(node)
+        .into_iter()
+        .map(|token| token.text)
+        .filter(|text| text.chars().any(char::is_alphanumeric))
+        .collect()
+}
+
+fn split_words(text: &str) -> impl Iterator<Item = &str> {
+    let class = |c: char| {
+        if c.is_alphanumeric() {
+            Some(true)
+        } else if c.is_whitespace() {
+            Some(false)
+        } else {
+            None
+        }
+    };
+
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let end = match class(first) {
+            Some(kind) => rest
+                .char_indices()
+                .find(|&(_, c)| class(c) != Some(kind))
+                .map_or(rest.len(), |(index, _)| index),
+            None => first.len_utf8(),
+        };
+        let (token, remainder) = rest.split_at(end);
+        rest = remainder;
+        Some(token)
+    })
+}
+
+fn text_changes(from: &Value, to: &Value) -> Vec<TiptapTextChange> {
+    let from = tokens(from);
+    let to = tokens(to);
+
+    let mut steps = align(&from, &to, |a, b| a.text == b.text)
+        .into_iter()
+        .map(|step| match step {
+            Alignment::Both(i, j) if from[i].marks == to[j].marks => {
+                TiptapTextChange::Unchanged(from[i].text.to_owned())
+            }
+            Alignment::Both(i, j) => TiptapTextChange::Formatted {
+                text: from[i].text.to_owned(),
+                from: from[i].marks.to_vec(),
+                to: to[j].marks.to_vec(),
+            },
+            Alignment::From(i) => TiptapTextChange::Deleted(from[i].text.to_owned()),
+            Alignment::To(j) => TiptapTextChange::Inserted(to[j].text.to_owned()),
+        })
+        .peekable();
+
+    // Deleted and inserted text is collected until the next unchanged or formatted text, and
+    // reported deletion first.
+    let mut changes: Vec<TiptapTextChange> = Vec::new();
+    let (mut deleted, mut inserted) = (String::new(), String::new());
+    while let Some(change) = steps.next() {
+        let change = match change {
+            TiptapTextChange::Deleted(text) => {
+                deleted.push_str(&text);
+                continue;
+            }
+            TiptapTextChange::Inserted(text) => {
+                inserted.push_str(&text);
+                continue;
+            }
+            // Whitespace between replaced words is part of the replacement.
+            TiptapTextChange::Unchanged(text)
+                if text.trim().is_empty()
+                    && !(deleted.is_empty() && inserted.is_empty())
+                    && matches!(
+                        steps.peek(),
+                        Some(TiptapTextChange::Deleted(_) | TiptapTextChange::Inserted(_))
+                    ) =>
+            {
+                deleted.push_str(&text);
+                inserted.push_str(&text);
+                continue;
+            }
+            change => change,
+        };
+
+        flush_replacement(&mut deleted, &mut inserted, &mut changes);
+        match (changes.last_mut(), change) {
+            (Some(TiptapTextChange::Unchanged(text)), TiptapTextChange::Unchanged(next)) => {
+                text.push_str(&next);
+            }
+            (
+                Some(TiptapTextChange::Formatted { text, from, to }),
+                TiptapTextChange::Formatted {
+                    text: next,
+                    from: next_from,
+                    to: next_to,
+                },
+            ) if *from == next_from && *to == next_to => text.push_str(&next),
+            (_, change) => changes.push(change),
+        }
+    }
+    flush_replacement(&mut deleted, &mut inserted, &mut changes);
+    changes
+}
+
+fn flush_replacement(
+    deleted: &mut String,
+    inserted: &mut String,
+    changes: &mut Vec<TiptapTextChange>,
+) {
+    if !deleted.is_empty() {
+        changes.push(TiptapTextChange::Deleted(std::mem::take(deleted)));
+    }
+    if !inserted.is_empty() {
+        changes.push(TiptapTextChange::Inserted(std::mem::take(inserted)));
+    }
+}
+
+fn utf16_len(text: &str) -> u32 {
+    u32::try_from(text.encode_utf16().count()).unwrap_or(u32::MAX)
+}
+
+fn text_highlights(block: u32, text: &[TiptapTextChange], highlights: &mut Vec<DiffHighlight>) {
+    // Offsets count the text of the newer block, which is the one displayed.
+    let mut offset = 0;
+    for change in text {
+        match change {
+            TiptapTextChange::Unchanged(text) => offset += utf16_len(text),
+            TiptapTextChange::Inserted(text) | TiptapTextChange::Formatted { text, .. } => {
+                let from = offset;
+                offset += utf16_len(text);
+                highlights.push(DiffHighlight::Text {
+                    block,
+                    from,
+                    to: offset,
+                    change: if matches!(change, TiptapTextChange::Inserted(_)) {
+                        DiffChange::Inserted
+                    } else {
+                        DiffChange::Changed
+                    },
+                });
+            }
+            TiptapTextChange::Deleted(text) => highlights.push(DiffHighlight::DeletedText {
+                block,
+                offset,
+                text: text.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    fn paragraph(text: &str) -> Value {
+        json!({"type": "paragraph", "content": [{"type": "text", "text": text}]})
+    }
+
+    fn heading(level: u8, text: &str) -> Value {
+        json!({"type": "heading", "attrs": {"level": level}, "content": [{"type": "text", "text": text}]})
+    }
+
+    fn doc(blocks: &[Value]) -> Value {
+        json!({"type": "doc", "content": blocks})
+    }
+
+    fn changed(from: Value, to: Value, text: Vec<TiptapTextChange>) -> TiptapDiffOperation {
+        TiptapDiffOperation::Changed {
+            from,
+            to,
+            attributes: Vec::new(),
+            text,
+        }
+    }
+
+    #[test]
+    fn reports_no_changes_between_equal_documents() {
+        let document = doc(&[heading(1, "Title"), paragraph("Body")]);
+
+        let diff = TiptapDocumentDiff::between(&document, &document);
+
+        assert_that!(diff.has_changes()).is_false();
+        assert_that!(diff.highlights()).is_empty();
+        assert_that!(diff.operations).is_equal_to(vec![
+            TiptapDiffOperation::Unchanged(heading(1, "Title")),
+            TiptapDiffOperation::Unchanged(paragraph("Body")),
+        ]);
+    }
+
+    #[test]
+    fn aligns_inserted_deleted_and_changed_blocks() {
+        let diff = TiptapDocumentDiff::between(
+            &doc(&[
+                heading(1, "Title"),
+                paragraph("a"),
+                paragraph("b"),
+                paragraph("c"),
+            ]),
+            &doc(&[
+                heading(1, "Title"),
+                paragraph("b"),
+                paragraph("c d"),
+                paragraph("new"),
+            ]),
+        );
+
+        assert_that!(diff.has_changes()).is_true();
+        assert_that!(diff.operations).is_equal_to(vec![
+            TiptapDiffOperation::Unchanged(heading(1, "Title")),
+            TiptapDiffOperation::Deleted(paragraph("a")),
+            TiptapDiffOperation::Unchanged(paragraph("b")),
+            changed(
+                paragraph("c"),
+                paragraph("c d"),
+                vec![
+                    TiptapTextChange::Unchanged("c".to_owned()),
+                    TiptapTextChange::Inserted(" d".to_owned()),
+                ],
+            ),
+            TiptapDiffOperation::Inserted(paragraph("new")),
+        ]);
+    }
+
+    #[test]
+    fn replaces_large_changed_parts_without_aligning_them() {
+        let count = 1100;
+        let from = (0..count)
+            .map(|i| paragraph(&format!("old{i}")))
+            .collect::<Vec<_>>();
+        let to = (0..count)
+            .map(|i| paragraph(&format!("new{i}")))
+            .collect::<Vec<_>>();
+        let diff = TiptapDocumentDiff::between(
+            &doc(&[&[heading(1, "Title")], from.as_slice()].concat()),
+            &doc(&[&[heading(1, "Title")], to.as_slice()].concat()),
+        );
+
+        let expected = std::iter::once(TiptapDiffOperation::Unchanged(heading(1, "Title")))
+            .chain(from.into_iter().map(TiptapDiffOperation::Deleted))
+            .chain(to.into_iter().map(TiptapDiffOperation::Inserted))
+            .collect::<Vec<_>>();
+        assert_that!(diff.operations).is_equal_to(expected);
+    }
+
+    #[test]
+    fn reports_blocks_of_another_type_as_replaced() {
+        let diff =
+            TiptapDocumentDiff::between(&doc(&[paragraph("Title")]), &doc(&[heading(1, "Title")]));
+
+        assert_that!(diff.operations).is_equal_to(vec![
+            TiptapDiffOperation::Deleted(paragraph("Title")),
+            TiptapDiffOperation::Inserted(heading(1, "Title")),
+        ]);
+    }
+
+    #[test]
+    fn treats_documents_without_content_as_empty() {
+        let diff = TiptapDocumentDiff::between(&json!({"type": "doc"}), &doc(&[paragraph("a")]));
+
+        assert_that!(diff.operations)
+            .is_equal_to(vec![TiptapDiffOperation::Inserted(paragraph("a"))]);
+    }
+
+    #[test]
+    fn compares_attributes_and_formatting_of_changed_blocks() {
+        let bold = json!({"type": "bold"});
+        let from = heading(1, "Quarterly results");
+        let to = json!({
+            "type": "heading",
+            "attrs": {"level": 2, "id": "results"},
+            "content": [
+                {"type": "text", "text": "Quarterly "},
+                {"type": "text", "text": "results", "marks": [bold]},
+            ],
+        });
+
+        let diff = TiptapDocumentDiff::between(
+            &doc(std::slice::from_ref(&from)),
+            &doc(std::slice::from_ref(&to)),
+        );
+
+        assert_that!(diff.highlights()).is_equal_to(vec![
+            DiffHighlight::Block {
+                block: 0,
+                change: DiffChange::Changed,
+            },
+            DiffHighlight::Text {
+                block: 0,
+                from: 10,
+                to: 17,
+                change: DiffChange::Changed,
+            },
+        ]);
+        assert_that!(diff.operations).is_equal_to(vec![TiptapDiffOperation::Changed {
+            from,
+            to,
+            attributes: vec![
+                TiptapAttributeChange {
+                    name: "id".to_owned(),
+                    from: None,
+                    to: Some(json!("results")),
+                },
+                TiptapAttributeChange {
+                    name: "level".to_owned(),
+                    from: Some(json!(1)),
+                    to: Some(json!(2)),
+                },
+            ],
+            text: vec![
+                TiptapTextChange::Unchanged("Quarterly ".to_owned()),
+                TiptapTextChange::Formatted {
+                    text: "results".to_owned(),
+                    from: Vec::new(),
+                    to: vec![bold],
+                },
+            ],
+        }]);
+    }
+
+    #[test]
+    fn locates_changes_in_the_displayed_document() {
+        let diff = TiptapDocumentDiff::between(
+            &doc(&[paragraph("Gone"), paragraph("Ünïcode wörds stay here")]),
+            &doc(&[paragraph("Ünïcode new wörds stay"), paragraph("Added")]),
+        );
+
+        assert_that!(diff.document()).is_equal_to(doc(&[
+            paragraph("Gone"),
+            paragraph("Ünïcode new wörds stay"),
+            paragraph("Added"),
+        ]));
+        assert_that!(diff.highlights()).is_equal_to(vec![
+            DiffHighlight::Block {
+                block: 0,
+                change: DiffChange::Deleted,
+            },
+            DiffHighlight::Text {
+                block: 1,
+                from: 8,
+                to: 12,
+                change: DiffChange::Inserted,
+            },
+            DiffHighlight::DeletedText {
+                block: 1,
+                offset: 22,
+                text: " here".to_owned(),
+            },
+            DiffHighlight::Block {
+                block: 2,
+                change: DiffChange::Inserted,
+            },
+        ]);
+    }
+}
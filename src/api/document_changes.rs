@@ -0,0 +1,389 @@
+use crate::protocol::{DocumentRequest, DocumentResponse};
+use crate::runtime;
+
+use super::{
+    TiptapDocumentChange, TiptapDocumentChangePayload, TiptapEditorError, TiptapEditorHandle,
+    TiptapEditorInstance, TiptapEditorResult, TiptapJsonPatchOperation,
+};
+
+impl TiptapEditorInstance {
+    /// Returns the current document as a snapshot entry of the change stream.
+    ///
+    /// The snapshot carries the version of the most recently emitted change, so a consumer that
+    /// detected a gap can replace its mirrored state with it and continue applying changes from
+    /// `version + 1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor was created without `on_document_change`, or when the JS
+    /// bridge rejects the request.
+    pub fn document_snapshot(&self) -> TiptapEditorResult<TiptapDocumentChange> {
+        match runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::GetDocumentSnapshot,
+        )? {
+            DocumentResponse::DocumentSnapshot { change } => Ok(change),
+            _ => Err(TiptapEditorError::BridgeError(
+                "received an unexpected response for a document snapshot request".to_owned(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Returns the current document as a snapshot entry of the change stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn document_snapshot(&self) -> TiptapEditorResult<TiptapDocumentChange> {
+        self.with_instance(TiptapEditorInstance::document_snapshot)
+    }
+}
+
+/// A schema-free copy of the editor document, maintained from the document change stream.
+///
+/// Feed every [`TiptapDocumentChange`] received through `on_document_change` into
+/// [`apply`](Self::apply). Snapshots replace the mirrored state; JSON patches must directly follow
+/// the mirrored version. `ProseMirror` steps require a schema and can not be mirrored here.
+///
+/// # Example
+///
+/// ```
+/// use leptos_tiptap::{TiptapDocumentChange, TiptapDocumentChangePayload, TiptapDocumentMirror};
+/// use serde_json::json;
+///
+/// let mut mirror = TiptapDocumentMirror::new();
+/// mirror
+///     .apply(&TiptapDocumentChange {
+///         version: 0,
+///         payload: TiptapDocumentChangePayload::Snapshot {
+///             document: json!({"type": "doc", "content": []}),
+///         },
+///     })
+///     .unwrap();
+///
+/// assert_eq!(mirror.version(), Some(0));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TiptapDocumentMirror {
+    state: Option<(u64, serde_json::Value)>,
+}
+
+impl TiptapDocumentMirror {
+    /// Creates an empty mirror that waits for its first snapshot.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the mirrored version, or `None` while the mirror needs a snapshot.
+    #[must_use]
+    pub fn version(&self) -> Option<u64> {
+        self.state.as_ref().map(|(version, _)| *version)
+    }
+
+    /// Returns the mirrored `ProseMirror` JSON document, or `None` while the mirror needs a
+    /// snapshot.
+    #[must_use]
+    pub fn document(&self) -> Option<&serde_json::Value> {
+        self.state.as_ref().map(|(_, document)| document)
+    }
+
+    /// Applies the next change of the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DocumentVersionGap`](TiptapEditorError::DocumentVersionGap) when the change does
+    /// not directly follow the mirrored version. The mirror is left untouched in that case.
+    ///
+    /// Returns [`InvalidDocumentChange`](TiptapEditorError::InvalidDocumentChange) when no
+    /// snapshot was applied yet, when the change carries `ProseMirror` steps, or when a patch
+    /// operation does not fit the mirrored document. A failed patch leaves the mirror
+    /// unsynchronized until the next snapshot.
+    pub fn apply(&mut self, change: &TiptapDocumentChange) -> TiptapEditorResult<()> {
+        let operations = match &change.payload {
+            TiptapDocumentChangePayload::Snapshot { document } => {
+                self.state = Some((change.version, document.clone()));
+                return Ok(());
+            }
+            TiptapDocumentChangePayload::JsonPatch { operations } => operations,
+            TiptapDocumentChangePayload::Steps { .. } => {
+                return Err(TiptapEditorError::InvalidDocumentChange(
+                    "ProseMirror steps require a schema and can not be mirrored".to_owned(),
+                )
+                .into());
+            }
+        };
+
+        let Some((version, document)) = self.state.as_mut() else {
+            return Err(TiptapEditorError::InvalidDocumentChange(
+                "a snapshot is required before incremental changes can be applied".to_owned(),
+            )
+            .into());
+        };
+
+        let expected = *version + 1;
+        if change.version != expected {
+            return Err(TiptapEditorError::DocumentVersionGap {
+                expected,
+                received: change.version,
+            }
+            .into());
+        }
+
+        match operations
+            .iter()
+            .try_for_each(|operation| apply_operation(document, operation))
+        {
+            Ok(()) => {
+                *version = expected;
+                Ok(())
+            }
+            Err(err) => {
+                self.state = None;
+                Err(err.into())
+            }
+        }
+    }
+}
+
+fn apply_operation(
+    document: &mut serde_json::Value,
+    operation: &TiptapJsonPatchOperation,
+) -> Result<(), TiptapEditorError> {
+    let (path, value) = match operation {
+        TiptapJsonPatchOperation::Add { path, value }
+        | TiptapJsonPatchOperation::Replace { path, value } => (path, Some(value)),
+        TiptapJsonPatchOperation::Remove { path } => (path, None),
+    };
+    let invalid = |reason: &str| {
+        TiptapEditorError::InvalidDocumentChange(format!("{reason} at JSON pointer '{path}'"))
+    };
+
+    let mut tokens = parse_pointer(path).ok_or_else(|| invalid("malformed path"))?;
+    let Some(last) = tokens.pop() else {
+        *document = value
+            .ok_or_else(|| invalid("can not remove the document"))?
+            .clone();
+        return Ok(());
+    };
+
+    let mut parent = &mut *document;
+    for token in &tokens {
+        parent = match parent {
+            serde_json::Value::Object(map) => map.get_mut(token),
+            serde_json::Value::Array(items) => token
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get_mut(index)),
+            _ => None,
+        }
+        .ok_or_else(|| invalid("missing parent"))?;
+    }
+
+    match (parent, operation) {
+        (serde_json::Value::Object(map), TiptapJsonPatchOperation::Add { value, .. }) => {
+            map.insert(last, value.clone());
+        }
+        (serde_json::Value::Object(map), TiptapJsonPatchOperation::Replace { value, .. }) => {
+            *map.get_mut(&last).ok_or_else(|| invalid("missing value"))? = value.clone();
+        }
+        (serde_json::Value::Object(map), TiptapJsonPatchOperation::Remove { .. }) => {
+            map.remove(&last).ok_or_else(|| invalid("missing value"))?;
+        }
+        (serde_json::Value::Array(items), TiptapJsonPatchOperation::Add { value, .. }) => {
+            let index = if last == "-" {
+                items.len()
+            } else {
+                last.parse::<usize>()
+                    .ok()
+                    .filter(|index| *index <= items.len())
+                    .ok_or_else(|| invalid("invalid array index"))?
+            };
+            items.insert(index, value.clone());
+        }
+        (serde_json::Value::Array(items), TiptapJsonPatchOperation::Replace { value, .. }) => {
+            *last
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get_mut(index))
+                .ok_or_else(|| invalid("invalid array index"))? = value.clone();
+        }
+        (serde_json::Value::Array(items), TiptapJsonPatchOperation::Remove { .. }) => {
+            let index = last
+                .parse::<usize>()
+                .ok()
+                .filter(|index| *index < items.len())
+                .ok_or_else(|| invalid("invalid array index"))?;
+            items.remove(index);
+        }
+        _ => return Err(invalid("parent is not a container")),
+    }
+
+    Ok(())
+}
+
+/// Splits an RFC 6901 JSON pointer into unescaped reference tokens.
+fn parse_pointer(path: &str) -> Option<Vec<String>> {
+    if path.is_empty() {
+        return Some(Vec::new());
+    }
+
+    path.strip_prefix('/')?
+        .split('/')
+        .map(|token| Some(token.replace("~1", "/").replace("~0", "~")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    fn snapshot(version: u64, document: serde_json::Value) -> TiptapDocumentChange {
+        TiptapDocumentChange {
+            version,
+            payload: TiptapDocumentChangePayload::Snapshot { document },
+        }
+    }
+
+    fn patch(version: u64, operations: Vec<TiptapJsonPatchOperation>) -> TiptapDocumentChange {
+        TiptapDocumentChange {
+            version,
+            payload: TiptapDocumentChangePayload::JsonPatch { operations },
+        }
+    }
+
+    fn paragraph(text: &str) -> serde_json::Value {
+        json!({"type": "paragraph", "content": [{"type": "text", "text": text}]})
+    }
+
+    #[test]
+    fn applies_consecutive_json_patches_after_a_snapshot() {
+        let mut mirror = TiptapDocumentMirror::new();
+        mirror
+            .apply(&snapshot(
+                0,
+                json!({"type": "doc", "content": [paragraph("a")]}),
+            ))
+            .unwrap();
+
+        mirror
+            .apply(&patch(
+                1,
+                vec![
+                    TiptapJsonPatchOperation::Replace {
+                        path: "/content/0/content/0/text".to_owned(),
+                        value: json!("ab"),
+                    },
+                    TiptapJsonPatchOperation::Add {
+                        path: "/content/1".to_owned(),
+                        value: paragraph("c"),
+                    },
+                ],
+            ))
+            .unwrap();
+        mirror
+            .apply(&patch(
+                2,
+                vec![
+                    TiptapJsonPatchOperation::Remove {
+                        path: "/content/0".to_owned(),
+                    },
+                    TiptapJsonPatchOperation::Add {
+                        path: "/content/0/attrs".to_owned(),
+                        value: json!({"textAlign": "center"}),
+                    },
+                ],
+            ))
+            .unwrap();
+
+        assert_that!(mirror.version()).is_equal_to(Some(2));
+        assert_that!(mirror.document().cloned()).is_equal_to(Some(json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [{"type": "text", "text": "c"}],
+                "attrs": {"textAlign": "center"}
+            }]
+        })));
+    }
+
+    #[test]
+    fn reports_version_gaps_without_touching_the_mirror() {
+        let mut mirror = TiptapDocumentMirror::new();
+        mirror
+            .apply(&snapshot(
+                4,
+                json!({"type": "doc", "content": [paragraph("a")]}),
+            ))
+            .unwrap();
+        let before = mirror.clone();
+
+        let err = mirror
+            .apply(&patch(
+                6,
+                vec![TiptapJsonPatchOperation::Remove {
+                    path: "/content/0".to_owned(),
+                }],
+            ))
+            .unwrap_err()
+            .into_current_context();
+
+        assert_that!(err).is_equal_to(TiptapEditorError::DocumentVersionGap {
+            expected: 5,
+            received: 6,
+        });
+        assert_that!(mirror).is_equal_to(before);
+    }
+
+    #[test]
+    fn requires_a_snapshot_after_a_failed_patch() {
+        let mut mirror = TiptapDocumentMirror::new();
+        mirror
+            .apply(&snapshot(0, json!({"type": "doc", "content": []})))
+            .unwrap();
+
+        let result = mirror.apply(&patch(
+            1,
+            vec![TiptapJsonPatchOperation::Replace {
+                path: "/content/3".to_owned(),
+                value: paragraph("x"),
+            }],
+        ));
+
+        assert_that!(result).is_err();
+        assert_that!(mirror.version()).is_none();
+        assert_that!(mirror.document()).is_none();
+
+        mirror
+            .apply(&snapshot(1, json!({"type": "doc", "content": []})))
+            .unwrap();
+        assert_that!(mirror.version()).is_equal_to(Some(1));
+    }
+
+    #[test]
+    fn rejects_steps_and_patches_before_the_first_snapshot() {
+        let mut mirror = TiptapDocumentMirror::new();
+
+        assert_that!(mirror.apply(&patch(1, Vec::new()))).is_err();
+        assert_that!(mirror.apply(&TiptapDocumentChange {
+            version: 1,
+            payload: TiptapDocumentChangePayload::Steps { steps: Vec::new() },
+        }))
+        .is_err();
+    }
+
+    #[test]
+    fn unescapes_json_pointer_tokens() {
+        assert_that!(parse_pointer("/a~1b/c~0d"))
+            .is_equal_to(Some(vec!["a/b".to_owned(), "c~d".to_owned()]));
+        assert_that!(parse_pointer("")).is_equal_to(Some(Vec::new()));
+        assert_that!(parse_pointer("content")).is_none();
+    }
+}
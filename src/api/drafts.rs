@@ -0,0 +1,444 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::protocol::EditorCommand;
+use crate::runtime;
+use leptos::prelude::set_timeout;
+
+use super::{
+    TiptapContent, TiptapDraft, TiptapEditorError, TiptapEditorHandle, TiptapEditorReport,
+    TiptapEditorResult, TiptapSelectionRange,
+};
+
+/// Persists editor drafts, keyed by editor id.
+///
+/// Implement this trait to keep drafts in a backend of your choice. Use
+/// [`TiptapLocalStorageDraftStore`] for the browser's `localStorage`,
+/// [`TiptapIndexedDbDraftStore`] for `IndexedDB`, or [`TiptapMemoryDraftStore`] in tests.
+pub trait TiptapDraftStore: Send + Sync {
+    /// Returns the draft stored under `key`, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend can not be read or holds an invalid draft.
+    fn load(&self, key: &str) -> TiptapEditorResult<Option<TiptapDraft>>;
+
+    /// Stores `draft` under `key`, replacing any previous draft.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend can not be written.
+    fn save(&self, key: &str, draft: &TiptapDraft) -> TiptapEditorResult<()>;
+
+    /// Removes the draft stored under `key`. Removing a missing draft is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend can not be written.
+    fn discard(&self, key: &str) -> TiptapEditorResult<()>;
+}
+
+/// Stores drafts as JSON in the browser's `localStorage`.
+///
+/// Under SSR there is no storage: nothing is loaded and saving does nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TiptapLocalStorageDraftStore {
+    prefix: String,
+}
+
+impl TiptapLocalStorageDraftStore {
+    /// Creates a store whose entries are named by `prefix` followed by the editor id.
+    #[must_use]
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    fn entry_name(&self, key: &str) -> String {
+        format!("{}{key}", self.prefix)
+    }
+}
+
+impl Default for TiptapLocalStorageDraftStore {
+    fn default() -> Self {
+        Self::with_prefix("leptos-tiptap-draft:")
+    }
+}
+
+impl TiptapDraftStore for TiptapLocalStorageDraftStore {
+    fn load(&self, key: &str) -> TiptapEditorResult<Option<TiptapDraft>> {
+        let Some(entry) = runtime::local_storage_get(&self.entry_name(key))? else {
+            return Ok(None);
+        };
+
+        parse_draft(&entry).map(Some)
+    }
+
+    fn save(&self, key: &str, draft: &TiptapDraft) -> TiptapEditorResult<()> {
+        let entry = serialize_draft(draft)?;
+        Ok(runtime::local_storage_set(&self.entry_name(key), &entry)?)
+    }
+
+    fn discard(&self, key: &str) -> TiptapEditorResult<()> {
+        Ok(runtime::local_storage_remove(&self.entry_name(key))?)
+    }
+}
+
+/// Stores drafts as JSON in an `IndexedDB` database.
+///
+/// `IndexedDB` is asynchronous, so the store keeps the drafts in memory: [`open`](Self::open) reads
+/// them once, and saving or discarding updates memory right away and writes the database in the
+/// background. A failed write is reported by the next save or discard. Clones share their drafts.
+///
+/// Under SSR there is no storage: nothing is loaded and drafts are only kept in memory.
+#[derive(Debug, Clone)]
+pub struct TiptapIndexedDbDraftStore {
+    drafts: TiptapMemoryDraftStore,
+    database: runtime::IndexedDbDrafts,
+    write_error: Arc<Mutex<Option<TiptapEditorError>>>,
+}
+
+impl TiptapIndexedDbDraftStore {
+    /// Opens the `IndexedDB` database `name`, creating it when missing, and reads its drafts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the database can not be opened or read, or holds an invalid draft.
+    pub async fn open(name: &str) -> TiptapEditorResult<Self> {
+        let (database, entries) = runtime::IndexedDbDrafts::open(name).await?;
+        let drafts = TiptapMemoryDraftStore::new();
+        for (key, entry) in entries {
+            drafts.save(&key, &parse_draft(&entry)?)?;
+        }
+        Ok(Self {
+            drafts,
+            database,
+            write_error: Arc::default(),
+        })
+    }
+
+    fn take_write_error(&self) -> TiptapEditorResult<()> {
+        match self
+            .write_error
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+        {
+            Some(err) => Err(err.into()),
+            None => Ok(()),
+        }
+    }
+
+    fn record_write_error(&self) -> impl FnOnce(TiptapEditorError) + 'static {
+        let write_error = Arc::clone(&self.write_error);
+        move |err| {
+            *write_error.lock().unwrap_or_else(PoisonError::into_inner) = Some(err);
+        }
+    }
+}
+
+impl TiptapDraftStore for TiptapIndexedDbDraftStore {
+    fn load(&self, key: &str) -> TiptapEditorResult<Option<TiptapDraft>> {
+        self.drafts.load(key)
+    }
+
+    fn save(&self, key: &str, draft: &TiptapDraft) -> TiptapEditorResult<()> {
+        self.take_write_error()?;
+        let entry = serialize_draft(draft)?;
+        self.drafts.save(key, draft)?;
+        self.database.put(key, &entry, self.record_write_error());
+        Ok(())
+    }
+
+    fn discard(&self, key: &str) -> TiptapEditorResult<()> {
+        self.take_write_error()?;
+        self.drafts.discard(key)?;
+        self.database.delete(key, self.record_write_error());
+        Ok(())
+    }
+}
+
+fn parse_draft(entry: &str) -> TiptapEditorResult<TiptapDraft> {
+    serde_json::from_str(entry).map_err(|err| {
+        TiptapEditorError::DraftStorage(format!("could not parse the stored draft: {err}")).into()
+    })
+}
+
+fn serialize_draft(draft: &TiptapDraft) -> TiptapEditorResult<String> {
+    serde_json::to_string(draft).map_err(|err| {
+        TiptapEditorError::DraftStorage(format!("could not serialize the draft: {err}")).into()
+    })
+}
+
+/// Keeps drafts in memory. Clones share their drafts.
+#[derive(Debug, Clone, Default)]
+pub struct TiptapMemoryDraftStore {
+    drafts: Arc<Mutex<HashMap<String, TiptapDraft>>>,
+}
+
+impl TiptapMemoryDraftStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_drafts<T>(&self, f: impl FnOnce(&mut HashMap<String, TiptapDraft>) -> T) -> T {
+        f(&mut self.drafts.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl TiptapDraftStore for TiptapMemoryDraftStore {
+    fn load(&self, key: &str) -> TiptapEditorResult<Option<TiptapDraft>> {
+        Ok(self.with_drafts(|drafts| drafts.get(key).cloned()))
+    }
+
+    fn save(&self, key: &str, draft: &TiptapDraft) -> TiptapEditorResult<()> {
+        self.with_drafts(|drafts| drafts.insert(key.to_owned(), draft.clone()));
+        Ok(())
+    }
+
+    fn discard(&self, key: &str) -> TiptapEditorResult<()> {
+        self.with_drafts(|drafts| drafts.remove(key));
+        Ok(())
+    }
+}
+
+/// Enables periodic draft saving for an editor.
+#[derive(Clone)]
+pub struct TiptapDraftConfig {
+    /// The backend drafts are saved to.
+    pub store: Arc<dyn TiptapDraftStore>,
+    /// How long to wait after a change before saving it. Further changes within this time are
+    /// saved together.
+    pub interval: Duration,
+}
+
+impl TiptapDraftConfig {
+    /// Saves drafts to `store` at most every two seconds.
+    #[must_use]
+    pub fn new(store: impl TiptapDraftStore + 'static) -> Self {
+        Self {
+            store: Arc::new(store),
+            interval: Duration::from_secs(2),
+        }
+    }
+
+    /// Saves drafts at most once per `interval`.
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Saves drafts to the browser's `localStorage`.
+    #[must_use]
+    pub fn local_storage() -> Self {
+        Self::new(TiptapLocalStorageDraftStore::default())
+    }
+}
+
+impl std::fmt::Debug for TiptapDraftConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TiptapDraftConfig")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Draft saving state of one editor, shared between its handle and its runtime session.
+pub(crate) struct TiptapDraftBinding {
+    config: TiptapDraftConfig,
+    key: String,
+    selection: Mutex<Option<TiptapSelectionRange>>,
+    dirty: AtomicBool,
+    save_scheduled: AtomicBool,
+}
+
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+impl TiptapDraftBinding {
+    pub(crate) fn new(config: TiptapDraftConfig, key: String) -> Self {
+        Self {
+            config,
+            key,
+            selection: Mutex::new(None),
+            dirty: AtomicBool::new(false),
+            save_scheduled: AtomicBool::new(false),
+        }
+    }
+
+    /// Remembers the selection for the next save. Moving the caret alone does not save a draft.
+    pub(crate) fn record_selection(&self, selection: TiptapSelectionRange) {
+        *self
+            .selection
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(selection);
+    }
+
+    /// Marks the editor state as unsaved and saves it once the configured interval has passed.
+    pub(crate) fn schedule_save(
+        self: &Arc<Self>,
+        editor: TiptapEditorHandle,
+        on_error: impl Fn(TiptapEditorReport) + Send + Sync + 'static,
+    ) {
+        self.dirty.store(true, Ordering::Relaxed);
+        if self.save_scheduled.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
+        let binding = Arc::clone(self);
+        set_timeout(
+            move || {
+                binding.save_scheduled.store(false, Ordering::Relaxed);
+                if let Err(err) = binding.flush(editor) {
+                    on_error(err);
+                }
+            },
+            self.config.interval,
+        );
+    }
+
+    /// Saves unsaved changes right away. Does nothing when the editor is not ready.
+    pub(crate) fn flush(&self, editor: TiptapEditorHandle) -> TiptapEditorResult<()> {
+        let Some(instance) = editor.instance_untracked() else {
+            return Ok(());
+        };
+        if !self.dirty.swap(false, Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let draft = TiptapDraft {
+            content: instance.get_json()?,
+            selection: *self
+                .selection
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        };
+        self.config.store.save(&self.key, &draft)
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Replaces the editor content and selection with the saved draft, if there is one.
+    ///
+    /// Returns `false` when no draft is stored for this editor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor was configured without drafts, when the handle has no
+    /// ready editor instance, or when the draft store or the editor rejects the request.
+    pub fn restore_draft(&self) -> TiptapEditorResult<bool> {
+        let drafts = self
+            .draft_binding()
+            .ok_or(TiptapEditorError::DraftsDisabled)?;
+        self.with_instance(|instance| {
+            let Some(draft) = drafts.config.store.load(&drafts.key)? else {
+                return Ok(false);
+            };
+
+            instance.set_content(TiptapContent::Json(draft.content))?;
+            if let Some(selection) = draft.selection {
+                instance.dispatch(EditorCommand::SetSelection {
+                    anchor: selection.anchor,
+                    head: selection.head,
+                })?;
+            }
+            Ok(true)
+        })
+    }
+
+    /// Removes the saved draft of this editor, including changes that are waiting to be saved.
+    ///
+    /// Later changes are saved as a new draft.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the editor was configured without drafts or when the draft store
+    /// rejects the request.
+    pub fn discard_draft(&self) -> TiptapEditorResult<()> {
+        let drafts = self
+            .draft_binding()
+            .ok_or(TiptapEditorError::DraftsDisabled)?;
+        drafts.dirty.store(false, Ordering::Relaxed);
+        drafts.config.store.discard(&drafts.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use leptos::prelude::Owner;
+    use serde_json::json;
+
+    fn draft() -> TiptapDraft {
+        TiptapDraft {
+            content: json!({"type": "doc", "content": []}),
+            selection: Some(TiptapSelectionRange { anchor: 1, head: 3 }),
+        }
+    }
+
+    #[test]
+    fn memory_store_saves_loads_and_discards_drafts() {
+        let store = TiptapMemoryDraftStore::new();
+        store.save("editor", &draft()).unwrap();
+
+        assert_that!(store.clone().load("editor").unwrap()).is_equal_to(Some(draft()));
+        assert_that!(store.load("other").unwrap()).is_none();
+
+        store.discard("editor").unwrap();
+        assert_that!(store.load("editor").unwrap()).is_none();
+    }
+
+    #[test]
+    fn indexed_db_store_reports_a_failed_write_on_the_next_write() {
+        let store = TiptapIndexedDbDraftStore {
+            drafts: TiptapMemoryDraftStore::new(),
+            database: runtime::IndexedDbDrafts::detached(),
+            write_error: Arc::default(),
+        };
+        store.record_write_error()(TiptapEditorError::DraftStorage("quota exceeded".to_owned()));
+
+        assert_that!(
+            store
+                .save("editor", &draft())
+                .unwrap_err()
+                .into_current_context()
+        )
+        .is_equal_to(TiptapEditorError::DraftStorage("quota exceeded".to_owned()));
+        assert_that!(store.load("editor").unwrap()).is_none();
+    }
+
+    #[test]
+    fn draft_methods_require_a_draft_store() {
+        Owner::new().with(|| {
+            let handle = TiptapEditorHandle::new();
+
+            assert_that!(handle.restore_draft().unwrap_err().into_current_context())
+                .is_equal_to(TiptapEditorError::DraftsDisabled);
+            assert_that!(handle.discard_draft().unwrap_err().into_current_context())
+                .is_equal_to(TiptapEditorError::DraftsDisabled);
+        });
+    }
+
+    #[test]
+    fn discarding_a_draft_does_not_require_a_ready_editor() {
+        Owner::new().with(|| {
+            let store = TiptapMemoryDraftStore::new();
+            store.save("editor", &draft()).unwrap();
+            let handle = TiptapEditorHandle::new();
+            handle.set_draft_binding(Some(Arc::new(TiptapDraftBinding::new(
+                TiptapDraftConfig::new(store.clone()),
+                "editor".to_owned(),
+            ))));
+
+            assert_that!(handle.restore_draft().unwrap_err().into_current_context())
+                .is_equal_to(TiptapEditorError::NotReady);
+            handle.discard_draft().unwrap();
+            assert_that!(store.load("editor").unwrap()).is_none();
+        });
+    }
+}
@@ -1,6 +1,11 @@
-use super::{TiptapEditorError, TiptapEditorResult};
+use super::drafts::TiptapDraftBinding;
+use super::{
+    TiptapCommentThread, TiptapEditorError, TiptapEditorResult, TiptapSearchMatches,
+    TiptapTrackedChange,
+};
 use crate::protocol::EditorCommand;
 use leptos::prelude::*;
+use std::sync::Arc;
 
 /// Internal lifecycle of a [`TiptapEditorHandle`].
 ///
@@ -36,7 +41,17 @@ enum HandleState {
 /// [`Destroyed`]: TiptapEditorError::Destroyed
 /// [`CreateFailed`]: TiptapEditorError::CreateFailed
 #[derive(Clone, Copy)]
-pub struct TiptapEditorHandle(RwSignal<HandleState>);
+pub struct TiptapEditorHandle {
+    state: RwSignal<HandleState>,
+    drafts: StoredValue<Option<Arc<TiptapDraftBinding>>>,
+    content_changes: Trigger,
+    search_matches: RwSignal<TiptapSearchMatches>,
+    focused: RwSignal<bool>,
+    history_depth: RwSignal<(u32, u32)>,
+    tracked_changes: RwSignal<Vec<TiptapTrackedChange>>,
+    comments: RwSignal<Vec<TiptapCommentThread>>,
+    focus: bool,
+}
 
 impl Default for TiptapEditorHandle {
     fn default() -> Self {
@@ -50,7 +65,29 @@ impl TiptapEditorHandle {
     /// Must be called within a reactive owner scope (e.g. inside a component body).
     #[must_use]
     pub fn new() -> Self {
-        Self(RwSignal::new(HandleState::NotReady))
+        Self {
+            state: RwSignal::new(HandleState::NotReady),
+            drafts: StoredValue::new(None),
+            content_changes: Trigger::new(),
+            search_matches: RwSignal::new(TiptapSearchMatches::default()),
+            focused: RwSignal::new(false),
+            history_depth: RwSignal::new((0, 0)),
+            tracked_changes: RwSignal::new(Vec::new()),
+            comments: RwSignal::new(Vec::new()),
+            focus: true,
+        }
+    }
+
+    /// Returns a copy of this handle whose commands do not focus the editor.
+    ///
+    /// See [`TiptapEditorInstance::without_focus`]. The copy shares the editor state of this
+    /// handle, so it can be created once and kept next to it.
+    #[must_use]
+    pub fn without_focus(&self) -> Self {
+        Self {
+            focus: false,
+            ..*self
+        }
     }
 
     /// Returns `true` when the underlying editor is ready.
@@ -59,7 +96,8 @@ impl TiptapEditorHandle {
     /// will re-run when readiness changes.
     #[must_use]
     pub fn is_ready(&self) -> bool {
-        self.0.with(|state| matches!(state, HandleState::Ready(_)))
+        self.state
+            .with(|state| matches!(state, HandleState::Ready(_)))
     }
 
     /// Returns the current live instance, if the editor is ready.
@@ -68,8 +106,8 @@ impl TiptapEditorHandle {
     /// readiness changes.
     #[must_use]
     pub fn instance(&self) -> Option<TiptapEditorInstance> {
-        self.0.with(|state| match state {
-            HandleState::Ready(instance) => Some(instance.clone()),
+        self.state.with(|state| match state {
+            HandleState::Ready(instance) => Some(self.configure(instance)),
             _ => None,
         })
     }
@@ -77,35 +115,169 @@ impl TiptapEditorHandle {
     /// Returns the current live instance without tracking the read reactively.
     #[must_use]
     pub fn instance_untracked(&self) -> Option<TiptapEditorInstance> {
-        self.0.with_untracked(|state| match state {
-            HandleState::Ready(instance) => Some(instance.clone()),
+        self.state.with_untracked(|state| match state {
+            HandleState::Ready(instance) => Some(self.configure(instance)),
             _ => None,
         })
     }
 
     #[cfg(not(feature = "ssr"))]
     pub(crate) fn set_instance(&self, instance: TiptapEditorInstance) {
-        self.0.set(HandleState::Ready(instance));
+        self.state.set(HandleState::Ready(instance));
     }
 
     pub(crate) fn mark_not_ready(&self) {
-        self.0.set(HandleState::NotReady);
+        self.state.set(HandleState::NotReady);
+        self.search_matches.set(TiptapSearchMatches::default());
+        self.focused.set(false);
+        self.history_depth.set((0, 0));
+        self.tracked_changes.set(Vec::new());
+        self.comments.set(Vec::new());
     }
 
     pub(crate) fn mark_destroyed(&self) {
-        self.0.set(HandleState::Destroyed);
+        self.state.set(HandleState::Destroyed);
+        self.search_matches.set(TiptapSearchMatches::default());
+        self.focused.set(false);
+        self.history_depth.set((0, 0));
+        self.tracked_changes.set(Vec::new());
+        self.comments.set(Vec::new());
     }
 
     pub(crate) fn mark_create_failed(&self) {
-        self.0.set(HandleState::CreateFailed);
+        self.state.set(HandleState::CreateFailed);
+    }
+
+    pub(crate) fn set_draft_binding(&self, drafts: Option<Arc<TiptapDraftBinding>>) {
+        self.drafts.set_value(drafts);
+    }
+
+    pub(crate) fn draft_binding(&self) -> Option<Arc<TiptapDraftBinding>> {
+        self.drafts.get_value()
+    }
+
+    /// Subscribes the current reactive scope to content changes of the editor.
+    pub(crate) fn track_content_changes(&self) {
+        self.content_changes.track();
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn notify_content_changed(&self) {
+        self.content_changes.notify();
+    }
+
+    /// Returns the matches of the active search as a signal.
+    ///
+    /// The count and the current match follow document changes and are reset when the search is
+    /// cleared or the editor is recreated.
+    #[must_use]
+    pub fn search_matches(&self) -> Signal<TiptapSearchMatches> {
+        self.search_matches.read_only().into()
+    }
+
+    /// Returns `true` while the editor has focus.
+    ///
+    /// This is a reactive read; calling it inside an `Effect` or a derived signal
+    /// will re-run when the editor gains or loses focus.
+    #[must_use]
+    pub fn is_focused(&self) -> bool {
+        self.focused.get()
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn set_focused(&self, focused: bool) {
+        self.focused.set(focused);
+    }
+
+    /// Returns the number of steps `undo` can revert.
+    ///
+    /// This is a reactive read, updated after every transaction that changes the history. It stays
+    /// `0` when the `history` extension is not active, e.g. while collaborating.
+    #[must_use]
+    pub fn undo_depth(&self) -> u32 {
+        self.history_depth.with(|(undo, _)| *undo)
+    }
+
+    /// Returns the number of steps `redo` can reapply.
+    ///
+    /// This is a reactive read, updated after every transaction that changes the history.
+    #[must_use]
+    pub fn redo_depth(&self) -> u32 {
+        self.history_depth.with(|(_, redo)| *redo)
+    }
+
+    /// Returns `true` when `undo` would revert a step.
+    ///
+    /// This is a reactive read, suited to enabling an undo button.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        self.undo_depth() > 0
+    }
+
+    /// Returns `true` when `redo` would reapply a step.
+    ///
+    /// This is a reactive read, suited to enabling a redo button.
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        self.redo_depth() > 0
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn set_history_depth(&self, undo_depth: u32, redo_depth: u32) {
+        self.history_depth.set((undo_depth, redo_depth));
+    }
+
+    /// Returns the pending changes recorded while tracking changes, in document order, as a
+    /// signal.
+    ///
+    /// The list is updated whenever a change is recorded, accepted, or rejected. It stays empty
+    /// unless the `track_changes` extension is active.
+    #[must_use]
+    pub fn tracked_changes(&self) -> Signal<Vec<TiptapTrackedChange>> {
+        self.tracked_changes.read_only().into()
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn set_tracked_changes(&self, changes: Vec<TiptapTrackedChange>) {
+        self.tracked_changes.set(changes);
+    }
+
+    /// Returns the comment threads anchored in the document, ordered by their first range, as a
+    /// signal.
+    ///
+    /// The ranges are updated with every change to the document, so a sidebar can align the
+    /// threads with their anchors. The list stays empty unless the `comments` extension is
+    /// active.
+    #[must_use]
+    pub fn comments(&self) -> Signal<Vec<TiptapCommentThread>> {
+        self.comments.read_only().into()
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn set_comments(&self, threads: Vec<TiptapCommentThread>) {
+        self.comments.set(threads);
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn set_search_matches(&self, matches: TiptapSearchMatches) {
+        self.search_matches.set(matches);
+    }
+
+    fn configure(&self, instance: &TiptapEditorInstance) -> TiptapEditorInstance {
+        if self.focus {
+            instance.clone()
+        } else {
+            instance.without_focus()
+        }
     }
 
     pub(super) fn with_instance<T>(
         &self,
         f: impl FnOnce(&TiptapEditorInstance) -> TiptapEditorResult<T>,
     ) -> TiptapEditorResult<T> {
-        self.0.with_untracked(|state| match state {
-            HandleState::Ready(instance) => f(instance),
+        self.state.with_untracked(|state| match state {
+            HandleState::Ready(instance) if self.focus => f(instance),
+            HandleState::Ready(instance) => f(&instance.without_focus()),
             HandleState::NotReady => Err(TiptapEditorError::NotReady.into()),
             HandleState::Destroyed => Err(TiptapEditorError::Destroyed.into()),
             HandleState::CreateFailed => Err(TiptapEditorError::CreateFailed.into()),
@@ -131,12 +303,18 @@ pub struct TiptapEditorInstance {
     pub(crate) id: String,
     /// Private instance generation used to reject stale instances after editor recreation.
     pub(crate) generation: u32,
+    /// Whether extension commands focus the editor before they run.
+    pub(crate) focus: bool,
 }
 
 impl TiptapEditorInstance {
     #[cfg(not(feature = "ssr"))]
     pub(crate) fn new(id: String, generation: u32) -> Self {
-        Self { id, generation }
+        Self {
+            id,
+            generation,
+            focus: true,
+        }
     }
 
     /// Returns the stable public editor id.
@@ -145,11 +323,27 @@ impl TiptapEditorInstance {
         &self.id
     }
 
+    /// Returns a copy of this instance whose commands do not focus the editor.
+    ///
+    /// Extension commands such as `toggle_bold` or `set_link` focus the editor by default, which
+    /// also scrolls the selection into view. Commands dispatched through the returned instance leave
+    /// both focus and scroll position alone, for example when they run from a side panel or a
+    /// timer; this includes selecting and replacing search matches. Commands that exist to change
+    /// focus or scroll position, like `focus`, `blur` and `scroll_into_view`, are unaffected.
+    #[must_use]
+    pub fn without_focus(&self) -> Self {
+        Self {
+            focus: false,
+            ..self.clone()
+        }
+    }
+
     pub(super) fn dispatch(&self, command: EditorCommand) -> TiptapEditorResult<()> {
         Ok(crate::runtime::command(
             self.id.clone(),
             self.generation,
             command,
+            self.focus,
         )?)
     }
 }
@@ -194,4 +388,25 @@ mod tests {
             assert_that!(unavailable_error(handle)).is_equal_to(TiptapEditorError::CreateFailed);
         });
     }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn handles_without_focus_share_the_instance_of_their_handle() {
+        Owner::new().with(|| {
+            let handle = TiptapEditorHandle::new();
+            let unfocused = handle.without_focus();
+            handle.set_instance(TiptapEditorInstance::new("editor".to_owned(), 3));
+
+            let instance = unfocused.instance_untracked().unwrap();
+            assert_that!(instance.id()).is_equal_to("editor");
+            assert_that!(instance.focus).is_false();
+            assert_that!(handle.instance_untracked().unwrap().focus).is_true();
+            assert_that!(
+                unfocused
+                    .with_instance(|instance| Ok(instance.focus))
+                    .unwrap()
+            )
+            .is_false();
+        });
+    }
 }
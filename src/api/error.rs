@@ -73,6 +73,78 @@ pub enum TiptapEditorError {
         message: String,
     },
 
+    /// A document change does not directly follow the previously applied version.
+    ///
+    /// At least one change was missed or replayed. Resynchronize from a fresh snapshot, for
+    /// example through [`TiptapEditorHandle::document_snapshot`].
+    ///
+    /// [`TiptapEditorHandle::document_snapshot`]: crate::TiptapEditorHandle::document_snapshot
+    #[error("expected document change version {expected}, received version {received}")]
+    DocumentVersionGap {
+        /// The version that would have directly followed the applied state.
+        expected: u64,
+        /// The version of the rejected change.
+        received: u64,
+    },
+
+    /// A document change could not be applied.
+    #[error("could not apply document change: {0}")]
+    InvalidDocumentChange(String),
+
+    /// Collaboration steps were made against another version than the authority's current one.
+    ///
+    /// The submitting client has not received all confirmed steps yet. It should apply the steps
+    /// returned by [`TiptapCollabAuthority::steps_since`] and submit its rebased steps again.
+    ///
+    /// [`TiptapCollabAuthority::steps_since`]: crate::TiptapCollabAuthority::steps_since
+    #[cfg(feature = "collab")]
+    #[error(
+        "collaboration steps were made against version {received}, but the authority is at version {current}"
+    )]
+    CollabVersionConflict {
+        /// The current version of the authority.
+        current: u64,
+        /// The version the rejected steps were made against.
+        received: u64,
+    },
+
+    /// The authority's step log does not cover the requested version.
+    ///
+    /// The version is either newer than the authority's or older than its first retained step.
+    /// The client should be recreated from the authority's current document.
+    #[cfg(feature = "collab")]
+    #[error("collaboration steps since version {0} are not available")]
+    CollabStepsUnavailable(u64),
+
+    /// The operation requires a draft store, but the editor was configured without one.
+    #[error("no draft store is configured for this Tiptap editor")]
+    DraftsDisabled,
+
+    /// A draft could not be loaded, saved, or discarded.
+    #[error("could not access the Tiptap draft store: {0}")]
+    DraftStorage(String),
+
+    /// A version could not be listed, loaded, saved, or removed.
+    #[error("could not access the Tiptap version store: {0}")]
+    VersionStorage(String),
+
+    /// The requested version is not in the version store.
+    #[error("there is no version {0} of this document")]
+    VersionNotFound(u64),
+
+    /// The save function of an autosave returned an error.
+    #[error("could not save the Tiptap document: {0}")]
+    AutosaveFailed(String),
+
+    /// The uploader of an image upload returned an error.
+    #[error("could not upload the image '{name}': {message}")]
+    ImageUploadFailed {
+        /// The name of the uploaded file.
+        name: String,
+        /// The error returned by the uploader.
+        message: String,
+    },
+
     /// The browser bridge returned an error.
     #[error("Tiptap bridge error: {0}")]
     BridgeError(String),
@@ -77,6 +77,20 @@ pub enum TiptapExtension {
     #[cfg(feature = "youtube")]
     /// The Tiptap `YouTube` extension.
     Youtube,
+    #[cfg(feature = "collaboration")]
+    /// The Tiptap collaboration extension, binding the document to a Yjs `Y.Doc`.
+    ///
+    /// Only editors created with a collaboration configuration are bound. Do not combine it with
+    /// the history extension, as Yjs brings its own undo manager.
+    Collaboration,
+    #[cfg(feature = "track_changes")]
+    /// Records edits as suggestions to accept or reject while tracking changes, see
+    /// [`TiptapEditorHandle::enable_track_changes`](crate::TiptapEditorHandle::enable_track_changes).
+    TrackChanges,
+    #[cfg(feature = "comments")]
+    /// Anchors comment threads to ranges of text, see
+    /// [`TiptapEditorHandle::add_comment`](crate::TiptapEditorHandle::add_comment).
+    Comments,
 }
 
 impl TiptapExtension {
@@ -132,6 +146,12 @@ impl TiptapExtension {
             Self::Placeholder => "placeholder",
             #[cfg(feature = "youtube")]
             Self::Youtube => "youtube",
+            #[cfg(feature = "collaboration")]
+            Self::Collaboration => "collaboration",
+            #[cfg(feature = "track_changes")]
+            Self::TrackChanges => "track_changes",
+            #[cfg(feature = "comments")]
+            Self::Comments => "comments",
         }
     }
 
@@ -187,6 +207,12 @@ impl TiptapExtension {
             Self::Placeholder,
             #[cfg(feature = "youtube")]
             Self::Youtube,
+            #[cfg(feature = "collaboration")]
+            Self::Collaboration,
+            #[cfg(feature = "track_changes")]
+            Self::TrackChanges,
+            #[cfg(feature = "comments")]
+            Self::Comments,
         ]
     }
 
@@ -0,0 +1,187 @@
+use super::markdown::to_markdown;
+use super::{TiptapContent, TiptapEditorHandle, TiptapEditorReport, TiptapFormFormat};
+use leptos::prelude::*;
+
+/// Form participation of `<TiptapEditor/>`.
+pub(crate) struct TiptapFormField {
+    pub(crate) name: String,
+    pub(crate) format: TiptapFormFormat,
+    pub(crate) required: Signal<bool>,
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
+    pub(crate) initial_content: TiptapContent,
+}
+
+/// Renders a textarea that submits the editor document with the surrounding form.
+///
+/// The textarea is visually hidden but stays focusable, so browsers can point at it when its
+/// constraints are violated. It forwards that focus to the editor.
+pub(crate) fn form_value(
+    handle: TiptapEditorHandle,
+    field: TiptapFormField,
+    on_error: Option<Callback<TiptapEditorReport>>,
+) -> impl IntoView {
+    let TiptapFormField {
+        name,
+        format,
+        required,
+        min_length,
+        max_length,
+        initial_content,
+    } = field;
+    let textarea = NodeRef::<leptos::html::Textarea>::new();
+    let value = RwSignal::new(String::new());
+    let text = RwSignal::new(String::new());
+
+    let sync = move || {
+        let Some(instance) = handle.instance_untracked() else {
+            return;
+        };
+        let content = match format {
+            TiptapFormFormat::Html => instance.get_html(),
+            TiptapFormFormat::Json => instance.get_json().map(|json| json.to_string()),
+            TiptapFormFormat::Markdown => instance.get_json().map(|json| to_markdown(&json)),
+        };
+        match content.and_then(|content| Ok((content, instance.get_text()?))) {
+            Ok((content, content_text)) => {
+                value.set(content);
+                text.set(content_text);
+            }
+            Err(err) => {
+                on_error.inspect(|cb| cb.run(err));
+            }
+        }
+    };
+
+    Effect::new(move |_| {
+        handle.track_content_changes();
+        if handle.is_ready() {
+            sync();
+        }
+    });
+
+    Effect::new(move |_| {
+        let message =
+            text.with(|text| validation_message(text, required.get(), min_length, max_length));
+        if let Some(textarea) = textarea.get() {
+            textarea.set_custom_validity(message.as_deref().unwrap_or_default());
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    reset_with_form(textarea, handle, initial_content, sync, on_error);
+    #[cfg(feature = "ssr")]
+    let _ = initial_content;
+
+    view! {
+        <textarea
+            node_ref=textarea
+            class="leptos-tiptap-form-value"
+            name=name
+            tabindex="-1"
+            aria-hidden="true"
+            style="display: block; width: 1px; height: 1px; margin: -1px 0 0; padding: 0; border: 0; opacity: 0; resize: none; pointer-events: none;"
+            prop:value=move || value.get()
+            on:focus=move |_| {
+                if let Err(err) = handle.focus() {
+                    on_error.inspect(|cb| cb.run(err));
+                }
+            }
+        ></textarea>
+    }
+}
+
+/// Restores the initial content when the surrounding form is reset.
+#[cfg(not(feature = "ssr"))]
+fn reset_with_form(
+    textarea: NodeRef<leptos::html::Textarea>,
+    handle: TiptapEditorHandle,
+    initial_content: TiptapContent,
+    sync: impl Fn() + Copy + 'static,
+    on_error: Option<Callback<TiptapEditorReport>>,
+) {
+    use send_wrapper::SendWrapper;
+    use std::time::Duration;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen::prelude::Closure;
+
+    Effect::new(move |_| {
+        let Some(form) = textarea.get().and_then(|textarea| textarea.form()) else {
+            return;
+        };
+
+        let initial_content = initial_content.clone();
+        let on_reset = Closure::<dyn Fn()>::new(move || {
+            if let Err(err) = handle.set_content(initial_content.clone()) {
+                on_error.inspect(|cb| cb.run(err));
+            }
+            // The browser resets the textarea after this listener, so write the value back.
+            set_timeout(sync, Duration::ZERO);
+        });
+        if form
+            .add_event_listener_with_callback("reset", on_reset.as_ref().unchecked_ref())
+            .is_err()
+        {
+            return;
+        }
+
+        let listener = SendWrapper::new((form, on_reset));
+        on_cleanup(move || {
+            let (form, on_reset) = &*listener;
+            let _ = form
+                .remove_event_listener_with_callback("reset", on_reset.as_ref().unchecked_ref());
+        });
+    });
+}
+
+/// Mirrors the browser's messages for `required`, `minlength`, and `maxlength`. Lengths count the
+/// characters of the text content, excluding line breaks between blocks.
+fn validation_message(
+    text: &str,
+    required: bool,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+) -> Option<String> {
+    if text.trim().is_empty() {
+        return required.then(|| "Please fill out this field.".to_owned());
+    }
+
+    let length = text.chars().filter(|char| *char != '\n').count();
+    if let Some(min_length) = min_length.filter(|min_length| length < *min_length) {
+        return Some(format!(
+            "Please lengthen this text to {min_length} characters or more (you are currently using {length} characters)."
+        ));
+    }
+    if let Some(max_length) = max_length.filter(|max_length| length > *max_length) {
+        return Some(format!(
+            "Please shorten this text to {max_length} characters or less (you are currently using {length} characters)."
+        ));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+
+    #[test]
+    fn empty_documents_are_only_invalid_when_required() {
+        assert_that!(validation_message("\n\n", false, Some(3), None)).is_none();
+        assert_that!(validation_message("\n\n", true, None, None))
+            .is_equal_to(Some("Please fill out this field.".to_owned()));
+    }
+
+    #[test]
+    fn lengths_ignore_block_separators() {
+        assert_that!(validation_message("ab\n\ncd", true, Some(4), Some(4))).is_none();
+        assert_that!(validation_message("ab\n\ncd", true, Some(5), None)).is_equal_to(Some(
+            "Please lengthen this text to 5 characters or more (you are currently using 4 characters)."
+                .to_owned(),
+        ));
+        assert_that!(validation_message("ab\n\ncd", true, None, Some(3))).is_equal_to(Some(
+            "Please shorten this text to 3 characters or less (you are currently using 4 characters)."
+                .to_owned(),
+        ));
+    }
+}
@@ -0,0 +1,191 @@
+#[cfg(not(feature = "ssr"))]
+use super::TiptapEditorHandle;
+use super::{
+    TiptapEditorError, TiptapEditorInstance, TiptapEditorReport, TiptapImageResource,
+    TiptapTransferFile,
+};
+use crate::protocol::EditorCommand;
+#[cfg(not(feature = "ssr"))]
+use leptos::prelude::*;
+#[cfg(not(feature = "ssr"))]
+use leptos::task::spawn_local;
+use std::fmt::{self, Debug, Display};
+use std::pin::Pin;
+use std::sync::Arc;
+#[cfg(not(feature = "ssr"))]
+use std::sync::atomic::{AtomicU32, Ordering};
+
+type UploadFuture = Pin<Box<dyn Future<Output = Result<TiptapImageResource, TiptapEditorReport>>>>;
+type UploadFn = Arc<dyn Fn(TiptapTransferFile, TiptapUploadProgress) -> UploadFuture + Send + Sync>;
+
+#[cfg(not(feature = "ssr"))]
+static NEXT_UPLOAD_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Uploads images pasted or dropped into the editor, passed through the `image_upload` option.
+///
+/// While an image is uploading, a placeholder with the `image-upload-placeholder` class and a
+/// `<progress>` element is shown where it was pasted or dropped. The placeholder is not part of
+/// the document, so it is never saved. When the upload succeeds, it is replaced with an image
+/// node of the returned resource, which needs the image extension. When it fails, the placeholder
+/// is removed and the error is reported through `on_error` as
+/// [`TiptapEditorError::ImageUploadFailed`].
+///
+/// Only files with an `image/` MIME type are uploaded, and only when the pasted or dropped
+/// content has no HTML, which the browser would otherwise insert together with the files. Pastes
+/// and drops that an `on_paste` or `on_drop` callback cancels or substitutes are not uploaded.
+///
+/// ```
+/// use leptos_tiptap::{TiptapImageResource, TiptapImageUpload};
+///
+/// let upload = TiptapImageUpload::new(|file| async move {
+///     // Send `file.blob()` to the server and use the URL it responds with.
+///     Ok::<_, String>(TiptapImageResource {
+///         src: format!("https://cdn.example.com/{}", file.name()),
+///         alt: None,
+///         title: None,
+///     })
+/// });
+/// ```
+#[derive(Clone)]
+pub struct TiptapImageUpload {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    upload: UploadFn,
+}
+
+impl Debug for TiptapImageUpload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TiptapImageUpload").finish_non_exhaustive()
+    }
+}
+
+impl TiptapImageUpload {
+    /// Creates an image upload calling `upload` for every pasted or dropped image. Its
+    /// placeholder shows an indeterminate progress.
+    pub fn new<U, Fut, E>(upload: U) -> Self
+    where
+        U: Fn(TiptapTransferFile) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<TiptapImageResource, E>> + 'static,
+        E: Display,
+    {
+        Self::with_progress(move |file, _progress| upload(file))
+    }
+
+    /// Creates an image upload calling `upload` for every pasted or dropped image, which reports
+    /// its progress through the passed [`TiptapUploadProgress`].
+    pub fn with_progress<U, Fut, E>(upload: U) -> Self
+    where
+        U: Fn(TiptapTransferFile, TiptapUploadProgress) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<TiptapImageResource, E>> + 'static,
+        E: Display,
+    {
+        Self {
+            upload: Arc::new(move |file, progress| {
+                let name = file.name().to_owned();
+                let uploaded = upload(file, progress);
+                Box::pin(async move {
+                    uploaded.await.map_err(|err| {
+                        TiptapEditorError::ImageUploadFailed {
+                            name,
+                            message: err.to_string(),
+                        }
+                        .into()
+                    })
+                })
+            }),
+        }
+    }
+
+    /// Starts uploading the image files among `files`, and returns whether there were any.
+    ///
+    /// Their placeholders are shown at `position`, or replace the selection when there is none.
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn start(
+        &self,
+        editor: TiptapEditorHandle,
+        files: Vec<TiptapTransferFile>,
+        position: Option<u32>,
+        on_error: Option<Callback<TiptapEditorReport>>,
+    ) -> bool {
+        let images = files
+            .into_iter()
+            .filter(|file| file.mime_type().starts_with("image/"))
+            .collect::<Vec<_>>();
+        let Some(instance) = editor.instance_untracked() else {
+            return false;
+        };
+        if images.is_empty() {
+            return false;
+        }
+
+        for file in images {
+            let upload_id = NEXT_UPLOAD_ID.fetch_add(1, Ordering::Relaxed);
+            if let Err(err) = instance.dispatch(EditorCommand::StartImageUpload {
+                upload_id,
+                position,
+            }) {
+                report_upload_error(on_error, err);
+                continue;
+            }
+
+            let uploaded = (self.upload)(
+                file,
+                TiptapUploadProgress {
+                    instance: instance.clone(),
+                    upload_id,
+                },
+            );
+            let instance = instance.clone();
+            spawn_local(async move {
+                let finished = match uploaded.await {
+                    Ok(image) => {
+                        instance.dispatch(EditorCommand::FinishImageUpload { upload_id, image })
+                    }
+                    Err(err) => {
+                        // The editor may have been destroyed while uploading, leaving nothing to
+                        // remove.
+                        let _ = instance.dispatch(EditorCommand::CancelImageUpload { upload_id });
+                        Err(err)
+                    }
+                };
+                if let Err(err) = finished {
+                    report_upload_error(on_error, err);
+                }
+            });
+        }
+        true
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn report_upload_error(on_error: Option<Callback<TiptapEditorReport>>, err: TiptapEditorReport) {
+    tracing::error!(?err, "TipTap image upload failed.");
+    // The owner of the callback may have been disposed while uploading.
+    on_error.inspect(|cb| {
+        cb.try_run(err);
+    });
+}
+
+/// Reports the progress of an image upload, shown by its placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TiptapUploadProgress {
+    instance: TiptapEditorInstance,
+    upload_id: u32,
+}
+
+impl TiptapUploadProgress {
+    /// Sets the uploaded fraction, between `0.0` and `1.0`.
+    ///
+    /// Does nothing once the upload finished, its placeholder was deleted, or the editor was
+    /// destroyed.
+    pub fn set(&self, fraction: f64) {
+        if let Err(err) = self
+            .instance
+            .dispatch(EditorCommand::SetImageUploadProgress {
+                upload_id: self.upload_id,
+                progress: fraction.clamp(0.0, 1.0),
+            })
+        {
+            tracing::debug!(?err, "Could not show the TipTap image upload progress.");
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use serde_json::Value;
+
+/// Serializes a Tiptap JSON document as Markdown, following `CommonMark`.
+///
+/// Covers the nodes and marks of the bundled extensions. Other nodes keep their content, and other
+/// marks their text.
+pub(crate) fn to_markdown(doc: &Value) -> String {
+    blocks(doc).join("\n\n")
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+fn node_type(node: &Value) -> &str {
+    node.get("type").and_then(Value::as_str).unwrap_or_default()
+}
+
+fn attribute<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs").and_then(|attrs| attrs.get(name))
+}
+
+fn string_attribute<'a>(node: &'a Value, name: &str) -> &'a str {
+    attribute(node, name)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+fn is_inline(node: &Value) -> bool {
+    matches!(node_type(node), "text" | "hardBreak" | "image")
+}
+
+/// Renders the block children of `node`, one entry per block.
+fn blocks(node: &Value) -> Vec<String> {
+    let children = children(node);
+    if children.iter().any(is_inline) {
+        return vec![inline(children)];
+    }
+    // Empty paragraphs have no Markdown equivalent.
+    children
+        .iter()
+        .filter_map(block)
+        .filter(|block| !block.is_empty())
+        .collect()
+}
+
+fn block(node: &Value) -> Option<String> {
+    let markdown = match node_type(node) {
+        "paragraph" => escape_block_start(&inline(children(node))),
+        "heading" => {
+            let level = attribute(node, "level")
+                .and_then(Value::as_u64)
+                .unwrap_or(1)
+                .clamp(1, 6);
+            let level = usize::try_from(level).unwrap_or(1);
+            format!("{} {}", "#".repeat(level), inline(children(node)))
+        }
+        "blockquote" => blocks(node)
+            .join("\n\n")
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    ">".to_owned()
+                } else {
+                    format!("> {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "bulletList" => list(node, |_| "- ".to_owned()),
+        "orderedList" => {
+            let start = attribute(node, "start")
+                .and_then(Value::as_u64)
+                .unwrap_or(1);
+            list(node, |index| format!("{}. ", start + index))
+        }
+        "codeBlock" => {
+            let code: String = children(node)
+                .iter()
+                .filter_map(|child| child.get("text").and_then(Value::as_str))
+                .collect();
+            let fence = "`".repeat(longest_run(&code, '`').max(2) + 1);
+            let language = string_attribute(node, "language");
+            format!("{fence}{language}\n{code}\n{fence}")
+        }
+        "horizontalRule" => "---".to_owned(),
+        "youtube" => {
+            let src = string_attribute(node, "src");
+            format!("<{src}>")
+        }
+        _ if is_inline(node) => inline(std::slice::from_ref(node)),
+        _ => {
+            let blocks = blocks(node);
+            if blocks.is_empty() {
+                return None;
+            }
+            blocks.join("\n\n")
+        }
+    };
+    Some(markdown)
+}
+
+/// Renders the items of a list. Lines after the marker are indented to its width, and the blocks
+/// of an item are separated by blank lines, so that they are not read as one paragraph.
+fn list(node: &Value, marker: impl Fn(u64) -> String) -> String {
+    children(node)
+        .iter()
+        .zip(0..)
+        .map(|(item, index)| {
+            let marker = marker(index);
+            let indent = " ".repeat(marker.len());
+            let content = blocks(item).join("\n\n");
+            let mut lines = content.lines();
+            let first = lines.next().unwrap_or_default();
+            let mut rendered = format!("{marker}{first}");
+            for line in lines {
+                rendered.push('\n');
+                if !line.is_empty() {
+                    rendered.push_str(&indent);
+                    rendered.push_str(line);
+                }
+            }
+            rendered
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders inline content. Adjacent nodes sharing a mark are wrapped in its delimiters once.
+fn inline(nodes: &[Value]) -> String {
+    let runs = nodes
+        .iter()
+        .map(|node| {
+            let marks = node
+                .get("marks")
+                .and_then(Value::as_array)
+                .map_or(&[][..], Vec::as_slice);
+            (marks, node)
+        })
+        .collect::<Vec<_>>();
+    marked(&runs, 0)
+}
+
+fn marked(runs: &[(&[Value], &Value)], depth: usize) -> String {
+    let mut rendered = String::new();
+    let mut index = 0;
+    while let Some((marks, node)) = runs.get(index) {
+        let Some(mark) = marks.get(depth) else {
+            rendered.push_str(&leaf(node, marks));
+            index += 1;
+            continue;
+        };
+
+        let end = runs[index..]
+            .iter()
+            .position(|(marks, _)| marks.get(depth) != Some(mark))
+            .map_or(runs.len(), |offset| index + offset);
+        rendered.push_str(&wrap(mark, &marked(&runs[index..end], depth + 1)));
+        index = end;
+    }
+    rendered
+}
+
+fn leaf(node: &Value, marks: &[Value]) -> String {
+    match node_type(node) {
+        "text" => {
+            let text = node.get("text").and_then(Value::as_str).unwrap_or_default();
+            if marks.iter().any(|mark| node_type(mark) == "code") {
+                text.to_owned()
+            } else {
+                escape(text)
+            }
+        }
+        "hardBreak" => "\\\n".to_owned(),
+        "image" => {
+            let alt = escape(string_attribute(node, "alt"));
+            let src = string_attribute(node, "src");
+            match attribute(node, "title").and_then(Value::as_str) {
+                Some(title) if !title.is_empty() => {
+                    format!("![{alt}]({src} \"{}\")", title.replace('"', "\\\""))
+                }
+                _ => format!("![{alt}]({src})"),
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+fn wrap(mark: &Value, content: &str) -> String {
+    let delimiter = match node_type(mark) {
+        "bold" => "**",
+        "italic" => "_",
+        "strike" => "~~",
+        "code" => {
+            let fence = "`".repeat(longest_run(content, '`') + 1);
+            // Code spans drop one space on each side, so pad content that starts or ends with a
+            // backtick.
+            let padding = if content.starts_with('`') || content.ends_with('`') {
+                " "
+            } else {
+                ""
+            };
+            return format!("{fence}{padding}{content}{padding}{fence}");
+        }
+        "link" => {
+            let href = string_attribute(mark, "href");
+            return format!("[{content}](<{href}>)");
+        }
+        _ => return content.to_owned(),
+    };
+
+    // Emphasis can not start or end with whitespace, so it is moved outside of the delimiters.
+    let inner = content.trim();
+    if inner.is_empty() {
+        return content.to_owned();
+    }
+    let leading = &content[..content.len() - content.trim_start().len()];
+    let trailing = &content[content.trim_end().len()..];
+    format!("{leading}{delimiter}{inner}{delimiter}{trailing}")
+}
+
+fn longest_run(text: &str, char: char) -> usize {
+    text.split(|candidate| candidate != char)
+        .map(str::len)
+        .max()
+        .unwrap_or_default()
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        if matches!(
+            char,
+            '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '<' | '>' | '|'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+    escaped
+}
+
+/// Escapes a paragraph start that would otherwise read as a heading, list item, or rule.
+fn escape_block_start(text: &str) -> String {
+    if text.starts_with(['#', '-', '+', '=']) {
+        return format!("\\{text}");
+    }
+
+    let digits = text.len()
+        - text
+            .trim_start_matches(|char: char| char.is_ascii_digit())
+            .len();
+    if digits > 0 && text[digits..].starts_with(['.', ')']) {
+        return format!("{}\\{}", &text[..digits], &text[digits..]);
+    }
+    text.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    fn text(text: &str, marks: &[Value]) -> Value {
+        json!({"type": "text", "text": text, "marks": marks})
+    }
+
+    #[test]
+    fn serializes_blocks() {
+        let doc = json!({"type": "doc", "content": [
+            {"type": "heading", "attrs": {"level": 2}, "content": [text("Title", &[])]},
+            {"type": "paragraph", "content": [
+                text("1. not a list", &[]),
+                {"type": "hardBreak"},
+                text("a*b", &[]),
+            ]},
+            {"type": "bulletList", "content": [
+                {"type": "listItem", "content": [
+                    {"type": "paragraph", "content": [text("one", &[])]},
+                    {"type": "orderedList", "attrs": {"start": 3}, "content": [
+                        {"type": "listItem", "content": [
+                            {"type": "paragraph", "content": [text("nested", &[])]},
+                        ]},
+                    ]},
+                ]},
+                {"type": "listItem", "content": [
+                    {"type": "paragraph", "content": [text("two", &[])]},
+                    {"type": "paragraph", "content": [text("paragraphs", &[])]},
+                ]},
+            ]},
+            {"type": "blockquote", "content": [
+                {"type": "paragraph", "content": [text("quoted", &[])]},
+                {"type": "paragraph", "content": [text("twice", &[])]},
+            ]},
+            {"type": "codeBlock", "attrs": {"language": "rust"}, "content": [text("let a = `b`;", &[])]},
+            {"type": "horizontalRule"},
+            {"type": "paragraph"},
+        ]});
+
+        assert_that!(to_markdown(&doc)).is_equal_to(
+            "## Title\n\n\
+             1\\. not a list\\\na\\*b\n\n\
+             - one\n\n  3. nested\n- two\n\n  paragraphs\n\n\
+             > quoted\n>\n> twice\n\n\
+             ```rust\nlet a = `b`;\n```\n\n\
+             ---"
+            .to_owned(),
+        );
+    }
+
+    #[test]
+    fn merges_adjacent_marks() {
+        let bold = json!({"type": "bold"});
+        let italic = json!({"type": "italic"});
+        let link = json!({"type": "link", "attrs": {"href": "https://example.com"}});
+        let doc = json!({"type": "doc", "content": [
+            {"type": "paragraph", "content": [
+                text("bold ", std::slice::from_ref(&bold)),
+                text("both", &[bold, italic]),
+                text(" ", &[]),
+                text("a_b", &[link, json!({"type": "code"})]),
+                {"type": "image", "attrs": {"src": "x.png", "alt": "X", "title": null}},
+            ]},
+        ]});
+
+        assert_that!(to_markdown(&doc))
+            .is_equal_to("**bold _both_** [`a_b`](<https://example.com>)![X](x.png)".to_owned());
+    }
+}
@@ -1,26 +1,83 @@
+#[cfg(feature = "collab")]
+mod collab;
+#[cfg(feature = "collaboration")]
+mod collaboration;
+mod collaboration_cursor;
 mod commands;
+#[cfg(feature = "comments")]
+mod comments;
 #[cfg(feature = "component")]
 pub(crate) mod component;
 mod content;
+mod custom_extensions;
+mod decorations;
+mod diff;
+mod document_changes;
+mod drafts;
 mod editor;
 mod error;
 mod extensions;
+#[cfg(feature = "component")]
+mod form;
+mod image_upload;
+#[cfg(feature = "component")]
+mod markdown;
+mod node_views;
+mod search;
+mod shortcuts;
+mod specs;
+#[cfg(feature = "track_changes")]
+mod track_changes;
 mod types;
+mod use_tiptap_autosave;
 mod use_tiptap_editor;
+mod versions;
+#[cfg(feature = "component")]
+pub(crate) mod viewer;
 
+#[cfg(feature = "collab")]
+pub use collab::TiptapCollabAuthority;
+pub use custom_extensions::TiptapCustomExtension;
+pub use diff::{TiptapAttributeChange, TiptapDiffOperation, TiptapDocumentDiff, TiptapTextChange};
+pub use document_changes::TiptapDocumentMirror;
+pub(crate) use drafts::TiptapDraftBinding;
+pub use drafts::{
+    TiptapDraftConfig, TiptapDraftStore, TiptapIndexedDbDraftStore, TiptapLocalStorageDraftStore,
+    TiptapMemoryDraftStore,
+};
 pub use editor::{TiptapEditorHandle, TiptapEditorInstance};
 pub use error::{TiptapEditorError, TiptapEditorReport, TiptapEditorResult};
 pub use extensions::TiptapExtension;
+pub use image_upload::{TiptapImageUpload, TiptapUploadProgress};
+#[cfg(not(feature = "ssr"))]
+pub(crate) use node_views::TiptapNodeViewContentElement;
+pub use node_views::{TiptapNodeView, TiptapNodeViewContent, TiptapNodeViewProps};
+pub use shortcuts::TiptapShortcuts;
+#[cfg(feature = "collab")]
+pub use types::TiptapCollabUpdate;
 pub use types::{
-    TiptapActiveKey, TiptapActiveState, TiptapAttributes, TiptapCodeBlockAttributes, TiptapContent,
-    TiptapFocusOptions, TiptapFocusTarget, TiptapHeadingLevel, TiptapHighlightAttributes,
-    TiptapImageResource, TiptapInsertContentOptions, TiptapLinkResource, TiptapListKind,
-    TiptapMarkName, TiptapMarkOptions, TiptapNodeName, TiptapParseOptions, TiptapPositionOrRange,
-    TiptapRange, TiptapSchemaTarget, TiptapSelectionState, TiptapSetContentOptions,
-    TiptapSplitBlockOptions, TiptapTextAlign, TiptapToggleListOptions, TiptapWhitespaceMode,
-    TiptapYoutubeVideoResource,
+    TiptapActiveKey, TiptapActiveState, TiptapAttributes, TiptapCodeBlockAttributes,
+    TiptapCollaborationConfig, TiptapCollaborationUser, TiptapCommentThread, TiptapContent,
+    TiptapDecoration, TiptapDecorationClick, TiptapDocumentChange, TiptapDocumentChangeFormat,
+    TiptapDocumentChangePayload, TiptapDraft, TiptapDropEvent, TiptapFocusOptions,
+    TiptapFocusTarget, TiptapFormFormat, TiptapHeadingLevel, TiptapHighlightAttributes,
+    TiptapImageResource, TiptapInputRule, TiptapInsertContentOptions, TiptapJsonPatchOperation,
+    TiptapLinkResource, TiptapListKind, TiptapMarkName, TiptapMarkOptions, TiptapMarkSpec,
+    TiptapNodeName, TiptapNodeSpec, TiptapParseOptions, TiptapPasteEvent, TiptapPasteRule,
+    TiptapPositionOrRange, TiptapRange, TiptapRemoteCursor, TiptapSchemaTarget,
+    TiptapSearchMatches, TiptapSearchOptions, TiptapSelectionRange, TiptapSelectionState,
+    TiptapSetContentOptions, TiptapSplitBlockOptions, TiptapTextAlign, TiptapToggleListOptions,
+    TiptapTrackedChange, TiptapTrackedChangeKind, TiptapTransaction, TiptapTransactionOrigin,
+    TiptapTransferAction, TiptapTransferFile, TiptapVersion, TiptapVersionDetails,
+    TiptapWhitespaceMode, TiptapYoutubeVideoResource,
+};
+pub use types::{TiptapCollabConfig, TiptapCollabSteps};
+pub use use_tiptap_autosave::{
+    TiptapAutosaveOptions, TiptapAutosaveRequest, TiptapAutosaveResponse, TiptapAutosaveStatus,
+    UseTiptapAutosaveReturn, use_tiptap_autosave, use_tiptap_autosave_with_options,
 };
 pub use use_tiptap_editor::{
     UseTiptapEditorAttrs, UseTiptapEditorInput, UseTiptapEditorProps, UseTiptapEditorReturn,
     use_tiptap_editor,
 };
+pub use versions::{TiptapMemoryVersionStore, TiptapVersionHistory, TiptapVersionStore};
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use leptos::prelude::*;
+
+use crate::protocol::EditorCommand;
+
+use super::{TiptapAttributes, TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult};
+
+/// The props passed to the component rendering a node view.
+#[derive(Debug, Clone, Copy)]
+pub struct TiptapNodeViewProps {
+    /// The attributes of the node. Updated whenever the node changes.
+    pub attributes: Signal<TiptapAttributes>,
+    /// Whether the node is selected as a whole.
+    pub selected: Signal<bool>,
+    /// Merges the given attributes into the attributes of the node, as one undoable change.
+    pub update_attributes: Callback<TiptapAttributes>,
+}
+
+type RenderNodeView = Arc<dyn Fn(TiptapNodeViewProps) -> AnyView + Send + Sync>;
+
+/// Renders the nodes of a [`TiptapNodeSpec`](crate::TiptapNodeSpec) through a Leptos component.
+///
+/// The component is mounted into the element of the node when `ProseMirror` creates its view, and
+/// unmounted, disposing its reactive owner, when the view is destroyed or recreated. Contexts
+/// provided above the editor are available. For nodes with content, render
+/// [`TiptapNodeViewContent`] where the editable content belongs.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_tiptap::{TiptapNodeSpec, TiptapNodeView, TiptapNodeViewProps};
+///
+/// let poll = TiptapNodeSpec::new("poll").group("block").atom(true).attribute("votes", 0);
+/// let poll_view = TiptapNodeView::new("poll", |props: TiptapNodeViewProps| {
+///     let votes = move || props.attributes.with(|attributes| {
+///         attributes.get("votes").and_then(serde_json::Value::as_u64).unwrap_or_default()
+///     });
+///     view! {
+///         <button on:click=move |_| {
+///             props.update_attributes.run([("votes", votes() + 1)].into_iter().collect());
+///         }>
+///             {move || format!("{} votes", votes())}
+///         </button>
+///     }
+/// });
+/// ```
+#[derive(Clone)]
+pub struct TiptapNodeView {
+    name: String,
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    render: RenderNodeView,
+}
+
+impl TiptapNodeView {
+    /// Renders the nodes of the node spec named `name` with `render`.
+    #[must_use]
+    pub fn new<V: IntoView + 'static>(
+        name: impl Into<String>,
+        render: impl Fn(TiptapNodeViewProps) -> V + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            render: Arc::new(move |props| render(props).into_any()),
+        }
+    }
+
+    /// Returns the name of the node spec rendered by this view.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn render(&self, props: TiptapNodeViewProps) -> AnyView {
+        (self.render)(props)
+    }
+}
+
+impl std::fmt::Debug for TiptapNodeView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TiptapNodeView")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The element `ProseMirror` renders the content of a node view into.
+#[cfg(not(feature = "ssr"))]
+#[derive(Clone)]
+pub(crate) struct TiptapNodeViewContentElement(
+    pub(crate) send_wrapper::SendWrapper<leptos::web_sys::Element>,
+);
+
+/// Places the editable content of the node inside of a node view component.
+///
+/// Render it once in the component of a node view whose node spec has content.
+#[component]
+pub fn TiptapNodeViewContent() -> impl IntoView {
+    let node_ref = NodeRef::<leptos::html::Div>::new();
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let content = use_context::<TiptapNodeViewContentElement>();
+        Effect::new(move || {
+            if let (Some(slot), Some(content)) = (node_ref.get(), &content) {
+                // Moving the element keeps the content `ProseMirror` already rendered into it.
+                let _ = slot.append_child(&content.0);
+            }
+        });
+    }
+
+    view! { <div data-node-view-content-slot="" node_ref=node_ref></div> }
+}
+
+impl TiptapEditorInstance {
+    pub(crate) fn update_node_view_attributes(
+        &self,
+        view_id: u32,
+        attributes: TiptapAttributes,
+    ) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::UpdateNodeViewAttributes {
+            view_id,
+            attributes,
+        })
+    }
+}
+
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+impl TiptapEditorHandle {
+    pub(crate) fn update_node_view_attributes(
+        &self,
+        view_id: u32,
+        attributes: TiptapAttributes,
+    ) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.update_node_view_attributes(view_id, attributes))
+    }
+}
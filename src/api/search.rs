@@ -0,0 +1,164 @@
+use crate::protocol::{DocumentRequest, DocumentResponse, EditorCommand};
+use crate::runtime;
+
+use super::{
+    TiptapEditorError, TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult, TiptapRange,
+    TiptapSearchOptions,
+};
+
+impl TiptapEditorInstance {
+    /// Searches the document for `query`, and returns the ranges of all matches in document
+    /// order.
+    ///
+    /// The search stays active until [`clear_search`](Self::clear_search) or the next call: its
+    /// matches are highlighted with the `search-match` class, the current match additionally with
+    /// `search-match-current`, and they are updated while the document changes. An empty query
+    /// clears the search.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when `query` is not a valid regular
+    /// expression, or when the JS bridge rejects the request.
+    pub fn find(
+        &self,
+        query: impl Into<String>,
+        options: TiptapSearchOptions,
+    ) -> TiptapEditorResult<Vec<TiptapRange>> {
+        match runtime::document(
+            self.id.clone(),
+            self.generation,
+            DocumentRequest::Find {
+                query: query.into(),
+                options,
+            },
+        )? {
+            DocumentResponse::SearchMatches { matches } => Ok(matches),
+            _ => Err(TiptapEditorError::BridgeError(
+                "received an unexpected response for a find document request".to_owned(),
+            )
+            .into()),
+        }
+    }
+
+    /// Ends the active search and removes the highlighting of its matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale or when the JS bridge rejects the request.
+    pub fn clear_search(&self) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::ClearSearch)
+    }
+
+    /// Selects the match after the current one, or the first match after the cursor when there
+    /// is no current match, wrapping around at the end of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when the active search has no matches, or
+    /// when the JS bridge rejects the request.
+    pub fn select_next_match(&self) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SelectNextMatch)
+    }
+
+    /// Selects the match before the current one, or the last match before the cursor when there
+    /// is no current match, wrapping around at the start of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when the active search has no matches, or
+    /// when the JS bridge rejects the request.
+    pub fn select_previous_match(&self) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SelectPreviousMatch)
+    }
+
+    /// Replaces the current match with `replacement` and selects the next match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when there is no current match, or when the
+    /// JS bridge rejects the request.
+    pub fn replace_current(&self, replacement: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::ReplaceCurrentMatch {
+            replacement: replacement.into(),
+        })
+    }
+
+    /// Replaces every match with `replacement` in a single transaction, undone as one step.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when the active search has no matches, or
+    /// when the JS bridge rejects the request.
+    pub fn replace_all(&self, replacement: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::ReplaceAllMatches {
+            replacement: replacement.into(),
+        })
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Searches the document for `query`, and returns the ranges of all matches in document
+    /// order. See [`TiptapEditorInstance::find`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn find(
+        &self,
+        query: impl Into<String>,
+        options: TiptapSearchOptions,
+    ) -> TiptapEditorResult<Vec<TiptapRange>> {
+        self.with_instance(|instance| instance.find(query, options))
+    }
+
+    /// Ends the active search and removes the highlighting of its matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn clear_search(&self) -> TiptapEditorResult<()> {
+        self.with_instance(TiptapEditorInstance::clear_search)
+    }
+
+    /// Selects the match after the current one, wrapping around at the end of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn select_next_match(&self) -> TiptapEditorResult<()> {
+        self.with_instance(TiptapEditorInstance::select_next_match)
+    }
+
+    /// Selects the match before the current one, wrapping around at the start of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn select_previous_match(&self) -> TiptapEditorResult<()> {
+        self.with_instance(TiptapEditorInstance::select_previous_match)
+    }
+
+    /// Replaces the current match with `replacement` and selects the next match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn replace_current(&self, replacement: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.replace_current(replacement))
+    }
+
+    /// Replaces every match with `replacement` in a single transaction, undone as one step.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn replace_all(&self, replacement: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.replace_all(replacement))
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+type ShortcutHandler = Arc<dyn Fn() -> bool + Send + Sync>;
+
+/// Keyboard shortcuts handled by Rust callbacks.
+///
+/// Keys are written in the notation of Tiptap, such as `"Mod-s"`, `"Shift-Enter"`, or
+/// `"Alt-ArrowUp"`, where `Mod` is `Cmd` on macOS and `Ctrl` elsewhere. A handler returns whether
+/// it handled the key press. Handled key presses have their default behavior prevented and are not
+/// passed on to bindings of lower priority, while unhandled ones fall through to them.
+///
+/// The shortcuts are registered as an extension with a priority of
+/// [`DEFAULT_PRIORITY`](Self::DEFAULT_PRIORITY), so they take precedence over the bindings of
+/// the built-in extensions.
+///
+/// ```
+/// use leptos_tiptap::TiptapShortcuts;
+///
+/// let shortcuts = TiptapShortcuts::new()
+///     .bind("Mod-s", || {
+///         // Save the document.
+///         true
+///     })
+///     .bind("Mod-k", || {
+///         // Open the link dialog.
+///         true
+///     });
+/// ```
+#[derive(Clone)]
+pub struct TiptapShortcuts {
+    priority: u32,
+    bindings: Vec<(String, ShortcutHandler)>,
+}
+
+impl TiptapShortcuts {
+    /// The priority shortcuts are registered with unless configured otherwise. Tiptap extensions
+    /// default to a priority of `100`.
+    pub const DEFAULT_PRIORITY: u32 = 1000;
+
+    /// Creates an empty set of shortcuts.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            priority: Self::DEFAULT_PRIORITY,
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Sets the priority of the shortcuts. Bindings of extensions with a higher priority run
+    /// first.
+    #[must_use]
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Binds `keys` to `handler`, replacing an earlier binding of the same keys.
+    #[must_use]
+    pub fn bind(
+        mut self,
+        keys: impl Into<String>,
+        handler: impl Fn() -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let keys = keys.into();
+        self.bindings.retain(|(bound, _)| *bound != keys);
+        self.bindings.push((keys, Arc::new(handler)));
+        self
+    }
+
+    /// Returns the bound key combinations.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.bindings.iter().map(|(keys, _)| keys.as_str())
+    }
+
+    /// Runs the handler bound to `keys`, returning whether it handled the key press.
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn handle(&self, keys: &str) -> bool {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| bound == keys)
+            .is_some_and(|(_, handler)| handler())
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn payload(&self) -> crate::protocol::ShortcutsPayload {
+        crate::protocol::ShortcutsPayload {
+            priority: self.priority,
+            keys: self.keys().map(str::to_owned).collect(),
+        }
+    }
+}
+
+impl Default for TiptapShortcuts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for TiptapShortcuts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TiptapShortcuts")
+            .field("priority", &self.priority)
+            .field("keys", &self.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+
+    #[test]
+    fn later_bindings_replace_earlier_bindings_of_the_same_keys() {
+        let shortcuts = TiptapShortcuts::new()
+            .bind("Mod-s", || false)
+            .bind("Mod-k", || true)
+            .bind("Mod-s", || true);
+
+        assert_that!(shortcuts.keys().collect::<Vec<_>>()).is_equal_to(vec!["Mod-k", "Mod-s"]);
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn reports_whether_a_binding_handled_the_keys() {
+        let shortcuts = TiptapShortcuts::new()
+            .priority(50)
+            .bind("Mod-s", || true)
+            .bind("Mod-b", || false);
+
+        assert_that!(shortcuts.handle("Mod-s")).is_true();
+        assert_that!(shortcuts.handle("Mod-b")).is_false();
+        assert_that!(shortcuts.handle("Mod-k")).is_false();
+        assert_that!(shortcuts.payload()).is_equal_to(crate::protocol::ShortcutsPayload {
+            priority: 50,
+            keys: vec!["Mod-s".to_owned(), "Mod-b".to_owned()],
+        });
+    }
+}
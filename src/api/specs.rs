@@ -0,0 +1,103 @@
+use crate::protocol::{EditorCommand, SpecCommandAction};
+
+use super::{TiptapAttributes, TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult};
+
+impl TiptapEditorInstance {
+    /// Runs the `set_<name>` command of a node or mark spec.
+    ///
+    /// Textblock nodes replace the selected blocks, leaf nodes are inserted at the selection,
+    /// other nodes wrap the selected blocks, and marks are applied to the selection. `attributes`
+    /// override the defaults of the spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when the editor has no spec named `name`, or
+    /// when the command can not be applied to the selection.
+    pub fn set_spec(
+        &self,
+        name: &str,
+        attributes: Option<TiptapAttributes>,
+    ) -> TiptapEditorResult<()> {
+        self.run_spec_command(SpecCommandAction::Set, name, attributes)
+    }
+
+    /// Runs the `toggle_<name>` command of a node or mark spec.
+    ///
+    /// Like [`Self::set_spec`], but reverts textblock nodes to paragraphs, unwraps wrapping nodes,
+    /// and removes marks when the spec is already active. Leaf nodes can not be toggled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when the editor has no spec named `name`, or
+    /// when the command can not be applied to the selection.
+    pub fn toggle_spec(
+        &self,
+        name: &str,
+        attributes: Option<TiptapAttributes>,
+    ) -> TiptapEditorResult<()> {
+        self.run_spec_command(SpecCommandAction::Toggle, name, attributes)
+    }
+
+    /// Runs the `unset_<name>` command of a mark spec, removing the mark from the selection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale or when the editor has no mark spec named
+    /// `name`.
+    pub fn unset_spec(&self, name: &str) -> TiptapEditorResult<()> {
+        self.run_spec_command(SpecCommandAction::Unset, name, None)
+    }
+
+    fn run_spec_command(
+        &self,
+        action: SpecCommandAction,
+        name: &str,
+        attributes: Option<TiptapAttributes>,
+    ) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SpecCommand {
+            action,
+            name: name.to_owned(),
+            attributes,
+        })
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Runs the `set_<name>` command of a node or mark spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn set_spec(
+        &self,
+        name: &str,
+        attributes: Option<TiptapAttributes>,
+    ) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.set_spec(name, attributes))
+    }
+
+    /// Runs the `toggle_<name>` command of a node or mark spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn toggle_spec(
+        &self,
+        name: &str,
+        attributes: Option<TiptapAttributes>,
+    ) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.toggle_spec(name, attributes))
+    }
+
+    /// Runs the `unset_<name>` command of a mark spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn unset_spec(&self, name: &str) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.unset_spec(name))
+    }
+}
@@ -0,0 +1,140 @@
+use crate::protocol::EditorCommand;
+
+use super::{TiptapEditorHandle, TiptapEditorInstance, TiptapEditorResult};
+
+impl TiptapEditorInstance {
+    /// Starts tracking changes made by `author`.
+    ///
+    /// While changes are tracked, inserted text is marked as an insertion and deleted text is
+    /// kept, marked as a deletion, until the change is accepted or rejected. Both marks record
+    /// the author and a timestamp, and are rendered as `ins.tracked-insertion` and
+    /// `del.tracked-deletion` elements. Deleting an own pending insertion removes it right away.
+    /// Replacing the whole document, as `set_content` does, and undoing are not tracked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when the `track_changes` extension is not
+    /// active, or when the JS bridge rejects the request.
+    pub fn enable_track_changes(&self, author: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SetTrackChanges {
+            author: Some(author.into()),
+        })
+    }
+
+    /// Stops tracking changes. Pending changes stay until they are accepted or rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when the `track_changes` extension is not
+    /// active, or when the JS bridge rejects the request.
+    pub fn disable_track_changes(&self) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::SetTrackChanges { author: None })
+    }
+
+    /// Accepts the pending change `id`: keeps inserted text, and removes deleted text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when there is no pending change `id`, or
+    /// when the JS bridge rejects the request.
+    pub fn accept_change(&self, id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::AcceptChange {
+            change_id: id.into(),
+        })
+    }
+
+    /// Rejects the pending change `id`: removes inserted text, and restores deleted text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when there is no pending change `id`, or
+    /// when the JS bridge rejects the request.
+    pub fn reject_change(&self, id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::RejectChange {
+            change_id: id.into(),
+        })
+    }
+
+    /// Accepts all pending changes in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when there are no pending changes, or when
+    /// the JS bridge rejects the request.
+    pub fn accept_all(&self) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::AcceptAllChanges)
+    }
+
+    /// Rejects all pending changes in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this instance is stale, when there are no pending changes, or when
+    /// the JS bridge rejects the request.
+    pub fn reject_all(&self) -> TiptapEditorResult<()> {
+        self.dispatch(EditorCommand::RejectAllChanges)
+    }
+}
+
+impl TiptapEditorHandle {
+    /// Starts tracking changes made by `author`, see
+    /// [`TiptapEditorInstance::enable_track_changes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn enable_track_changes(&self, author: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.enable_track_changes(author))
+    }
+
+    /// Stops tracking changes. Pending changes stay until they are accepted or rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn disable_track_changes(&self) -> TiptapEditorResult<()> {
+        self.with_instance(TiptapEditorInstance::disable_track_changes)
+    }
+
+    /// Accepts the pending change `id`: keeps inserted text, and removes deleted text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn accept_change(&self, id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.accept_change(id))
+    }
+
+    /// Rejects the pending change `id`: removes inserted text, and restores deleted text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn reject_change(&self, id: impl Into<String>) -> TiptapEditorResult<()> {
+        self.with_instance(|instance| instance.reject_change(id))
+    }
+
+    /// Accepts all pending changes in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn accept_all(&self) -> TiptapEditorResult<()> {
+        self.with_instance(TiptapEditorInstance::accept_all)
+    }
+
+    /// Rejects all pending changes in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance or when
+    /// the underlying instance request fails.
+    pub fn reject_all(&self) -> TiptapEditorResult<()> {
+        self.with_instance(TiptapEditorInstance::reject_all)
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// One-time configuration of the `prosemirror-collab` plugin.
+///
+/// The initial content of the editor must be the document of the central authority at
+/// `version`. Otherwise, steps exchanged with other clients do not apply to the same document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapCollabConfig {
+    /// Authority version of the initial content.
+    pub version: u64,
+    /// Identifies the steps of this client in the authority's step log.
+    ///
+    /// Every concurrently connected client needs a distinct id, because confirmed steps are
+    /// recognized by comparing client ids.
+    pub client_id: String,
+}
+
+impl TiptapCollabConfig {
+    /// Creates a configuration for a client whose initial content is at `version`.
+    #[must_use]
+    pub fn new(version: u64, client_id: impl Into<String>) -> Self {
+        Self {
+            version,
+            client_id: client_id.into(),
+        }
+    }
+}
+
+/// Local steps that have not been confirmed by the central authority yet.
+///
+/// This is what a client submits to the central authority, for example to
+/// `TiptapCollabAuthority::receive_steps`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapCollabSteps {
+    /// Authority version the steps were made against.
+    pub version: u64,
+    /// `ProseMirror` steps, serialized through `Step.toJSON()`.
+    pub steps: Vec<serde_json::Value>,
+    /// Id of the client that made the steps.
+    pub client_id: String,
+}
+
+/// Confirmed steps that a client has not applied yet.
+///
+/// Returned by [`TiptapCollabAuthority::steps_since`](crate::TiptapCollabAuthority::steps_since)
+/// and applied through
+/// [`TiptapEditorHandle::receive_transaction`](crate::TiptapEditorHandle::receive_transaction).
+#[cfg(feature = "collab")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapCollabUpdate {
+    /// Authority version after applying all steps.
+    pub version: u64,
+    /// `ProseMirror` steps in authority order, serialized through `Step.toJSON()`.
+    pub steps: Vec<serde_json::Value>,
+    /// Id of the client that made each step, in the same order as `steps`.
+    pub client_ids: Vec<String>,
+}
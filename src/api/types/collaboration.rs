@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// One-time configuration of the Yjs collaboration extension.
+///
+/// Requires the `collaboration` feature, without which the editor fails to mount, and the
+/// collaboration extension to be active for the editor.
+///
+/// The editor content is bound to a `Y.XmlFragment` of a `Y.Doc` and `initial_content` is ignored.
+/// Seed the document through `initial_update` instead, for example with the state a `yrs`
+/// document on your server encodes through `encode_state_as_update_v1`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapCollaborationConfig {
+    /// Name of the `Y.XmlFragment` holding the document. Defaults to `"default"`, matching
+    /// Tiptap's own default.
+    pub field: String,
+    /// Yjs update (v1 encoding) applied to the `Y.Doc` before the editor is created.
+    pub initial_update: Option<Vec<u8>>,
+}
+
+impl TiptapCollaborationConfig {
+    /// Creates a configuration whose document starts from `update`.
+    #[must_use]
+    pub fn from_update(update: impl Into<Vec<u8>>) -> Self {
+        Self {
+            initial_update: Some(update.into()),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for TiptapCollaborationConfig {
+    fn default() -> Self {
+        Self {
+            field: "default".to_owned(),
+            initial_update: None,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// A selection given by the position it was started at and the position of the caret.
+///
+/// Positions are plain document positions, so they can be exchanged over any transport. A
+/// collapsed selection has `anchor == head`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapSelectionRange {
+    /// Position the selection was started at. It stays in place while the selection is extended.
+    pub anchor: u32,
+    /// Position of the caret.
+    pub head: u32,
+}
+
+impl TiptapSelectionRange {
+    /// Creates a collapsed selection, which only renders a caret.
+    #[must_use]
+    pub fn caret(position: u32) -> Self {
+        Self {
+            anchor: position,
+            head: position,
+        }
+    }
+}
+
+/// Identifies a remote user and how their cursor is rendered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapCollaborationUser {
+    /// Stable identifier of the user.
+    pub id: String,
+    /// Name rendered in the label above the caret.
+    pub name: String,
+    /// CSS color of the caret, its label, and a translucent selection highlight.
+    pub color: String,
+}
+
+/// The cursor and selection of a remote user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapRemoteCursor {
+    /// The user the cursor belongs to.
+    pub user: TiptapCollaborationUser,
+    /// The selection of the user in the shared document.
+    pub selection: TiptapSelectionRange,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use super::TiptapRange;
+
+/// A comment thread anchored to the document through the comment mark.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapCommentThread {
+    /// The identifier the thread was added with.
+    pub thread_id: String,
+    /// The current ranges of the anchored text, in document order.
+    ///
+    /// Edits inside the anchored text move the ranges along with it, and can split it into
+    /// several ranges. Removing all of its text removes the thread.
+    pub ranges: Vec<TiptapRange>,
+    /// Whether the thread was resolved.
+    pub resolved: bool,
+}
@@ -41,6 +41,19 @@ impl TiptapContent {
     }
 }
 
+/// Serialization of the document in the form value of `<TiptapEditor/>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TiptapFormFormat {
+    /// The document as HTML.
+    #[default]
+    Html,
+    /// The document as Tiptap/ProseMirror JSON text.
+    Json,
+    /// The document as `CommonMark`. Marks without a Markdown equivalent, like highlights, are
+    /// dropped and keep their text.
+    Markdown,
+}
+
 /// Whitespace handling mode for parsing content.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TiptapWhitespaceMode {
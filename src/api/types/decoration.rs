@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The part of the document a decoration is drawn on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum TiptapDecorationTarget {
+    Inline { from: u32, to: u32 },
+    Node { position: u32 },
+    Widget { position: u32 },
+}
+
+/// A highlight drawn over the document without being part of it, such as a spelling mistake, a
+/// search hit, or a suggestion.
+///
+/// Decorations are set per layer through
+/// [`TiptapEditorHandle::set_decorations`](crate::TiptapEditorHandle::set_decorations). Their
+/// positions follow later changes of the document, and decorations whose range is deleted are
+/// removed. Clicks on a decoration are reported with its `id` through the `on_decoration_click`
+/// callback of the editor.
+///
+/// ```
+/// use leptos_tiptap::TiptapDecoration;
+///
+/// let typo = TiptapDecoration::inline("typo-1", 4, 9)
+///     .class("spelling-error")
+///     .attribute("title", "Did you mean \"their\"?");
+/// let hint = TiptapDecoration::widget("hint-1", 12).class("suggestion-marker");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapDecoration {
+    id: String,
+    target: TiptapDecorationTarget,
+    class: Option<String>,
+    style: Option<String>,
+    attributes: BTreeMap<String, String>,
+}
+
+impl TiptapDecoration {
+    /// Decorates the text between `from` and `to` by wrapping it in an element.
+    #[must_use]
+    pub fn inline(id: impl Into<String>, from: u32, to: u32) -> Self {
+        Self::new(id, TiptapDecorationTarget::Inline { from, to })
+    }
+
+    /// Decorates the element of the node starting at `position`.
+    #[must_use]
+    pub fn node(id: impl Into<String>, position: u32) -> Self {
+        Self::new(id, TiptapDecorationTarget::Node { position })
+    }
+
+    /// Inserts an empty `span` element at `position`, styled through its class, style, and
+    /// attributes.
+    #[must_use]
+    pub fn widget(id: impl Into<String>, position: u32) -> Self {
+        Self::new(id, TiptapDecorationTarget::Widget { position })
+    }
+
+    fn new(id: impl Into<String>, target: TiptapDecorationTarget) -> Self {
+        Self {
+            id: id.into(),
+            target,
+            class: None,
+            style: None,
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the identifier of the decoration, reported when it is clicked.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Sets the CSS classes, separated by spaces.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Sets the inline CSS style.
+    #[must_use]
+    pub fn style(mut self, style: impl Into<String>) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Adds an HTML attribute, such as a `title` or a `data-` attribute.
+    #[must_use]
+    pub fn attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// A click on a decoration, identified by its layer and its id.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapDecorationClick {
+    /// The layer the decoration was set in.
+    pub layer_id: String,
+    /// The id of the decoration.
+    pub decoration_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_decorations_with_their_target() {
+        let typo = TiptapDecoration::inline("typo", 4, 9)
+            .class("spelling-error")
+            .attribute("title", "their");
+        let hint = TiptapDecoration::widget("hint", 12).style("color: gray");
+
+        assert_that!(serde_json::to_value(typo).unwrap()).is_equal_to(json!({
+            "id": "typo",
+            "target": {"kind": "inline", "from": 4, "to": 9},
+            "class": "spelling-error",
+            "style": null,
+            "attributes": {"title": "their"},
+        }));
+        assert_that!(serde_json::to_value(hint).unwrap()).is_equal_to(json!({
+            "id": "hint",
+            "target": {"kind": "widget", "position": 12},
+            "class": null,
+            "style": "color: gray",
+            "attributes": {},
+        }));
+    }
+}
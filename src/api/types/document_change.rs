@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+/// Wire format of the incremental document change stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TiptapDocumentChangeFormat {
+    /// RFC 6902 JSON patches against the `ProseMirror` JSON document.
+    ///
+    /// Patches can be applied without a `ProseMirror` schema, for example through
+    /// [`TiptapDocumentMirror`](crate::TiptapDocumentMirror).
+    #[default]
+    JsonPatch,
+    /// `ProseMirror` steps, serialized through `Step.toJSON()`.
+    ///
+    /// Steps are the most compact representation, but applying them requires a `ProseMirror`
+    /// schema, for example on a Node.js sync server.
+    Steps,
+}
+
+/// One entry of the incremental document change stream.
+///
+/// Versions start at `0` for the snapshot reported when the editor becomes ready and increase by
+/// exactly one per emitted change. Each non-snapshot change applies to the document at
+/// `version - 1`, so a consumer that observes a version jump has missed a change and should
+/// resynchronize from a fresh snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapDocumentChange {
+    /// Document version after applying this change.
+    pub version: u64,
+    /// The change payload.
+    pub payload: TiptapDocumentChangePayload,
+}
+
+/// Payload of a [`TiptapDocumentChange`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "format", rename_all = "snake_case")]
+pub enum TiptapDocumentChangePayload {
+    /// The complete `ProseMirror` JSON document. Replaces any previously mirrored state.
+    Snapshot {
+        /// The `ProseMirror` JSON document.
+        document: serde_json::Value,
+    },
+    /// RFC 6902 operations transforming the previous version into this one.
+    JsonPatch {
+        /// Operations to apply in order.
+        operations: Vec<TiptapJsonPatchOperation>,
+    },
+    /// `ProseMirror` steps transforming the previous version into this one.
+    Steps {
+        /// Steps to apply in order, serialized through `Step.toJSON()`.
+        steps: Vec<serde_json::Value>,
+    },
+}
+
+/// An RFC 6902 JSON patch operation.
+///
+/// The change stream only emits `add`, `remove`, and `replace` operations. Paths are JSON
+/// pointers (RFC 6901) into the `ProseMirror` JSON document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum TiptapJsonPatchOperation {
+    /// Insert `value` at `path`, shifting later array elements.
+    Add {
+        /// JSON pointer of the inserted value.
+        path: String,
+        /// The inserted value.
+        value: serde_json::Value,
+    },
+    /// Remove the value at `path`, shifting later array elements.
+    Remove {
+        /// JSON pointer of the removed value.
+        path: String,
+    },
+    /// Replace the existing value at `path`.
+    Replace {
+        /// JSON pointer of the replaced value.
+        path: String,
+        /// The new value.
+        value: serde_json::Value,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserializes_json_patch_changes_from_the_wire_format() {
+        let change: TiptapDocumentChange = serde_json::from_value(json!({
+            "version": 3,
+            "payload": {
+                "format": "json_patch",
+                "operations": [
+                    {"op": "replace", "path": "/content/0/content/0/text", "value": "Hi"},
+                    {"op": "remove", "path": "/content/1"}
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_that!(change).is_equal_to(TiptapDocumentChange {
+            version: 3,
+            payload: TiptapDocumentChangePayload::JsonPatch {
+                operations: vec![
+                    TiptapJsonPatchOperation::Replace {
+                        path: "/content/0/content/0/text".to_owned(),
+                        value: json!("Hi"),
+                    },
+                    TiptapJsonPatchOperation::Remove {
+                        path: "/content/1".to_owned(),
+                    },
+                ],
+            },
+        });
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::TiptapSelectionRange;
+
+/// An unsaved editor state, persisted by a draft store.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TiptapDraft {
+    /// The document as Tiptap JSON.
+    pub content: serde_json::Value,
+    /// The local selection at the time of saving, if it was known.
+    pub selection: Option<TiptapSelectionRange>,
+}
@@ -1,23 +1,56 @@
+mod collab;
+mod collaboration;
+mod collaboration_cursor;
+mod comments;
 mod content;
 mod core;
+mod decoration;
+mod document_change;
+mod draft;
 mod extensions;
+mod rule;
 mod schema;
+mod search;
 mod selection;
 mod shared;
+mod spec;
+mod track_changes;
+mod transaction;
+mod transfer;
+mod version;
 
+#[cfg(feature = "collab")]
+pub use collab::TiptapCollabUpdate;
+pub use collab::{TiptapCollabConfig, TiptapCollabSteps};
+pub use collaboration::TiptapCollaborationConfig;
+pub use collaboration_cursor::{TiptapCollaborationUser, TiptapRemoteCursor, TiptapSelectionRange};
+pub use comments::TiptapCommentThread;
 pub use content::{
-    TiptapContent, TiptapInsertContentOptions, TiptapParseOptions, TiptapSetContentOptions,
-    TiptapWhitespaceMode,
+    TiptapContent, TiptapFormFormat, TiptapInsertContentOptions, TiptapParseOptions,
+    TiptapSetContentOptions, TiptapWhitespaceMode,
 };
 pub use core::{
     TiptapFocusOptions, TiptapFocusTarget, TiptapMarkOptions, TiptapPositionOrRange, TiptapRange,
     TiptapSplitBlockOptions,
 };
+pub use decoration::{TiptapDecoration, TiptapDecorationClick};
+pub use document_change::{
+    TiptapDocumentChange, TiptapDocumentChangeFormat, TiptapDocumentChangePayload,
+    TiptapJsonPatchOperation,
+};
+pub use draft::TiptapDraft;
 pub use extensions::{
     TiptapCodeBlockAttributes, TiptapHeadingLevel, TiptapHighlightAttributes, TiptapImageResource,
     TiptapLinkResource, TiptapListKind, TiptapTextAlign, TiptapToggleListOptions,
     TiptapYoutubeVideoResource,
 };
+pub use rule::{TiptapInputRule, TiptapPasteRule};
 pub use schema::{TiptapMarkName, TiptapNodeName, TiptapSchemaTarget};
+pub use search::{TiptapSearchMatches, TiptapSearchOptions};
 pub use selection::{TiptapActiveKey, TiptapActiveState, TiptapSelectionState};
 pub use shared::TiptapAttributes;
+pub use spec::{TiptapMarkSpec, TiptapNodeSpec};
+pub use track_changes::{TiptapTrackedChange, TiptapTrackedChangeKind};
+pub use transaction::{TiptapTransaction, TiptapTransactionOrigin};
+pub use transfer::{TiptapDropEvent, TiptapPasteEvent, TiptapTransferAction, TiptapTransferFile};
+pub use version::{TiptapVersion, TiptapVersionDetails};
@@ -0,0 +1,188 @@
+use serde::Serialize;
+
+use super::{TiptapAttributes, TiptapMarkName, TiptapNodeName};
+
+/// What a rule does with the text its pattern matched.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum TiptapRuleAction {
+    Replace {
+        text: String,
+    },
+    Mark {
+        name: &'static str,
+        attributes: Option<TiptapAttributes>,
+    },
+    WrapIn {
+        name: &'static str,
+        attributes: Option<TiptapAttributes>,
+    },
+    SetBlock {
+        name: &'static str,
+        attributes: Option<TiptapAttributes>,
+    },
+}
+
+/// Formats text while it is typed, such as replacing `-->` with an arrow.
+///
+/// The pattern is a JS regular expression, matched against the text before the cursor whenever a
+/// character is typed. Anchor it to the end of the typed text with `$`. Rules are created with the
+/// editor, so they are one-time initialization input.
+///
+/// ```
+/// use leptos_tiptap::TiptapInputRule;
+///
+/// let arrow = TiptapInputRule::replace("-->$", "→");
+/// let smile = TiptapInputRule::replace(":\\)$", "🙂");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TiptapInputRule {
+    find: String,
+    action: TiptapRuleAction,
+}
+
+impl TiptapInputRule {
+    /// Replaces the matched text with `text`. With a capture group, only the captured text is
+    /// replaced.
+    #[must_use]
+    pub fn replace(find: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            find: find.into(),
+            action: TiptapRuleAction::Replace { text: text.into() },
+        }
+    }
+
+    /// Applies `mark` to the text of the last capture group, removing the rest of the match, such
+    /// as the asterisks of `\*\*(.+)\*\*$`.
+    #[must_use]
+    pub fn mark(
+        find: impl Into<String>,
+        mark: TiptapMarkName,
+        attributes: Option<TiptapAttributes>,
+    ) -> Self {
+        Self {
+            find: find.into(),
+            action: TiptapRuleAction::Mark {
+                name: mark.schema_name(),
+                attributes,
+            },
+        }
+    }
+
+    /// Removes the matched text at the start of a textblock and wraps the block in `node`, such
+    /// as a blockquote for `^>\s$`.
+    #[must_use]
+    pub fn wrap_in(
+        find: impl Into<String>,
+        node: TiptapNodeName,
+        attributes: Option<TiptapAttributes>,
+    ) -> Self {
+        Self {
+            find: find.into(),
+            action: TiptapRuleAction::WrapIn {
+                name: node.schema_name(),
+                attributes,
+            },
+        }
+    }
+
+    /// Removes the matched text at the start of a textblock and turns the block into `node`, such
+    /// as a heading for `^#\s$`.
+    #[must_use]
+    pub fn set_block(
+        find: impl Into<String>,
+        node: TiptapNodeName,
+        attributes: Option<TiptapAttributes>,
+    ) -> Self {
+        Self {
+            find: find.into(),
+            action: TiptapRuleAction::SetBlock {
+                name: node.schema_name(),
+                attributes,
+            },
+        }
+    }
+}
+
+/// Formats pasted text, such as marking every `#tag` in it.
+///
+/// The pattern is a JS regular expression, matched globally against the pasted text. Rules are
+/// created with the editor, so they are one-time initialization input.
+///
+/// ```
+/// use leptos_tiptap::TiptapPasteRule;
+///
+/// let arrows = TiptapPasteRule::replace("-->", "→");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TiptapPasteRule {
+    find: String,
+    action: TiptapRuleAction,
+}
+
+impl TiptapPasteRule {
+    /// Replaces every match with `text`.
+    #[must_use]
+    pub fn replace(find: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            find: find.into(),
+            action: TiptapRuleAction::Replace { text: text.into() },
+        }
+    }
+
+    /// Applies `mark` to the text of the last capture group of every match, removing the rest of
+    /// the match.
+    #[must_use]
+    pub fn mark(
+        find: impl Into<String>,
+        mark: TiptapMarkName,
+        attributes: Option<TiptapAttributes>,
+    ) -> Self {
+        Self {
+            find: find.into(),
+            action: TiptapRuleAction::Mark {
+                name: mark.schema_name(),
+                attributes,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_rules_with_their_action() {
+        assert_that!(serde_json::to_value(TiptapInputRule::replace("-->$", "→")).unwrap())
+            .is_equal_to(json!({"find": "-->$", "action": {"kind": "replace", "text": "→"}}));
+        #[cfg(feature = "blockquote")]
+        assert_that!(
+            serde_json::to_value(TiptapInputRule::wrap_in(
+                "^>\\s$",
+                TiptapNodeName::Blockquote,
+                None
+            ))
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "find": "^>\\s$",
+            "action": {"kind": "wrap_in", "name": "blockquote", "attributes": null},
+        }));
+        #[cfg(feature = "highlight")]
+        assert_that!(
+            serde_json::to_value(TiptapPasteRule::mark(
+                "==(.+?)==",
+                TiptapMarkName::Highlight,
+                Some([("color", "yellow")].into_iter().collect()),
+            ))
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "find": "==(.+?)==",
+            "action": {"kind": "mark", "name": "highlight", "attributes": {"color": "yellow"}},
+        }));
+    }
+}
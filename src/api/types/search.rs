@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// How [`TiptapEditorHandle::find`](crate::TiptapEditorHandle::find) matches its query against
+/// the text of the document.
+///
+/// Matches never span textblocks. By default, the query is matched literally and ignoring case.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapSearchOptions {
+    /// Matches only text in the same case as the query.
+    pub case_sensitive: bool,
+    /// Matches only text that is not part of a longer word.
+    pub whole_word: bool,
+    /// Treats the query as a JS regular expression. Replacements may then refer to capture groups
+    /// with `$1`, `$2`, and so on.
+    pub regex: bool,
+}
+
+/// The matches of the active search, updated while the document changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapSearchMatches {
+    /// The number of matches in the document.
+    pub count: u32,
+    /// The index of the current match, selected through
+    /// [`TiptapEditorHandle::select_next_match`](crate::TiptapEditorHandle::select_next_match)
+    /// or [`TiptapEditorHandle::select_previous_match`](crate::TiptapEditorHandle::select_previous_match).
+    pub current: Option<u32>,
+}
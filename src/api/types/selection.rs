@@ -131,11 +131,14 @@ impl TiptapActiveState {
 ///
 /// The aggregate is opaque so separately typed selection information can be added without
 /// changing the representation exposed to applications. Boolean extension activity is available
-/// through [`Self::active_state`], [`Self::active`], and [`Self::is_active`].
+/// through [`Self::active_state`], [`Self::active`], and [`Self::is_active`]. States contributed by
+/// custom extensions are available through [`Self::custom_active`] and [`Self::is_custom_active`].
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct TiptapSelectionState {
     active: TiptapActiveState,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    custom: BTreeMap<String, bool>,
 }
 
 impl TiptapSelectionState {
@@ -167,6 +170,21 @@ impl TiptapSelectionState {
     ) -> impl ExactSizeIterator<Item = (TiptapActiveKey, bool)> + DoubleEndedIterator + '_ {
         self.active.iter()
     }
+
+    /// Returns the value a custom extension reports for `key`, or `None` when no selected custom
+    /// extension contributes it.
+    #[must_use]
+    pub fn custom_active(&self, key: &str) -> Option<bool> {
+        self.custom.get(key).copied()
+    }
+
+    /// Returns whether a custom extension reports `key` as active.
+    ///
+    /// Missing keys are treated as inactive.
+    #[must_use]
+    pub fn is_custom_active(&self, key: &str) -> bool {
+        self.custom_active(key).unwrap_or(false)
+    }
 }
 
 #[cfg(test)]
@@ -211,6 +229,20 @@ mod tests {
             .is_equal_to(json!({"active": {"bold": true}}));
     }
 
+    #[test]
+    fn keeps_custom_active_states_apart_from_built_in_keys() {
+        let state: TiptapSelectionState = serde_json::from_value(json!({
+            "active": {"bold": true},
+            "custom": {"mention": true, "bold": false},
+        }))
+        .unwrap();
+
+        assert_that!(state.is_active(TiptapActiveKey::Bold)).is_true();
+        assert_that!(state.custom_active("mention")).is_equal_to(Some(true));
+        assert_that!(state.custom_active("bold")).is_equal_to(Some(false));
+        assert_that!(state.is_custom_active("missing")).is_false();
+    }
+
     #[test]
     fn rejects_unknown_active_keys() {
         let result = serde_json::from_value::<TiptapSelectionState>(json!({
@@ -0,0 +1,332 @@
+use serde::Serialize;
+
+use super::TiptapAttributes;
+
+/// The element a node or mark spec renders to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TiptapRenderTemplate {
+    tag: String,
+    attributes: TiptapAttributes,
+}
+
+impl TiptapRenderTemplate {
+    /// Resolves the element a spec renders to, falling back to a `fallback` element carrying
+    /// `data-type="<name>"`, like the bridge does.
+    #[cfg(feature = "component")]
+    fn resolve(template: Option<&Self>, name: &str, fallback: &str) -> (String, TiptapAttributes) {
+        template.map_or_else(
+            || {
+                (
+                    fallback.to_owned(),
+                    [("data-type", name)].into_iter().collect(),
+                )
+            },
+            |template| (template.tag.clone(), template.attributes.clone()),
+        )
+    }
+}
+
+/// A custom Tiptap node, declared in Rust and created by the bridge when the editor mounts.
+///
+/// The node accepts the HTML matched by its parse rules and renders as its render template, with
+/// its attributes added as HTML attributes of the same name. Without parse rules or a render
+/// template, it renders as a `div`, or a `span` when inline, carrying `data-type="<name>"`, and
+/// parses that element back.
+///
+/// Every spec provides `set_<name>` and `toggle_<name>` commands, run through
+/// [`TiptapEditorHandle::set_spec`](crate::TiptapEditorHandle::set_spec) and
+/// [`TiptapEditorHandle::toggle_spec`](crate::TiptapEditorHandle::toggle_spec), and reports
+/// whether it is active in the selection through
+/// [`TiptapSelectionState::custom_active`](crate::TiptapSelectionState::custom_active).
+///
+/// ```
+/// use leptos_tiptap::TiptapNodeSpec;
+///
+/// let callout = TiptapNodeSpec::new("callout")
+///     .group("block")
+///     .content("paragraph+")
+///     .attribute("kind", "info")
+///     .parse_tag_with_class("div", "callout")
+///     .render_html("div", [("class", "callout")]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TiptapNodeSpec {
+    name: String,
+    group: Option<String>,
+    content: Option<String>,
+    inline: bool,
+    atom: bool,
+    attributes: TiptapAttributes,
+    parse_html: Vec<String>,
+    render_html: Option<TiptapRenderTemplate>,
+}
+
+impl TiptapNodeSpec {
+    /// Creates a spec for a node type named `name`, which must be unique within the schema.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            group: None,
+            content: None,
+            inline: false,
+            atom: false,
+            attributes: TiptapAttributes::new(),
+            parse_html: Vec::new(),
+            render_html: None,
+        }
+    }
+
+    /// Returns the name of the node type.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the attributes of the node with their default values.
+    #[cfg(feature = "component")]
+    pub(crate) fn attributes(&self) -> &TiptapAttributes {
+        &self.attributes
+    }
+
+    /// Returns whether the node renders its content.
+    #[cfg(feature = "component")]
+    pub(crate) fn has_content(&self) -> bool {
+        !self.atom && self.content.is_some()
+    }
+
+    /// Returns the tag and the static attributes of the element the node renders to.
+    #[cfg(feature = "component")]
+    pub(crate) fn render_template(&self) -> (String, TiptapAttributes) {
+        TiptapRenderTemplate::resolve(
+            self.render_html.as_ref(),
+            &self.name,
+            if self.inline { "span" } else { "div" },
+        )
+    }
+
+    /// Adds the node to the given groups, separated by spaces, such as `"block"` or `"inline"`.
+    #[must_use]
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Sets the content expression, such as `"inline*"` or `"paragraph+"`. Nodes without one are
+    /// leaves.
+    #[must_use]
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Marks the node as inline. Inline nodes usually also belong to the `"inline"` group.
+    #[must_use]
+    pub fn inline(mut self, inline: bool) -> Self {
+        self.inline = inline;
+        self
+    }
+
+    /// Marks the node as an atom, which is edited as a single unit.
+    #[must_use]
+    pub fn atom(mut self, atom: bool) -> Self {
+        self.atom = atom;
+        self
+    }
+
+    /// Adds an attribute with its default value.
+    #[must_use]
+    pub fn attribute(
+        mut self,
+        name: impl Into<String>,
+        default: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.attributes.insert(name, default);
+        self
+    }
+
+    /// Parses elements matching the CSS selector `selector`.
+    #[must_use]
+    pub fn parse_html(mut self, selector: impl Into<String>) -> Self {
+        self.parse_html.push(selector.into());
+        self
+    }
+
+    /// Parses elements with the tag `tag`.
+    #[must_use]
+    pub fn parse_tag(self, tag: impl Into<String>) -> Self {
+        self.parse_html(tag)
+    }
+
+    /// Parses elements with the tag `tag` and the class `class`.
+    #[must_use]
+    pub fn parse_tag_with_class(self, tag: impl AsRef<str>, class: impl AsRef<str>) -> Self {
+        self.parse_html(format!("{}.{}", tag.as_ref(), class.as_ref()))
+    }
+
+    /// Renders the node as a `tag` element with the static `attributes`. The content of non-atom
+    /// nodes is rendered inside of it.
+    #[must_use]
+    pub fn render_html<K, V>(
+        mut self,
+        tag: impl Into<String>,
+        attributes: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        K: Into<String>,
+        V: Into<serde_json::Value>,
+    {
+        self.render_html = Some(TiptapRenderTemplate {
+            tag: tag.into(),
+            attributes: attributes.into_iter().collect(),
+        });
+        self
+    }
+}
+
+/// A custom Tiptap mark, declared in Rust and created by the bridge when the editor mounts.
+///
+/// Marks follow the parse and render rules of [`TiptapNodeSpec`], defaulting to a `span`. Besides
+/// `set_<name>` and `toggle_<name>`, every mark spec provides an `unset_<name>` command, run
+/// through [`TiptapEditorHandle::unset_spec`](crate::TiptapEditorHandle::unset_spec).
+///
+/// ```
+/// use leptos_tiptap::TiptapMarkSpec;
+///
+/// let badge = TiptapMarkSpec::new("badge")
+///     .attribute("tone", "neutral")
+///     .parse_tag_with_class("span", "badge")
+///     .render_html("span", [("class", "badge")]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TiptapMarkSpec {
+    name: String,
+    inclusive: Option<bool>,
+    attributes: TiptapAttributes,
+    parse_html: Vec<String>,
+    render_html: Option<TiptapRenderTemplate>,
+}
+
+impl TiptapMarkSpec {
+    /// Creates a spec for a mark type named `name`, which must be unique within the schema.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            inclusive: None,
+            attributes: TiptapAttributes::new(),
+            parse_html: Vec::new(),
+            render_html: None,
+        }
+    }
+
+    /// Returns the name of the mark type.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the attributes of the mark with their default values.
+    #[cfg(feature = "component")]
+    pub(crate) fn attributes(&self) -> &TiptapAttributes {
+        &self.attributes
+    }
+
+    /// Returns the tag and the static attributes of the element the mark renders to.
+    #[cfg(feature = "component")]
+    pub(crate) fn render_template(&self) -> (String, TiptapAttributes) {
+        TiptapRenderTemplate::resolve(self.render_html.as_ref(), &self.name, "span")
+    }
+
+    /// Sets whether text typed at the end of the mark is marked as well. Marks are inclusive by
+    /// default.
+    #[must_use]
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Adds an attribute with its default value.
+    #[must_use]
+    pub fn attribute(
+        mut self,
+        name: impl Into<String>,
+        default: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.attributes.insert(name, default);
+        self
+    }
+
+    /// Parses elements matching the CSS selector `selector`.
+    #[must_use]
+    pub fn parse_html(mut self, selector: impl Into<String>) -> Self {
+        self.parse_html.push(selector.into());
+        self
+    }
+
+    /// Parses elements with the tag `tag`.
+    #[must_use]
+    pub fn parse_tag(self, tag: impl Into<String>) -> Self {
+        self.parse_html(tag)
+    }
+
+    /// Parses elements with the tag `tag` and the class `class`.
+    #[must_use]
+    pub fn parse_tag_with_class(self, tag: impl AsRef<str>, class: impl AsRef<str>) -> Self {
+        self.parse_html(format!("{}.{}", tag.as_ref(), class.as_ref()))
+    }
+
+    /// Renders the mark as a `tag` element with the static `attributes`, wrapping the marked text.
+    #[must_use]
+    pub fn render_html<K, V>(
+        mut self,
+        tag: impl Into<String>,
+        attributes: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        K: Into<String>,
+        V: Into<serde_json::Value>,
+    {
+        self.render_html = Some(TiptapRenderTemplate {
+            tag: tag.into(),
+            attributes: attributes.into_iter().collect(),
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_node_and_mark_specs_for_the_bridge() {
+        let callout = TiptapNodeSpec::new("callout")
+            .group("block")
+            .content("paragraph+")
+            .attribute("kind", "info")
+            .parse_tag_with_class("div", "callout")
+            .render_html("div", [("class", "callout")]);
+        let badge = TiptapMarkSpec::new("badge").inclusive(false);
+
+        assert_that!(serde_json::to_value(callout).unwrap()).is_equal_to(json!({
+            "name": "callout",
+            "group": "block",
+            "content": "paragraph+",
+            "inline": false,
+            "atom": false,
+            "attributes": {"kind": "info"},
+            "parse_html": ["div.callout"],
+            "render_html": {"tag": "div", "attributes": {"class": "callout"}},
+        }));
+        assert_that!(serde_json::to_value(badge).unwrap()).is_equal_to(json!({
+            "name": "badge",
+            "inclusive": false,
+            "attributes": {},
+            "parse_html": [],
+            "render_html": null,
+        }));
+    }
+}
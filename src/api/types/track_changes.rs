@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use super::TiptapRange;
+
+/// Whether a tracked change suggests inserting or deleting its text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TiptapTrackedChangeKind {
+    /// The text was added while tracking changes.
+    Insertion,
+    /// The text was removed while tracking changes, and is kept until the change is accepted.
+    Deletion,
+}
+
+/// A pending change recorded while tracking changes.
+///
+/// Accepting an insertion keeps its text, and accepting a deletion removes it. Rejecting does the
+/// opposite.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapTrackedChange {
+    /// The identifier to accept or reject the change with.
+    pub id: String,
+    /// Whether the change inserts or deletes its text.
+    pub kind: TiptapTrackedChangeKind,
+    /// The author the editor was tracking changes for when the change was made.
+    pub author: String,
+    /// When the change was made, in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The range of the document from the start to the end of the change.
+    pub range: TiptapRange,
+    /// The text of the change.
+    pub text: String,
+}
@@ -0,0 +1,41 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::TiptapSelectionRange;
+
+/// Where a transaction came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TiptapTransactionOrigin {
+    /// Dispatched from Rust through the editor handle, such as by a command or `set_content`.
+    /// This includes commands run by shortcut callbacks and received collaboration steps.
+    Api,
+    /// Inserts pasted content, including the changes of paste rules.
+    Paste,
+    /// Inserts dropped content, or moves content dragged within the editor.
+    Drop,
+    /// Deletes cut content.
+    Cut,
+    /// Applies an input rule to the typed text.
+    InputRule,
+    /// Any other transaction, such as typing, built-in keyboard shortcuts, pointer selection, or
+    /// JS plugins.
+    Other,
+}
+
+/// A transaction applied to the editor, as reported through the `on_transaction` callback.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiptapTransaction {
+    /// Whether the transaction changed the document.
+    pub doc_changed: bool,
+    /// The new selection, when the transaction moved it.
+    pub selection: Option<TiptapSelectionRange>,
+    /// The transaction meta written through
+    /// [`TiptapEditorHandle::set_meta`](crate::TiptapEditorHandle::set_meta), by key.
+    pub meta: BTreeMap<String, serde_json::Value>,
+    /// Whether the transaction is recorded in the undo history.
+    pub add_to_history: bool,
+    /// Where the transaction came from, which tells user edits apart from programmatic ones.
+    pub origin: TiptapTransactionOrigin,
+}
@@ -0,0 +1,78 @@
+use leptos::web_sys::Blob;
+use send_wrapper::SendWrapper;
+
+use super::TiptapContent;
+
+/// A file pasted or dropped into the editor.
+#[derive(Debug, Clone)]
+pub struct TiptapTransferFile {
+    name: String,
+    blob: SendWrapper<Blob>,
+}
+
+impl TiptapTransferFile {
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn new(name: String, blob: Blob) -> Self {
+        Self {
+            name,
+            blob: SendWrapper::new(blob),
+        }
+    }
+
+    /// Returns the name of the file, without its path.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the MIME type of the file, or an empty string when the browser does not know it.
+    #[must_use]
+    pub fn mime_type(&self) -> String {
+        self.blob.type_()
+    }
+
+    /// Returns the browser `File`, for reading or uploading its content.
+    #[must_use]
+    pub fn blob(&self) -> &Blob {
+        &self.blob
+    }
+}
+
+/// Content pasted into the editor, as offered by the clipboard.
+#[derive(Debug, Clone)]
+pub struct TiptapPasteEvent {
+    /// The HTML content, such as the markup copied from a word processor.
+    pub html: Option<String>,
+    /// The plain text content.
+    pub text: Option<String>,
+    /// The pasted files, such as a screenshot.
+    pub files: Vec<TiptapTransferFile>,
+}
+
+/// Content dropped into the editor.
+#[derive(Debug, Clone)]
+pub struct TiptapDropEvent {
+    /// The HTML content.
+    pub html: Option<String>,
+    /// The plain text content.
+    pub text: Option<String>,
+    /// The dropped files.
+    pub files: Vec<TiptapTransferFile>,
+    /// The document position the content was dropped at, if it is within the document.
+    pub position: Option<u32>,
+    /// Whether the content was dragged from this editor, so that Tiptap moves it.
+    pub moved: bool,
+}
+
+/// How the editor continues after an `on_paste` or `on_drop` callback.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum TiptapTransferAction {
+    /// Lets Tiptap insert the content as usual.
+    #[default]
+    Proceed,
+    /// Inserts nothing.
+    Cancel,
+    /// Inserts this content instead, replacing the selection for pastes and at the drop position
+    /// for drops.
+    Insert(TiptapContent),
+}
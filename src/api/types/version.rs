@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// A recorded version of a document, kept by a
+/// [`TiptapVersionStore`](crate::TiptapVersionStore).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TiptapVersion {
+    /// Identifies the version among the versions of its document. Later versions have larger ids.
+    pub id: u64,
+    /// When the version was recorded, in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Who recorded the version, if known.
+    pub author: Option<String>,
+    /// A name for the version, such as "Sent for review".
+    pub label: Option<String>,
+    /// The document as Tiptap JSON.
+    pub content: serde_json::Value,
+}
+
+/// Describes a version that is about to be recorded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TiptapVersionDetails {
+    /// Who records the version.
+    pub author: Option<String>,
+    /// A name for the version.
+    pub label: Option<String>,
+}
@@ -0,0 +1,467 @@
+use super::{TiptapEditorError, TiptapEditorHandle, TiptapEditorReport};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use std::fmt::Display;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The document passed to the save function of [`use_tiptap_autosave`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiptapAutosaveRequest {
+    /// The document as Tiptap JSON.
+    pub content: serde_json::Value,
+    /// The revision the document is based on: the revision returned by the last successful save,
+    /// or the initial revision.
+    pub revision: u64,
+}
+
+/// The result of a save, as reported by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TiptapAutosaveResponse {
+    /// The document was stored as the given new revision.
+    Saved {
+        /// The revision the next save is based on.
+        revision: u64,
+    },
+    /// The document was not stored, as the server holds a newer revision than the request was
+    /// based on.
+    Conflict {
+        /// The latest revision known to the server.
+        revision: u64,
+    },
+}
+
+/// Reactive state of [`use_tiptap_autosave`].
+#[derive(Debug, Clone)]
+pub enum TiptapAutosaveStatus {
+    /// All changes are saved.
+    Saved,
+    /// There are changes waiting to be saved.
+    Dirty,
+    /// A save is in flight.
+    Saving,
+    /// The last save failed, or there was no ready editor to save. It is retried with an
+    /// increasing delay.
+    Error(Arc<TiptapEditorReport>),
+    /// The server holds a newer revision. Autosaving is paused until the conflict is resolved
+    /// through [`UseTiptapAutosaveReturn::resolve_conflict`].
+    Conflict {
+        /// The latest revision known to the server.
+        revision: u64,
+    },
+}
+
+/// Timing of [`use_tiptap_autosave_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TiptapAutosaveOptions {
+    /// The revision of the document the editor was initialized with.
+    pub initial_revision: u64,
+    /// How long to wait after a change before saving. Further changes within this time are saved
+    /// together.
+    pub debounce: Duration,
+    /// Delay before the first retry of a failed save. It doubles with each further failure.
+    pub retry_delay: Duration,
+    /// Upper bound for the retry delay.
+    pub max_retry_delay: Duration,
+}
+
+impl Default for TiptapAutosaveOptions {
+    fn default() -> Self {
+        Self {
+            initial_revision: 0,
+            debounce: Duration::from_secs(1),
+            retry_delay: Duration::from_secs(1),
+            max_retry_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Return value of [`use_tiptap_autosave`].
+#[derive(Clone, Copy)]
+pub struct UseTiptapAutosaveReturn {
+    /// The current autosave status.
+    pub status: Signal<TiptapAutosaveStatus>,
+    /// The revision returned by the last successful save, or the initial revision.
+    pub revision: Signal<u64>,
+    /// Saves right away, without waiting for the debounce delay.
+    ///
+    /// Does nothing while the status is [`TiptapAutosaveStatus::Conflict`], as the save would be
+    /// based on a stale revision. Use `resolve_conflict` instead.
+    pub save_now: Callback<()>,
+    /// Adopts the given server revision and saves the current document on top of it.
+    ///
+    /// Call it once the conflict was resolved, for example after merging the newer server state
+    /// into the editor.
+    pub resolve_conflict: Callback<u64>,
+}
+
+type SaveFuture = Pin<Box<dyn Future<Output = Result<TiptapAutosaveResponse, TiptapEditorReport>>>>;
+type SaveFn = Arc<dyn Fn(TiptapAutosaveRequest) -> SaveFuture + Send + Sync>;
+
+#[derive(Clone, Copy)]
+struct Autosave {
+    handle: TiptapEditorHandle,
+    options: TiptapAutosaveOptions,
+    save: StoredValue<SaveFn>,
+    status: RwSignal<TiptapAutosaveStatus>,
+    revision: RwSignal<u64>,
+    /// Identifies the latest scheduled timer. Older timers do nothing when they fire.
+    timer: StoredValue<u64>,
+    in_flight: StoredValue<bool>,
+    changed_during_save: StoredValue<bool>,
+    retry_delay: StoredValue<Duration>,
+}
+
+/// The side effect of an autosave state transition.
+#[derive(Debug, PartialEq)]
+enum Next {
+    Idle,
+    Schedule(Duration),
+    Save(TiptapAutosaveRequest),
+}
+
+impl Autosave {
+    fn new(handle: TiptapEditorHandle, options: TiptapAutosaveOptions, save: SaveFn) -> Self {
+        Self {
+            handle,
+            options,
+            save: StoredValue::new(save),
+            status: RwSignal::new(TiptapAutosaveStatus::Saved),
+            revision: RwSignal::new(options.initial_revision),
+            timer: StoredValue::new(0),
+            in_flight: StoredValue::new(false),
+            changed_during_save: StoredValue::new(false),
+            retry_delay: StoredValue::new(options.retry_delay),
+        }
+    }
+
+    fn mark_dirty(self) {
+        if self.in_flight.get_value() {
+            self.changed_during_save.set_value(true);
+            return;
+        }
+        if self.in_conflict() {
+            return;
+        }
+
+        self.status.set(TiptapAutosaveStatus::Dirty);
+        self.schedule(self.options.debounce);
+    }
+
+    fn in_conflict(self) -> bool {
+        self.status
+            .with_untracked(|status| matches!(status, TiptapAutosaveStatus::Conflict { .. }))
+    }
+
+    fn schedule(self, delay: Duration) {
+        let Some(timer) = self.timer.try_update_value(|timer| {
+            *timer += 1;
+            *timer
+        }) else {
+            return;
+        };
+
+        set_timeout(
+            move || {
+                if self.timer.try_get_value() == Some(timer) {
+                    self.save();
+                }
+            },
+            delay,
+        );
+    }
+
+    fn run(self, next: Next) {
+        match next {
+            Next::Idle => {}
+            Next::Schedule(delay) => self.schedule(delay),
+            Next::Save(request) => {
+                let save = self.save.get_value();
+                spawn_local(async move {
+                    let result = save(request).await;
+                    self.complete(result);
+                });
+            }
+        }
+    }
+
+    fn save(self) {
+        self.run(self.begin_save());
+    }
+
+    fn begin_save(self) -> Next {
+        if self.in_flight.get_value() {
+            self.changed_during_save.set_value(true);
+            return Next::Idle;
+        }
+        // Saving against a stale revision would overwrite the newer server state.
+        if self.in_conflict() {
+            return Next::Idle;
+        }
+
+        // Destroyed or remounting editors have no document to save. The save is retried, so
+        // a remounted editor picks it up.
+        let content = match self.handle.get_json() {
+            Ok(content) => content,
+            Err(err) => return self.fail(err),
+        };
+
+        self.in_flight.set_value(true);
+        self.changed_during_save.set_value(false);
+        self.status.set(TiptapAutosaveStatus::Saving);
+        Next::Save(TiptapAutosaveRequest {
+            content,
+            revision: self.revision.get_untracked(),
+        })
+    }
+
+    fn complete(self, result: Result<TiptapAutosaveResponse, TiptapEditorReport>) {
+        self.run(self.finish_save(result));
+    }
+
+    fn finish_save(self, result: Result<TiptapAutosaveResponse, TiptapEditorReport>) -> Next {
+        // The owner may have been disposed while the save was in flight.
+        if self.in_flight.try_set_value(false).is_some() {
+            return Next::Idle;
+        }
+
+        match result {
+            Ok(TiptapAutosaveResponse::Saved { revision }) => {
+                self.revision.set(revision);
+                self.retry_delay.set_value(self.options.retry_delay);
+                if self.changed_during_save.get_value() {
+                    self.status.set(TiptapAutosaveStatus::Dirty);
+                    Next::Schedule(self.options.debounce)
+                } else {
+                    self.status.set(TiptapAutosaveStatus::Saved);
+                    Next::Idle
+                }
+            }
+            Ok(TiptapAutosaveResponse::Conflict { revision }) => {
+                self.status.set(TiptapAutosaveStatus::Conflict { revision });
+                Next::Idle
+            }
+            Err(err) => self.fail(err),
+        }
+    }
+
+    fn fail(self, err: TiptapEditorReport) -> Next {
+        self.status.set(TiptapAutosaveStatus::Error(Arc::new(err)));
+
+        let delay = self.retry_delay.get_value();
+        self.retry_delay
+            .set_value(next_retry_delay(delay, self.options.max_retry_delay));
+        Next::Schedule(delay)
+    }
+
+    fn save_now(self) {
+        // Cancels a pending debounce or retry timer.
+        self.timer.update_value(|timer| *timer += 1);
+        self.save();
+    }
+
+    fn resolve_conflict(self, revision: u64) {
+        self.revision.set(revision);
+        self.status.set(TiptapAutosaveStatus::Dirty);
+        self.save_now();
+    }
+}
+
+fn next_retry_delay(delay: Duration, max_retry_delay: Duration) -> Duration {
+    delay.saturating_mul(2).min(max_retry_delay)
+}
+
+/// Saves the document of an editor whenever it changes.
+///
+/// Changes are batched for a second before `save` is called with the document and the revision
+/// it is based on. Failed saves are retried with exponential backoff. When the server reports a
+/// newer revision, autosaving pauses in [`TiptapAutosaveStatus::Conflict`].
+///
+/// Must be called within a reactive owner scope, and `handle` must be the handle of the editor.
+///
+/// # Example
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_tiptap::{
+///     TiptapAutosaveRequest, TiptapAutosaveResponse, TiptapEditorHandle, use_tiptap_autosave,
+/// };
+///
+/// async fn save_document(request: TiptapAutosaveRequest) -> Result<TiptapAutosaveResponse, String> {
+///     // Call a server function here.
+///     Ok(TiptapAutosaveResponse::Saved {
+///         revision: request.revision + 1,
+///     })
+/// }
+///
+/// # Owner::new().with(|| {
+/// let handle = TiptapEditorHandle::new();
+/// let autosave = use_tiptap_autosave(handle, save_document);
+/// # });
+/// ```
+pub fn use_tiptap_autosave<S, Fut, E>(
+    handle: TiptapEditorHandle,
+    save: S,
+) -> UseTiptapAutosaveReturn
+where
+    S: Fn(TiptapAutosaveRequest) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<TiptapAutosaveResponse, E>> + 'static,
+    E: Display,
+{
+    use_tiptap_autosave_with_options(handle, TiptapAutosaveOptions::default(), save)
+}
+
+/// Like [`use_tiptap_autosave`], with explicit revision and timing options.
+pub fn use_tiptap_autosave_with_options<S, Fut, E>(
+    handle: TiptapEditorHandle,
+    options: TiptapAutosaveOptions,
+    save: S,
+) -> UseTiptapAutosaveReturn
+where
+    S: Fn(TiptapAutosaveRequest) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<TiptapAutosaveResponse, E>> + 'static,
+    E: Display,
+{
+    let save: SaveFn = Arc::new(move |request| {
+        let response = save(request);
+        Box::pin(async move {
+            response
+                .await
+                .map_err(|err| TiptapEditorError::AutosaveFailed(err.to_string()).into())
+        })
+    });
+    let autosave = Autosave::new(handle, options, save);
+
+    Effect::new(move |tracked: Option<()>| {
+        handle.track_content_changes();
+        // The first run only subscribes to changes.
+        if tracked.is_some() {
+            autosave.mark_dirty();
+        }
+    });
+
+    UseTiptapAutosaveReturn {
+        status: autosave.status.into(),
+        revision: autosave.revision.into(),
+        save_now: Callback::new(move |()| autosave.save_now()),
+        resolve_conflict: Callback::new(move |revision| autosave.resolve_conflict(revision)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertr::prelude::*;
+
+    fn autosave() -> Autosave {
+        let save: SaveFn = Arc::new(|_| Box::pin(async { unreachable!("saves are not run") }));
+        Autosave::new(
+            TiptapEditorHandle::new(),
+            TiptapAutosaveOptions::default(),
+            save,
+        )
+    }
+
+    fn failure() -> TiptapEditorReport {
+        TiptapEditorError::AutosaveFailed("offline".to_owned()).into()
+    }
+
+    fn is_error(autosave: &Autosave) -> bool {
+        autosave
+            .status
+            .with_untracked(|status| matches!(status, TiptapAutosaveStatus::Error(_)))
+    }
+
+    #[test]
+    fn saving_without_a_ready_editor_reports_an_error_and_retries() {
+        Owner::new().with(|| {
+            let autosave = autosave();
+
+            assert_that!(autosave.begin_save()).is_equal_to(Next::Schedule(Duration::from_secs(1)));
+            assert_that!(is_error(&autosave)).is_true();
+            assert_that!(autosave.in_flight.get_value()).is_false();
+            assert_that!(autosave.begin_save()).is_equal_to(Next::Schedule(Duration::from_secs(2)));
+        });
+    }
+
+    #[test]
+    fn failed_saves_back_off_until_a_save_succeeds() {
+        Owner::new().with(|| {
+            let autosave = autosave();
+
+            autosave.in_flight.set_value(true);
+            assert_that!(autosave.finish_save(Err(failure())))
+                .is_equal_to(Next::Schedule(Duration::from_secs(1)));
+            assert_that!(is_error(&autosave)).is_true();
+
+            autosave.in_flight.set_value(true);
+            assert_that!(autosave.finish_save(Err(failure())))
+                .is_equal_to(Next::Schedule(Duration::from_secs(2)));
+
+            autosave.in_flight.set_value(true);
+            assert_that!(autosave.finish_save(Ok(TiptapAutosaveResponse::Saved { revision: 4 })))
+                .is_equal_to(Next::Idle);
+            assert_that!(autosave.revision.get_untracked()).is_equal_to(4);
+            assert_that!(autosave.retry_delay.get_value()).is_equal_to(Duration::from_secs(1));
+            assert_that!(
+                autosave
+                    .status
+                    .with_untracked(|status| { matches!(status, TiptapAutosaveStatus::Saved) })
+            )
+            .is_true();
+        });
+    }
+
+    #[test]
+    fn changes_during_a_save_are_saved_after_it() {
+        Owner::new().with(|| {
+            let autosave = autosave();
+            autosave.in_flight.set_value(true);
+
+            autosave.mark_dirty();
+            assert_that!(autosave.begin_save()).is_equal_to(Next::Idle);
+
+            assert_that!(autosave.finish_save(Ok(TiptapAutosaveResponse::Saved { revision: 1 })))
+                .is_equal_to(Next::Schedule(Duration::from_secs(1)));
+            assert_that!(autosave.revision.get_untracked()).is_equal_to(1);
+            assert_that!(
+                autosave
+                    .status
+                    .with_untracked(|status| { matches!(status, TiptapAutosaveStatus::Dirty) })
+            )
+            .is_true();
+        });
+    }
+
+    #[test]
+    fn conflicts_pause_saving() {
+        Owner::new().with(|| {
+            let autosave = autosave();
+            autosave.in_flight.set_value(true);
+
+            assert_that!(
+                autosave.finish_save(Ok(TiptapAutosaveResponse::Conflict { revision: 7 }))
+            )
+            .is_equal_to(Next::Idle);
+
+            autosave.mark_dirty();
+            autosave.save_now();
+            assert_that!(autosave.begin_save()).is_equal_to(Next::Idle);
+            assert_that!(autosave.revision.get_untracked()).is_equal_to(0);
+            assert_that!(autosave.status.with_untracked(|status| {
+                matches!(status, TiptapAutosaveStatus::Conflict { revision: 7 })
+            }))
+            .is_true();
+        });
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_maximum() {
+        let max = Duration::from_secs(30);
+
+        assert_that!(next_retry_delay(Duration::from_secs(1), max))
+            .is_equal_to(Duration::from_secs(2));
+        assert_that!(next_retry_delay(Duration::from_secs(20), max)).is_equal_to(max);
+    }
+}
@@ -1,9 +1,16 @@
+use super::{TiptapCollabConfig, TiptapCollabSteps};
+use super::{TiptapCollaborationConfig, TiptapSelectionRange};
 use super::{
-    TiptapContent, TiptapEditorHandle, TiptapEditorReport, TiptapExtension, TiptapSelectionState,
+    TiptapContent, TiptapCustomExtension, TiptapDecorationClick, TiptapDocumentChange,
+    TiptapDocumentChangeFormat, TiptapDraftBinding, TiptapDraftConfig, TiptapDropEvent,
+    TiptapEditorHandle, TiptapEditorReport, TiptapExtension, TiptapImageUpload, TiptapInputRule,
+    TiptapMarkSpec, TiptapNodeSpec, TiptapNodeView, TiptapPasteEvent, TiptapPasteRule,
+    TiptapSelectionState, TiptapShortcuts, TiptapTransaction, TiptapTransferAction,
 };
 use crate::runtime::{TiptapRuntimeMountOptions, TiptapRuntimeSession};
 use leptos::{attr, attr::Attr, prelude::*};
 use leptos_element_capture::{CapturedElement, ElementCaptureAttr};
+use std::sync::Arc;
 
 /// Input parameters for the `use_tiptap_editor` hook.
 ///
@@ -39,6 +46,29 @@ pub struct UseTiptapEditorInput {
     /// distinct.
     pub on_selection_change: Option<Callback<TiptapSelectionState>>,
 
+    /// Called when the editor gains focus.
+    pub on_focus: Option<Callback<()>>,
+
+    /// Called when the editor loses focus, for example to validate its content.
+    pub on_blur: Option<Callback<()>>,
+
+    /// Called with every transaction applied to the editor, telling whether it changed the
+    /// document or the selection, its meta written through `TiptapEditorHandle::set_meta`, and
+    /// whether it came from the user or from Rust.
+    pub on_transaction: Option<Callback<TiptapTransaction>>,
+
+    /// Called with every entry of the incremental document change stream.
+    ///
+    /// Setting this callback enables the stream. The first entry is a version `0` snapshot emitted
+    /// when the editor becomes ready; every later entry describes one document change relative to
+    /// the previous version, independently of `emit_update` options. Use
+    /// [`TiptapDocumentMirror`](crate::TiptapDocumentMirror) to maintain a schema-free copy of the
+    /// document from JSON patches.
+    pub on_document_change: Option<Callback<TiptapDocumentChange>>,
+
+    /// Wire format of the entries passed to `on_document_change`.
+    pub document_change_format: TiptapDocumentChangeFormat,
+
     /// Called whenever the JS bridge reports a runtime error.
     pub on_error: Option<Callback<TiptapEditorReport>>,
 
@@ -50,6 +80,36 @@ pub struct UseTiptapEditorInput {
     /// If omitted, all extensions enabled through Cargo features are activated.
     pub extensions: Option<Vec<TiptapExtension>>,
 
+    /// Extensions supplied by the application as JS modules, activated in addition to
+    /// `extensions`.
+    ///
+    /// Their commands are run through `TiptapEditorHandle::custom_command`.
+    pub custom_extensions: Vec<TiptapCustomExtension>,
+
+    /// Custom nodes declared in Rust, added to the schema of the editor.
+    ///
+    /// Their commands are run through `TiptapEditorHandle::set_spec` and
+    /// `TiptapEditorHandle::toggle_spec`.
+    pub node_specs: Vec<TiptapNodeSpec>,
+
+    /// Custom marks declared in Rust, added to the schema of the editor.
+    ///
+    /// Their commands are run through `TiptapEditorHandle::set_spec`,
+    /// `TiptapEditorHandle::toggle_spec`, and `TiptapEditorHandle::unset_spec`.
+    pub mark_specs: Vec<TiptapMarkSpec>,
+
+    /// Leptos components rendering the nodes of `node_specs`.
+    pub node_views: Vec<TiptapNodeView>,
+
+    /// Keyboard shortcuts handled by Rust callbacks, such as `Mod-s` to save.
+    pub shortcuts: Option<TiptapShortcuts>,
+
+    /// Rules formatting text while it is typed, such as replacing `-->` with an arrow.
+    pub input_rules: Vec<TiptapInputRule>,
+
+    /// Rules formatting pasted text.
+    pub paste_rules: Vec<TiptapPasteRule>,
+
     /// Placeholder text used by the Tiptap placeholder extension during editor initialization.
     ///
     /// The placeholder extension adds empty-node classes and `data-placeholder` attributes, but
@@ -59,6 +119,67 @@ pub struct UseTiptapEditorInput {
     /// See the official Tiptap Placeholder docs for CSS examples:
     /// <https://tiptap.dev/docs/editor/extensions/functionality/placeholder>.
     pub placeholder: Option<String>,
+
+    /// Enables `prosemirror-collab` collaboration through a central authority.
+    ///
+    /// `initial_content` must be the authority's document at the configured version. Requires the
+    /// `collab` feature, without which the editor fails to mount.
+    pub collab: Option<TiptapCollabConfig>,
+
+    /// Called when local steps become sendable to the central authority.
+    ///
+    /// It is called again whenever the unconfirmed steps change, for example after more local
+    /// edits or after received steps rebased them. Apps that already have a submission in flight
+    /// can ignore the call and read `TiptapEditorHandle::sendable_steps` once it completes.
+    pub on_collab_sendable: Option<Callback<TiptapCollabSteps>>,
+
+    /// Binds the editor to a Yjs document through the collaboration extension.
+    ///
+    /// The collaboration extension must be part of `extensions`, and `initial_content` is ignored
+    /// in favor of the configured initial update. Requires the `collaboration` feature, without
+    /// which the editor fails to mount.
+    pub collaboration: Option<TiptapCollaborationConfig>,
+
+    /// Called with every Yjs update (v1 encoding) caused by this editor.
+    ///
+    /// Updates applied through `TiptapEditorHandle::apply_update` are not reported. Forward the
+    /// updates to the other clients, or to a `yrs` document on your server.
+    pub on_local_update: Option<Callback<Vec<u8>>>,
+
+    /// Called with the local selection whenever its anchor or head moves.
+    ///
+    /// Forward it to the other clients, which render it through
+    /// `TiptapEditorHandle::set_remote_cursors`.
+    pub on_local_selection: Option<Callback<TiptapSelectionRange>>,
+
+    /// Called when a decoration set through `TiptapEditorHandle::set_decorations` is clicked.
+    pub on_decoration_click: Option<Callback<TiptapDecorationClick>>,
+
+    /// Called with the threads of the comments at the cursor whenever the cursor enters or leaves
+    /// a commented range, with an empty list once it left all of them.
+    ///
+    /// This only has an effect when the `comments` extension is active.
+    pub on_active_comments: Option<Callback<Vec<String>>>,
+
+    /// Called with the clipboard content before it is pasted. The returned
+    /// [`TiptapTransferAction`] lets Tiptap paste it, cancels the paste, or inserts other content
+    /// instead, such as sanitized HTML.
+    pub on_paste: Option<Callback<TiptapPasteEvent, TiptapTransferAction>>,
+
+    /// Called with the dropped content and its target position before it is inserted. The
+    /// returned [`TiptapTransferAction`] lets Tiptap insert it, cancels the drop, or inserts other
+    /// content instead, such as images for uploaded files.
+    pub on_drop: Option<Callback<TiptapDropEvent, TiptapTransferAction>>,
+
+    /// Uploads pasted and dropped images, showing a placeholder with their progress until they
+    /// are inserted as image nodes. Needs the image extension.
+    pub image_upload: Option<TiptapImageUpload>,
+
+    /// Periodically saves the document and selection as a draft, keyed by `id`.
+    ///
+    /// Offer `TiptapEditorHandle::restore_draft` to recover the saved state after a crash or
+    /// reload, and call `TiptapEditorHandle::discard_draft` once the content was submitted.
+    pub drafts: Option<TiptapDraftConfig>,
 }
 
 impl UseTiptapEditorInput {
@@ -83,10 +204,33 @@ impl Default for UseTiptapEditorInput {
             on_ready: None,
             on_change: None,
             on_selection_change: None,
+            on_focus: None,
+            on_blur: None,
+            on_transaction: None,
+            on_document_change: None,
+            document_change_format: TiptapDocumentChangeFormat::default(),
             on_error: None,
             disabled: Signal::derive(|| false),
             extensions: None,
+            custom_extensions: Vec::new(),
+            node_specs: Vec::new(),
+            mark_specs: Vec::new(),
+            node_views: Vec::new(),
+            shortcuts: None,
+            input_rules: Vec::new(),
+            paste_rules: Vec::new(),
             placeholder: None,
+            collab: None,
+            on_collab_sendable: None,
+            collaboration: None,
+            on_local_update: None,
+            on_local_selection: None,
+            on_decoration_click: None,
+            on_active_comments: None,
+            on_paste: None,
+            on_drop: None,
+            image_upload: None,
+            drafts: None,
         }
     }
 }
@@ -175,6 +319,7 @@ pub struct UseTiptapEditorReturn {
 ///     }
 /// }
 /// ```
+#[allow(clippy::too_many_lines)]
 pub fn use_tiptap_editor(input: UseTiptapEditorInput) -> UseTiptapEditorReturn {
     let UseTiptapEditorInput {
         id,
@@ -185,21 +330,70 @@ pub fn use_tiptap_editor(input: UseTiptapEditorInput) -> UseTiptapEditorReturn {
         on_error,
         disabled,
         extensions,
+        custom_extensions,
+        node_specs,
+        mark_specs,
+        node_views,
+        shortcuts,
+        input_rules,
+        paste_rules,
         placeholder,
         on_selection_change,
+        on_focus,
+        on_blur,
+        on_transaction,
+        on_document_change,
+        document_change_format,
+        collab,
+        on_collab_sendable,
+        collaboration,
+        on_local_update,
+        on_local_selection,
+        on_decoration_click,
+        on_active_comments,
+        on_paste,
+        on_drop,
+        image_upload,
+        drafts,
     } = input;
 
     let handle = handle.unwrap_or_default();
+    handle.set_draft_binding(
+        drafts.map(|drafts| Arc::new(TiptapDraftBinding::new(drafts, id.clone()))),
+    );
     let session = TiptapRuntimeSession::new(id, handle);
     let mount_options = TiptapRuntimeMountOptions {
         initial_content,
         initial_editable: !disabled.get_untracked(),
         extensions: extensions.unwrap_or_else(TiptapExtension::all_enabled),
+        custom_extensions,
+        node_specs,
+        mark_specs,
+        node_views,
+        shortcuts,
+        input_rules,
+        paste_rules,
+        owner: Owner::current(),
         placeholder,
         on_ready,
         on_change,
         on_error,
         on_selection_change,
+        on_focus,
+        on_blur,
+        on_transaction,
+        on_document_change,
+        document_change_format,
+        collab,
+        on_collab_sendable,
+        collaboration,
+        on_local_update,
+        on_local_selection,
+        on_decoration_click,
+        on_active_comments,
+        on_paste,
+        on_drop,
+        image_upload,
     };
 
     let element = CapturedElement::new();
@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::runtime;
+
+use super::{
+    TiptapContent, TiptapDocumentDiff, TiptapEditorError, TiptapEditorHandle, TiptapEditorResult,
+    TiptapVersion, TiptapVersionDetails,
+};
+
+/// Persists recorded versions, keyed by document.
+///
+/// Implement this trait to keep versions in a backend of your choice, or use
+/// [`TiptapMemoryVersionStore`] in tests.
+pub trait TiptapVersionStore: Send + Sync {
+    /// Returns the versions stored under `key`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend can not be read or holds invalid versions.
+    fn list(&self, key: &str) -> TiptapEditorResult<Vec<TiptapVersion>>;
+
+    /// Returns the version `id` stored under `key`, if there is one.
+    ///
+    /// The default implementation searches the versions returned by [`list`](Self::list).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend can not be read or holds an invalid version.
+    fn load(&self, key: &str, id: u64) -> TiptapEditorResult<Option<TiptapVersion>> {
+        Ok(self.list(key)?.into_iter().find(|version| version.id == id))
+    }
+
+    /// Reserves the id of the next version stored under `key`.
+    ///
+    /// Ids must increase with each call and are never reused, so that a recorded version gets a
+    /// fresh id even when the newest versions were removed. Backends typically assign them from a
+    /// counter or sequence that removing versions does not reset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend can not be read or written.
+    fn next_id(&self, key: &str) -> TiptapEditorResult<u64>;
+
+    /// Adds `version` to the versions stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend can not be written.
+    fn save(&self, key: &str, version: &TiptapVersion) -> TiptapEditorResult<()>;
+
+    /// Removes the version `id` stored under `key`. Removing a missing version is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend can not be written.
+    fn remove(&self, key: &str, id: u64) -> TiptapEditorResult<()>;
+}
+
+/// Keeps versions in memory. Clones share their versions.
+#[derive(Debug, Clone, Default)]
+pub struct TiptapMemoryVersionStore {
+    documents: Arc<Mutex<HashMap<String, MemoryVersions>>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryVersions {
+    versions: Vec<TiptapVersion>,
+    last_id: u64,
+}
+
+impl TiptapMemoryVersionStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_documents<T>(&self, f: impl FnOnce(&mut HashMap<String, MemoryVersions>) -> T) -> T {
+        f(&mut self
+            .documents
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl TiptapVersionStore for TiptapMemoryVersionStore {
+    fn list(&self, key: &str) -> TiptapEditorResult<Vec<TiptapVersion>> {
+        Ok(self.with_documents(|documents| {
+            documents
+                .get(key)
+                .map(|document| document.versions.clone())
+                .unwrap_or_default()
+        }))
+    }
+
+    fn next_id(&self, key: &str) -> TiptapEditorResult<u64> {
+        Ok(self.with_documents(|documents| {
+            let document = documents.entry(key.to_owned()).or_default();
+            document.last_id += 1;
+            document.last_id
+        }))
+    }
+
+    fn save(&self, key: &str, version: &TiptapVersion) -> TiptapEditorResult<()> {
+        self.with_documents(|documents| {
+            let document = documents.entry(key.to_owned()).or_default();
+            document.last_id = document.last_id.max(version.id);
+            document.versions.push(version.clone());
+        });
+        Ok(())
+    }
+
+    fn remove(&self, key: &str, id: u64) -> TiptapEditorResult<()> {
+        self.with_documents(|documents| {
+            if let Some(document) = documents.get_mut(key) {
+                document.versions.retain(|version| version.id != id);
+            }
+        });
+        Ok(())
+    }
+}
+
+/// The recorded versions of one document.
+///
+/// Versions are snapshots of the editor's JSON content. Restoring a version replaces the editor
+/// content in a single transaction, so it can be undone like any other edit.
+///
+/// # Example
+///
+/// ```
+/// use leptos_tiptap::{TiptapMemoryVersionStore, TiptapVersionDetails, TiptapVersionHistory};
+/// use serde_json::json;
+///
+/// let history = TiptapVersionHistory::new(TiptapMemoryVersionStore::new(), "article-42");
+/// let first = history
+///     .record_content(
+///         json!({"type": "doc", "content": []}),
+///         TiptapVersionDetails {
+///             author: Some("Ada".to_owned()),
+///             label: Some("Outline".to_owned()),
+///         },
+///     )
+///     .unwrap();
+///
+/// assert_eq!(history.versions().unwrap(), vec![first]);
+/// ```
+#[derive(Clone)]
+pub struct TiptapVersionHistory {
+    store: Arc<dyn TiptapVersionStore>,
+    key: String,
+}
+
+impl TiptapVersionHistory {
+    /// Creates the history of the document `key`, whose versions are kept in `store`.
+    #[must_use]
+    pub fn new(store: impl TiptapVersionStore + 'static, key: impl Into<String>) -> Self {
+        Self {
+            store: Arc::new(store),
+            key: key.into(),
+        }
+    }
+
+    /// Returns the recorded versions, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the version store rejects the request.
+    pub fn versions(&self) -> TiptapEditorResult<Vec<TiptapVersion>> {
+        self.store.list(&self.key)
+    }
+
+    /// Returns the version `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersionNotFound`](TiptapEditorError::VersionNotFound) when there is no such
+    /// version, or an error when the version store rejects the request.
+    pub fn version(&self, id: u64) -> TiptapEditorResult<TiptapVersion> {
+        self.store
+            .load(&self.key, id)?
+            .ok_or_else(|| TiptapEditorError::VersionNotFound(id).into())
+    }
+
+    /// Records the current content of `editor` as a new version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the handle has no ready editor instance, or when the editor or the
+    /// version store rejects the request.
+    pub fn record(
+        &self,
+        editor: TiptapEditorHandle,
+        details: TiptapVersionDetails,
+    ) -> TiptapEditorResult<TiptapVersion> {
+        self.record_content(editor.get_json()?, details)
+    }
+
+    /// Records `content`, a document as Tiptap JSON, as a new version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the version store rejects the request.
+    pub fn record_content(
+        &self,
+        content: serde_json::Value,
+        details: TiptapVersionDetails,
+    ) -> TiptapEditorResult<TiptapVersion> {
+        let version = TiptapVersion {
+            id: self.store.next_id(&self.key)?,
+            timestamp: runtime::now_millis(),
+            author: details.author,
+            label: details.label,
+            content,
+        };
+        self.store.save(&self.key, &version)?;
+        Ok(version)
+    }
+
+    /// Replaces the content of `editor` with the version `id`, as a single undoable change.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersionNotFound`](TiptapEditorError::VersionNotFound) when there is no such
+    /// version, or an error when the handle has no ready editor instance, or when the editor or
+    /// the version store rejects the request.
+    pub fn restore(&self, editor: TiptapEditorHandle, id: u64) -> TiptapEditorResult<()> {
+        editor.with_instance(|instance| {
+            instance.set_content(TiptapContent::Json(self.version(id)?.content))
+        })
+    }
+
+    /// Removes the version `id`. Removing a missing version is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the version store rejects the request.
+    pub fn remove(&self, id: u64) -> TiptapEditorResult<()> {
+        self.store.remove(&self.key, id)
+    }
+
+    /// Compares the versions `from` and `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersionNotFound`](TiptapEditorError::VersionNotFound) when either version does
+    /// not exist, or an error when the version store rejects the request.
+    pub fn diff(&self, from: u64, to: u64) -> TiptapEditorResult<TiptapDocumentDiff> {
+        Ok(TiptapDocumentDiff::between(
+            &self.version(from)?.content,
+            &self.version(to)?.content,
+        ))
+    }
+}
+
+impl std::fmt::Debug for TiptapVersionHistory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TiptapVersionHistory")
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TiptapDiffOperation, TiptapTextChange};
+    use assertr::prelude::*;
+    use leptos::prelude::Owner;
+    use serde_json::{Value, json};
+
+    fn doc(text: &str) -> Value {
+        json!({
+            "type": "doc",
+            "content": [{"type": "paragraph", "content": [{"type": "text", "text": text}]}],
+        })
+    }
+
+    fn labeled(label: &str) -> TiptapVersionDetails {
+        TiptapVersionDetails {
+            author: Some("Ada".to_owned()),
+            label: Some(label.to_owned()),
+        }
+    }
+
+    #[test]
+    fn records_versions_with_increasing_ids() {
+        let store = TiptapMemoryVersionStore::new();
+        let history = TiptapVersionHistory::new(store.clone(), "article");
+
+        let first = history.record_content(doc("a"), labeled("first")).unwrap();
+        let second = history.record_content(doc("b"), labeled("second")).unwrap();
+
+        assert_that!(first.id).is_equal_to(1);
+        assert_that!(second.id).is_equal_to(2);
+        assert_that!(second.timestamp >= first.timestamp).is_true();
+        assert_that!(second.author.as_deref()).is_equal_to(Some("Ada"));
+        assert_that!(history.versions().unwrap()).is_equal_to(vec![first, second.clone()]);
+        assert_that!(store.list("other").unwrap()).is_empty();
+
+        history.remove(1).unwrap();
+        let third = history.record_content(doc("c"), labeled("third")).unwrap();
+        assert_that!(third.id).is_equal_to(3);
+        assert_that!(history.versions().unwrap()).is_equal_to(vec![second.clone(), third]);
+
+        history.remove(3).unwrap();
+        let fourth = history.record_content(doc("d"), labeled("fourth")).unwrap();
+        assert_that!(fourth.id).is_equal_to(4);
+        assert_that!(history.versions().unwrap()).is_equal_to(vec![second, fourth]);
+    }
+
+    #[test]
+    fn diffs_recorded_versions() {
+        let history = TiptapVersionHistory::new(TiptapMemoryVersionStore::new(), "article");
+        history
+            .record_content(doc("first draft"), labeled("first"))
+            .unwrap();
+        history
+            .record_content(doc("second draft"), labeled("second"))
+            .unwrap();
+
+        let diff = history.diff(1, 2).unwrap();
+
+        assert_that!(diff.operations).is_equal_to(vec![TiptapDiffOperation::Changed {
+            from: doc("first draft")["content"][0].clone(),
+            to: doc("second draft")["content"][0].clone(),
+            attributes: Vec::new(),
+            text: vec![
+                TiptapTextChange::Deleted("first".to_owned()),
+                TiptapTextChange::Inserted("second".to_owned()),
+                TiptapTextChange::Unchanged(" draft".to_owned()),
+            ],
+        }]);
+        assert_that!(history.diff(1, 3).unwrap_err().into_current_context())
+            .is_equal_to(TiptapEditorError::VersionNotFound(3));
+    }
+
+    #[test]
+    fn restoring_requires_a_ready_editor() {
+        Owner::new().with(|| {
+            let history = TiptapVersionHistory::new(TiptapMemoryVersionStore::new(), "article");
+            history.record_content(doc("a"), labeled("first")).unwrap();
+            let handle = TiptapEditorHandle::new();
+
+            assert_that!(
+                history
+                    .restore(handle, 1)
+                    .unwrap_err()
+                    .into_current_context()
+            )
+            .is_equal_to(TiptapEditorError::NotReady);
+            assert_that!(
+                history
+                    .record(handle, labeled("second"))
+                    .unwrap_err()
+                    .into_current_context()
+            )
+            .is_equal_to(TiptapEditorError::NotReady);
+        });
+    }
+}
@@ -0,0 +1,765 @@
+use std::sync::Arc;
+
+use leptos::attr::any_attribute::{AnyAttribute, IntoAnyAttribute};
+use leptos::attr::custom::custom_attribute;
+use leptos::prelude::*;
+use leptos_classes::{Classes, MergeStrategy};
+use leptos_styles::Styles;
+use serde_json::{Map, Value};
+
+use super::{TiptapAttributes, TiptapMarkSpec, TiptapNodeSpec};
+
+/// The props passed to the component rendering a node in a [`TiptapViewer`].
+pub struct TiptapNodeRendererProps {
+    /// The attributes of the node, as stored in the document.
+    pub attributes: TiptapAttributes,
+    /// The content of the node, rendered by the viewer.
+    pub children: AnyView,
+}
+
+impl std::fmt::Debug for TiptapNodeRendererProps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TiptapNodeRendererProps")
+            .field("attributes", &self.attributes)
+            .finish_non_exhaustive()
+    }
+}
+
+type RenderNode = Arc<dyn Fn(TiptapNodeRendererProps) -> AnyView + Send + Sync>;
+
+/// Renders the nodes of a type through a Leptos component in a [`TiptapViewer`], instead of the
+/// element the editor renders them to.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_tiptap::{TiptapNodeRenderer, TiptapNodeRendererProps};
+///
+/// let mention = TiptapNodeRenderer::new("mention", |props: TiptapNodeRendererProps| {
+///     let name = props
+///         .attributes
+///         .get("label")
+///         .and_then(serde_json::Value::as_str)
+///         .unwrap_or_default()
+///         .to_owned();
+///     view! { <a href=format!("/users/{name}")>"@" {name.clone()}</a> }
+/// });
+/// ```
+#[derive(Clone)]
+pub struct TiptapNodeRenderer {
+    name: String,
+    render: RenderNode,
+}
+
+impl TiptapNodeRenderer {
+    /// Renders the nodes of the type named `name` with `render`.
+    #[must_use]
+    pub fn new<V: IntoView + 'static>(
+        name: impl Into<String>,
+        render: impl Fn(TiptapNodeRendererProps) -> V + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            render: Arc::new(move |props| render(props).into_any()),
+        }
+    }
+
+    /// Returns the name of the node type rendered by this renderer.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::fmt::Debug for TiptapNodeRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TiptapNodeRenderer")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Renders a Tiptap JSON document to plain Leptos views, without mounting an editor.
+///
+/// Nodes and marks render to the same elements the compiled extensions render them to in the
+/// editor, so stylesheets written for the editor content apply. Custom nodes and marks render
+/// through the templates of their `node_specs` and `mark_specs`, and `node_renderers` replace
+/// the rendering of node types with Leptos components. Node types the viewer does not know render
+/// their content only.
+///
+/// The viewer needs no JavaScript and no unique id, renders on the server, and hydrates. Use it
+/// to display stored content, such as in list views.
+///
+/// # Example
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_tiptap::TiptapViewer;
+///
+/// #[component]
+/// fn Article(content: serde_json::Value) -> impl IntoView {
+///     view! { <TiptapViewer content=content /> }
+/// }
+/// ```
+#[component]
+pub fn TiptapViewer(
+    /// The Tiptap JSON document to render.
+    #[prop(into)]
+    content: Signal<Value>,
+
+    /// Specs of the custom nodes the document may contain, as passed to the editor.
+    #[prop(optional)]
+    node_specs: Vec<TiptapNodeSpec>,
+
+    /// Specs of the custom marks the document may contain, as passed to the editor.
+    #[prop(optional)]
+    mark_specs: Vec<TiptapMarkSpec>,
+
+    /// Leptos components rendering nodes of specific types.
+    #[prop(optional)]
+    node_renderers: Vec<TiptapNodeRenderer>,
+
+    /// Additional classes for the viewer element, which always carries the `leptos-tiptap-viewer`
+    /// and `tiptap` classes.
+    #[prop(into, optional)]
+    classes: Classes,
+
+    /// Inline styles for the viewer element.
+    #[prop(into, optional)]
+    styles: Styles,
+) -> impl IntoView {
+    let rules = Arc::new(ViewerRules {
+        node_specs,
+        mark_specs,
+        node_renderers,
+    });
+    let classes =
+        Classes::from(["leptos-tiptap-viewer", "tiptap"]).merge(classes, MergeStrategy::KeepSelf);
+
+    view! {
+        <div class={classes} style={styles}>
+            {move || {
+                content.with(|document| {
+                    rules.node(document).into_iter().map(ViewerNode::into_view).collect_view()
+                })
+            }}
+        </div>
+    }
+}
+
+/// A rendered node, before it becomes a view.
+enum ViewerNode {
+    Text(String),
+    Element {
+        tag: String,
+        attributes: Vec<(String, String)>,
+        children: Vec<ViewerNode>,
+    },
+    Renderer {
+        renderer: TiptapNodeRenderer,
+        attributes: TiptapAttributes,
+        children: Vec<ViewerNode>,
+    },
+}
+
+impl ViewerNode {
+    fn element(tag: &str, attributes: Vec<(String, String)>, children: Vec<Self>) -> Self {
+        Self::Element {
+            tag: tag.to_owned(),
+            attributes,
+            children,
+        }
+    }
+
+    fn into_view(self) -> AnyView {
+        match self {
+            Self::Text(text) => text.into_any(),
+            Self::Element {
+                tag,
+                attributes,
+                children,
+            } => {
+                let attributes = attributes
+                    .into_iter()
+                    .map(|(name, value)| custom_attribute(name, value).into_any_attr())
+                    .collect::<Vec<AnyAttribute>>();
+                // Void elements must not render a closing tag.
+                match tag.as_str() {
+                    "br" => leptos::html::br().add_any_attr(attributes).into_any(),
+                    "hr" => leptos::html::hr().add_any_attr(attributes).into_any(),
+                    "img" => leptos::html::img().add_any_attr(attributes).into_any(),
+                    _ => leptos::html::custom(tag)
+                        .add_any_attr(attributes)
+                        .child(Self::collect_view(children))
+                        .into_any(),
+                }
+            }
+            Self::Renderer {
+                renderer,
+                attributes,
+                children,
+            } => (renderer.render)(TiptapNodeRendererProps {
+                attributes,
+                children: Self::collect_view(children).into_any(),
+            }),
+        }
+    }
+
+    fn collect_view(children: Vec<Self>) -> Vec<AnyView> {
+        children.into_iter().map(Self::into_view).collect()
+    }
+}
+
+/// Schemes links may use. Others, like `javascript:`, render with an empty `href`, as in the
+/// link extension.
+const ALLOWED_LINK_SCHEMES: &[&str] = &[
+    "http", "https", "ftp", "ftps", "mailto", "tel", "callto", "sms", "cid", "xmpp",
+];
+
+const TEXT_ALIGNMENTS: &[&str] = &["center", "right", "justify"];
+
+struct ViewerRules {
+    node_specs: Vec<TiptapNodeSpec>,
+    mark_specs: Vec<TiptapMarkSpec>,
+    node_renderers: Vec<TiptapNodeRenderer>,
+}
+
+impl ViewerRules {
+    fn node(&self, node: &Value) -> Vec<ViewerNode> {
+        let node_type = node.get("type").and_then(Value::as_str).unwrap_or_default();
+        if node_type == "text" {
+            let text = node.get("text").and_then(Value::as_str).unwrap_or_default();
+            return vec![ViewerNode::Text(text.to_owned())];
+        }
+
+        let empty = Map::new();
+        let attributes = node
+            .get("attrs")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+        let children = self.content(node);
+        if let Some(renderer) = self
+            .node_renderers
+            .iter()
+            .find(|renderer| renderer.name == node_type)
+        {
+            return vec![ViewerNode::Renderer {
+                renderer: renderer.clone(),
+                attributes: attributes.clone().into(),
+                children,
+            }];
+        }
+
+        let element = match node_type {
+            "paragraph" => ViewerNode::element("p", text_align(attributes), children),
+            "heading" => {
+                let level = attributes
+                    .get("level")
+                    .and_then(Value::as_u64)
+                    .unwrap_or(1)
+                    .clamp(1, 6);
+                ViewerNode::element(&format!("h{level}"), text_align(attributes), children)
+            }
+            "blockquote" => ViewerNode::element("blockquote", Vec::new(), children),
+            "bulletList" => ViewerNode::element("ul", Vec::new(), children),
+            "orderedList" => {
+                let start = attributes.get("start").and_then(Value::as_i64).unwrap_or(1);
+                let start = (start != 1).then(|| ("start".to_owned(), start.to_string()));
+                ViewerNode::element("ol", start.into_iter().collect(), children)
+            }
+            "listItem" => ViewerNode::element("li", Vec::new(), children),
+            "codeBlock" => {
+                let language = string_attribute(attributes, "language")
+                    .map(|language| ("class".to_owned(), format!("language-{language}")));
+                ViewerNode::element(
+                    "pre",
+                    Vec::new(),
+                    vec![ViewerNode::element(
+                        "code",
+                        language.into_iter().collect(),
+                        children,
+                    )],
+                )
+            }
+            "hardBreak" => ViewerNode::element("br", Vec::new(), Vec::new()),
+            "horizontalRule" => ViewerNode::element("hr", Vec::new(), Vec::new()),
+            "image" => ViewerNode::element(
+                "img",
+                html_attributes(attributes, &["src", "alt", "title"]),
+                Vec::new(),
+            ),
+            "youtube" => youtube(attributes),
+            _ => match self.node_specs.iter().find(|spec| spec.name() == node_type) {
+                Some(spec) => {
+                    let (tag, template) = spec.render_template();
+                    let children = if spec.has_content() {
+                        children
+                    } else {
+                        Vec::new()
+                    };
+                    ViewerNode::element(
+                        &tag,
+                        spec_attributes(&template, spec.attributes(), attributes),
+                        children,
+                    )
+                }
+                // The document node, and nodes the viewer does not know, render their content.
+                None => return children,
+            },
+        };
+        vec![element]
+    }
+
+    /// Renders the content of `node`, wrapping runs of nodes that share a mark into a single
+    /// element of the mark, as the editor does.
+    fn content(&self, node: &Value) -> Vec<ViewerNode> {
+        let children = node
+            .get("content")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        self.marked(
+            children
+                .iter()
+                .map(|child| {
+                    let marks = child
+                        .get("marks")
+                        .and_then(Value::as_array)
+                        .map(|marks| marks.iter().collect())
+                        .unwrap_or_default();
+                    (marks, self.node(child))
+                })
+                .collect(),
+            0,
+        )
+    }
+
+    fn marked(&self, nodes: Vec<(Vec<&Value>, Vec<ViewerNode>)>, depth: usize) -> Vec<ViewerNode> {
+        let mut rendered = Vec::new();
+        let mut nodes = nodes.into_iter().peekable();
+        while let Some((marks, children)) = nodes.next() {
+            let Some(mark) = marks.get(depth).copied() else {
+                rendered.extend(children);
+                continue;
+            };
+
+            let mut run = vec![(marks, children)];
+            while let Some(next) = nodes.next_if(|(marks, _)| marks.get(depth) == Some(&mark)) {
+                run.push(next);
+            }
+            rendered.extend(self.mark(mark, self.marked(run, depth + 1)));
+        }
+        rendered
+    }
+
+    fn mark(&self, mark: &Value, children: Vec<ViewerNode>) -> Vec<ViewerNode> {
+        let mark_type = mark.get("type").and_then(Value::as_str).unwrap_or_default();
+        let empty = Map::new();
+        let attributes = mark
+            .get("attrs")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+        let element = match mark_type {
+            "bold" => ViewerNode::element("strong", Vec::new(), children),
+            "italic" => ViewerNode::element("em", Vec::new(), children),
+            "strike" => ViewerNode::element("s", Vec::new(), children),
+            "code" => ViewerNode::element("code", Vec::new(), children),
+            "highlight" => ViewerNode::element("mark", Vec::new(), children),
+            "link" => ViewerNode::element("a", link_attributes(attributes), children),
+            "insertion" | "deletion" => {
+                let mut html = vec![("class".to_owned(), format!("tracked-{mark_type}"))];
+                html.extend(tracked_change_attributes(attributes));
+                let tag = if mark_type == "insertion" {
+                    "ins"
+                } else {
+                    "del"
+                };
+                ViewerNode::element(tag, html, children)
+            }
+            "comment" => {
+                let resolved = attributes.get("resolved") == Some(&Value::Bool(true));
+                let mut html = vec![(
+                    "class".to_owned(),
+                    if resolved {
+                        "comment comment-resolved"
+                    } else {
+                        "comment"
+                    }
+                    .to_owned(),
+                )];
+                html.extend(
+                    string_attribute(attributes, "threadId")
+                        .map(|thread_id| ("data-thread-id".to_owned(), thread_id)),
+                );
+                if resolved {
+                    html.push(("data-resolved".to_owned(), "true".to_owned()));
+                }
+                ViewerNode::element("span", html, children)
+            }
+            _ => match self.mark_specs.iter().find(|spec| spec.name() == mark_type) {
+                Some(spec) => {
+                    let (tag, template) = spec.render_template();
+                    ViewerNode::element(
+                        &tag,
+                        spec_attributes(&template, spec.attributes(), attributes),
+                        children,
+                    )
+                }
+                None => return children,
+            },
+        };
+        vec![element]
+    }
+}
+
+fn string_attribute(attributes: &Map<String, Value>, name: &str) -> Option<String> {
+    attributes
+        .get(name)
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned)
+}
+
+/// Converts an attribute value to its HTML attribute value. Attributes without a value are
+/// omitted.
+fn html_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+fn html_attributes(attributes: &Map<String, Value>, names: &[&str]) -> Vec<(String, String)> {
+    names
+        .iter()
+        .filter_map(|name| {
+            let value = html_value(attributes.get(*name)?)?;
+            Some(((*name).to_owned(), value))
+        })
+        .collect()
+}
+
+fn text_align(attributes: &Map<String, Value>) -> Vec<(String, String)> {
+    attributes
+        .get("textAlign")
+        .and_then(Value::as_str)
+        .filter(|alignment| TEXT_ALIGNMENTS.contains(alignment))
+        .map(|alignment| ("style".to_owned(), format!("text-align: {alignment}")))
+        .into_iter()
+        .collect()
+}
+
+fn link_attributes(attributes: &Map<String, Value>) -> Vec<(String, String)> {
+    let href = attributes
+        .get("href")
+        .and_then(Value::as_str)
+        .filter(|href| is_allowed_uri(href))
+        .unwrap_or_default();
+    let mut html = vec![("href".to_owned(), href.to_owned())];
+    for (name, default) in [
+        ("target", Some("_blank")),
+        ("rel", Some("noopener noreferrer nofollow")),
+        ("class", None),
+    ] {
+        let value = match attributes.get(name) {
+            Some(value) => html_value(value),
+            None => default.map(ToOwned::to_owned),
+        };
+        html.extend(value.map(|value| (name.to_owned(), value)));
+    }
+    html
+}
+
+fn is_allowed_uri(uri: &str) -> bool {
+    let uri = uri
+        .chars()
+        .filter(|character| !character.is_whitespace() && !character.is_control())
+        .collect::<String>();
+    match uri.find([':', '/', '?', '#']) {
+        Some(index) if uri[index..].starts_with(':') => ALLOWED_LINK_SCHEMES
+            .iter()
+            .any(|scheme| uri[..index].eq_ignore_ascii_case(scheme)),
+        // Relative references have no scheme.
+        _ => true,
+    }
+}
+
+fn tracked_change_attributes(attributes: &Map<String, Value>) -> Vec<(String, String)> {
+    [
+        ("id", "data-change-id"),
+        ("author", "data-author"),
+        ("timestamp", "data-timestamp"),
+    ]
+    .into_iter()
+    .filter_map(|(name, html_name)| {
+        Some((html_name.to_owned(), html_value(attributes.get(name)?)?))
+    })
+    .collect()
+}
+
+fn youtube(attributes: &Map<String, Value>) -> ViewerNode {
+    let start = attributes.get("start").and_then(Value::as_u64).unwrap_or(0);
+    let iframe = attributes
+        .get("src")
+        .and_then(Value::as_str)
+        .and_then(|src| youtube_embed_url(src, start))
+        .map(|src| {
+            let dimension = |name: &str, default: u64| {
+                let value = attributes
+                    .get(name)
+                    .and_then(Value::as_u64)
+                    .unwrap_or(default);
+                (name.to_owned(), value.to_string())
+            };
+            ViewerNode::element(
+                "iframe",
+                vec![
+                    dimension("width", 640),
+                    dimension("height", 480),
+                    ("allowfullscreen".to_owned(), "true".to_owned()),
+                    ("src".to_owned(), src),
+                ],
+                Vec::new(),
+            )
+        });
+    ViewerNode::element(
+        "div",
+        vec![("data-youtube-video".to_owned(), String::new())],
+        iframe.into_iter().collect(),
+    )
+}
+
+/// Returns the embed URL of a video URL of the `youtube` extension, or `None` for other URLs.
+fn youtube_embed_url(src: &str, start: u64) -> Option<String> {
+    let (_, rest) = src.split_once("://").unwrap_or(("", src));
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = host.trim_start_matches("www.").trim_start_matches("m.");
+    let (path, query) = path.split_once('?').unwrap_or((path, ""));
+    let id = match host {
+        "youtu.be" => Some(path),
+        "youtube.com" | "youtube-nocookie.com" => path
+            .strip_prefix("embed/")
+            .or_else(|| path.strip_prefix("shorts/"))
+            .or_else(|| {
+                query
+                    .split('&')
+                    .find_map(|parameter| parameter.strip_prefix("v="))
+            }),
+        _ => None,
+    }?;
+    let id = id.split(['/', '&', '#']).next().unwrap_or_default();
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_'))
+    {
+        return None;
+    }
+
+    let start = if start > 0 {
+        format!("?start={start}")
+    } else {
+        String::new()
+    };
+    Some(format!("https://www.youtube.com/embed/{id}{start}"))
+}
+
+/// Merges the attributes of a spec node or mark into the static attributes of its template.
+/// Declared attributes render under their own name, falling back to their defaults.
+fn spec_attributes(
+    template: &TiptapAttributes,
+    defaults: &TiptapAttributes,
+    attributes: &Map<String, Value>,
+) -> Vec<(String, String)> {
+    let mut html: Vec<(String, String)> = Vec::new();
+    let declared = defaults
+        .as_map()
+        .iter()
+        .map(|(name, default)| (name, attributes.get(name).unwrap_or(default)));
+    for (name, value) in template.as_map().iter().chain(declared) {
+        let Some(value) = html_value(value) else {
+            continue;
+        };
+        match html.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, existing)) if name == "class" => *existing = format!("{existing} {value}"),
+            Some((_, existing)) if name == "style" => *existing = format!("{existing}; {value}"),
+            Some((_, existing)) => *existing = value,
+            None => html.push((name.clone(), value)),
+        }
+    }
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use super::*;
+    use assertr::prelude::*;
+    use serde_json::json;
+
+    fn rules() -> ViewerRules {
+        ViewerRules {
+            node_specs: Vec::new(),
+            mark_specs: Vec::new(),
+            node_renderers: Vec::new(),
+        }
+    }
+
+    fn html(nodes: &[ViewerNode]) -> String {
+        nodes
+            .iter()
+            .map(|node| match node {
+                ViewerNode::Text(text) => text.clone(),
+                ViewerNode::Element {
+                    tag,
+                    attributes,
+                    children,
+                } => {
+                    let attributes =
+                        attributes
+                            .iter()
+                            .fold(String::new(), |mut html, (name, value)| {
+                                let _ = write!(html, " {name}=\"{value}\"");
+                                html
+                            });
+                    format!("<{tag}{attributes}>{}</{tag}>", html(children))
+                }
+                ViewerNode::Renderer {
+                    renderer, children, ..
+                } => format!("<{}>{}</{}>", renderer.name, html(children), renderer.name),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn renders_nodes_and_marks_like_the_compiled_extensions() {
+        let document = json!({
+            "type": "doc",
+            "content": [
+                {
+                    "type": "heading",
+                    "attrs": {"level": 2, "textAlign": "center"},
+                    "content": [{"type": "text", "text": "Title"}],
+                },
+                {
+                    "type": "paragraph",
+                    "attrs": {"textAlign": "left"},
+                    "content": [
+                        {"type": "text", "marks": [{"type": "bold"}], "text": "bold "},
+                        {"type": "text", "marks": [{"type": "bold"}, {"type": "italic"}], "text": "both"},
+                        {"type": "hardBreak"},
+                        {
+                            "type": "text",
+                            "marks": [{"type": "link", "attrs": {"href": "javascript:alert(1)", "target": null}}],
+                            "text": "link",
+                        },
+                    ],
+                },
+                {
+                    "type": "orderedList",
+                    "attrs": {"start": 3},
+                    "content": [{
+                        "type": "listItem",
+                        "content": [{"type": "paragraph", "content": [{"type": "text", "text": "<item>"}]}],
+                    }],
+                },
+                {
+                    "type": "codeBlock",
+                    "attrs": {"language": "rust"},
+                    "content": [{"type": "text", "text": "fn main() {}"}],
+                },
+            ],
+        });
+
+        assert_that!(html(&rules().node(&document))).is_equal_to(
+            concat!(
+                r#"<h2 style="text-align: center">Title</h2>"#,
+                "<p><strong>bold <em>both</em></strong><br></br>",
+                r#"<a href="" rel="noopener noreferrer nofollow">link</a></p>"#,
+                r#"<ol start="3"><li><p><item></p></li></ol>"#,
+                r#"<pre><code class="language-rust">fn main() {}</code></pre>"#,
+            )
+            .to_owned(),
+        );
+    }
+
+    #[test]
+    fn renders_spec_nodes_and_marks_through_their_templates() {
+        let rules = ViewerRules {
+            node_specs: vec![
+                TiptapNodeSpec::new("callout")
+                    .content("paragraph+")
+                    .attribute("kind", "info")
+                    .render_html("div", [("class", "callout")]),
+                TiptapNodeSpec::new("mention")
+                    .inline(true)
+                    .atom(true)
+                    .attribute("id", Value::Null),
+            ],
+            mark_specs: vec![TiptapMarkSpec::new("badge").attribute("class", "badge-new")],
+            node_renderers: Vec::new(),
+        };
+        let document = json!({
+            "type": "callout",
+            "attrs": {"kind": "warning", "onclick": "alert(1)"},
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    {"type": "mention", "attrs": {"id": 7}},
+                    {"type": "text", "marks": [{"type": "badge"}], "text": "new"},
+                    {"type": "unknown", "content": [{"type": "text", "text": " kept"}]},
+                ],
+            }],
+        });
+
+        assert_that!(html(&rules.node(&document))).is_equal_to(
+            concat!(
+                r#"<div class="callout" kind="warning"><p>"#,
+                r#"<span data-type="mention" id="7"></span>"#,
+                r#"<span data-type="badge" class="badge-new">new</span> kept</p></div>"#,
+            )
+            .to_owned(),
+        );
+    }
+
+    #[test]
+    fn renders_overridden_node_types_through_their_renderers() {
+        let rules = ViewerRules {
+            node_renderers: vec![TiptapNodeRenderer::new(
+                "blockquote",
+                |_: TiptapNodeRendererProps| (),
+            )],
+            ..rules()
+        };
+        let nodes = rules.node(&json!({
+            "type": "blockquote",
+            "attrs": {"cite": "source"},
+            "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Quote"}]}],
+        }));
+
+        assert_that!(html(&nodes)).is_equal_to("<blockquote><p>Quote</p></blockquote>".to_owned());
+        let [ViewerNode::Renderer { attributes, .. }] = nodes.as_slice() else {
+            panic!("expected the blockquote renderer");
+        };
+        assert_that!(attributes.get("cite")).is_equal_to(Some(&json!("source")));
+    }
+
+    #[test]
+    fn embeds_youtube_videos_by_their_id() {
+        assert_that!(youtube_embed_url(
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=1",
+            0
+        ))
+        .is_equal_to(Some("https://www.youtube.com/embed/dQw4w9WgXcQ".to_owned()));
+        assert_that!(youtube_embed_url("https://youtu.be/dQw4w9WgXcQ", 42)).is_equal_to(Some(
+            "https://www.youtube.com/embed/dQw4w9WgXcQ?start=42".to_owned(),
+        ));
+        assert_that!(youtube_embed_url(
+            "https://example.com/watch?v=dQw4w9WgXcQ",
+            0
+        ))
+        .is_none();
+        assert_that!(youtube_embed_url("https://youtu.be/\"onload=\"", 0)).is_none();
+    }
+}
@@ -0,0 +1,3 @@
+// Placeholder until `just bundle-tiptap` bundles tiptap/src/collab_module.ts. Without the bundled
+// module, creating an editor with a collab configuration fails.
+export function install_collab_module() {}
@@ -0,0 +1,21 @@
+// Placeholder until `just bundle-tiptap` bundles tiptap/src/extensions/tiptap_collaboration.ts
+// together with yjs and y-prosemirror. Editors without a collaboration configuration work as
+// usual; binding a Yjs document fails until the bundle is generated.
+var bridgeBindings = globalThis.__LEPTOS_TIPTAP_BRIDGE_V1__?.getBindings(new URL(".", import.meta.url).href);
+if (bridgeBindings == null || bridgeBindings.modules == null) throw new Error("leptos-tiptap bridge bindings are unavailable");
+
+var descriptor = {
+  name: "collaboration",
+  create: ({ collaboration }) => {
+    if (collaboration == null) {
+      return [];
+    }
+    throw new Error("leptos-tiptap collaboration bundle is missing, rebuild it with `just bundle-tiptap`");
+  }
+};
+function register_collaboration() {
+  bridgeBindings.registerExtension(descriptor);
+}
+export {
+  register_collaboration
+};
@@ -0,0 +1,181 @@
+var bridgeBindings = globalThis.__LEPTOS_TIPTAP_BRIDGE_V1__?.getBindings(new URL(".", import.meta.url).href);
+if (bridgeBindings == null || bridgeBindings.modules == null) throw new Error("leptos-tiptap bridge bindings are unavailable");
+function hostedModule(name) {
+  const module = bridgeBindings.modules[name];
+  if (module == null) throw new Error(`leptos-tiptap bridge module "${name}" is unavailable`);
+  return module;
+}
+var { Extension, Mark, mergeAttributes } = hostedModule("@tiptap/core");
+var { Plugin, PluginKey } = hostedModule("@tiptap/pm/state");
+
+// src/bridge_extension_helpers.ts
+function registerOfficialExtension(descriptor2) {
+  bridgeBindings.registerExtension(descriptor2);
+}
+
+// src/extensions/tiptap_comments.ts
+var COMMENT = "comment";
+var commentsKey = new PluginKey("leptosTiptapComments");
+var commentMark = Mark.create({
+  name: COMMENT,
+  inclusive: false,
+  excludes: "",
+  addAttributes: () => ({
+    threadId: {
+      default: null,
+      parseHTML: (element) => element.getAttribute("data-thread-id"),
+      renderHTML: (attributes) => ({ "data-thread-id": attributes.threadId })
+    },
+    resolved: {
+      default: false,
+      parseHTML: (element) => element.getAttribute("data-resolved") === "true",
+      renderHTML: (attributes) => attributes.resolved === true ? { "data-resolved": "true" } : {}
+    }
+  }),
+  parseHTML: () => [{ tag: "span[data-thread-id]" }],
+  renderHTML: ({ mark, HTMLAttributes }) => [
+    "span",
+    mergeAttributes({ class: mark.attrs.resolved === true ? "comment comment-resolved" : "comment" }, HTMLAttributes),
+    0
+  ]
+});
+function collectCommentThreads(doc) {
+  const threads = /* @__PURE__ */ new Map();
+  doc.descendants((node, position) => {
+    if (!node.isInline) {
+      return true;
+    }
+    for (const mark of node.marks) {
+      if (mark.type.name !== COMMENT) {
+        continue;
+      }
+      const threadId = String(mark.attrs.threadId);
+      const range = { from: position, to: position + node.nodeSize };
+      const thread = threads.get(threadId);
+      if (thread == null) {
+        threads.set(threadId, { thread_id: threadId, ranges: [range], resolved: mark.attrs.resolved === true });
+        continue;
+      }
+      const last = thread.ranges[thread.ranges.length - 1];
+      if (last.to === range.from) {
+        last.to = range.to;
+      } else {
+        thread.ranges.push(range);
+      }
+    }
+    return false;
+  });
+  return [...threads.values()];
+}
+function activeCommentThreads(state) {
+  const { from, to, empty, $from } = state.selection;
+  const threadIds = /* @__PURE__ */ new Set();
+  const collect = (marks) => {
+    for (const mark of marks) {
+      if (mark.type.name === COMMENT) {
+        threadIds.add(String(mark.attrs.threadId));
+      }
+    }
+  };
+  if (empty) {
+    collect($from.marks().filter((mark) => $from.nodeAfter != null && mark.isInSet($from.nodeAfter.marks)));
+  } else {
+    state.doc.nodesBetween(from, to, (node) => {
+      collect(node.isInline ? node.marks : []);
+    });
+  }
+  return [...threadIds];
+}
+function updateThread(editor, threadId, resolved) {
+  const { state } = editor;
+  const type = state.schema.marks[COMMENT];
+  const tr = state.tr;
+  state.doc.descendants((node, position) => {
+    if (!node.isInline) {
+      return true;
+    }
+    const mark = node.marks.find((candidate) => candidate.type === type && candidate.attrs.threadId === threadId);
+    if (mark != null) {
+      const to = position + node.nodeSize;
+      tr.removeMark(position, to, mark);
+      if (resolved != null) {
+        tr.addMark(position, to, type.create({ ...mark.attrs, resolved }));
+      }
+    }
+    return false;
+  });
+  if (!tr.docChanged) {
+    return false;
+  }
+  editor.view.dispatch(tr);
+  return true;
+}
+function createCommentsPlugin(onThreads, onActive) {
+  return new Plugin({
+    key: commentsKey,
+    view: (view) => {
+      let lastThreads = JSON.stringify([]);
+      let lastActive = JSON.stringify([]);
+      const report = (state) => {
+        const threads = collectCommentThreads(state.doc);
+        const serializedThreads = JSON.stringify(threads);
+        if (serializedThreads !== lastThreads) {
+          lastThreads = serializedThreads;
+          onThreads(threads);
+        }
+        const active = activeCommentThreads(state);
+        const serializedActive = JSON.stringify(active);
+        if (serializedActive !== lastActive) {
+          lastActive = serializedActive;
+          onActive(active);
+        }
+      };
+      report(view.state);
+      return {
+        update: (updated, previous) => {
+          if (!updated.state.doc.eq(previous.doc) || !updated.state.selection.eq(previous.selection)) {
+            report(updated.state);
+          }
+        }
+      };
+    }
+  });
+}
+var descriptor = {
+  name: "comments",
+  create: ({ on_comments, on_active_comments }) => [
+    commentMark,
+    Extension.create({
+      name: "comments",
+      addProseMirrorPlugins: () => [
+        createCommentsPlugin(on_comments ?? (() => {
+        }), on_active_comments ?? (() => {
+        }))
+      ]
+    })
+  ],
+  commands: {
+    add_comment: (editor, command) => {
+      if (editor.state.selection.empty) {
+        return false;
+      }
+      const { from, to } = editor.state.selection;
+      const type = editor.state.schema.marks[COMMENT];
+      editor.view.dispatch(editor.state.tr.addMark(from, to, type.create({ threadId: command.thread_id })));
+      return true;
+    },
+    remove_comment: (editor, command) => updateThread(editor, command.thread_id, null),
+    set_comment_resolved: (editor, command) => updateThread(editor, command.thread_id, command.resolved)
+  }
+};
+function register_comments() {
+  registerOfficialExtension(descriptor);
+}
+var __testing = {
+  activeCommentThreads,
+  collectCommentThreads
+};
+export {
+  __testing,
+  register_comments
+};
@@ -0,0 +1,273 @@
+var bridgeBindings = globalThis.__LEPTOS_TIPTAP_BRIDGE_V1__?.getBindings(new URL(".", import.meta.url).href);
+if (bridgeBindings == null || bridgeBindings.modules == null) throw new Error("leptos-tiptap bridge bindings are unavailable");
+function hostedModule(name) {
+  const module = bridgeBindings.modules[name];
+  if (module == null) throw new Error(`leptos-tiptap bridge module "${name}" is unavailable`);
+  return module;
+}
+var { Extension, Mark, mergeAttributes } = hostedModule("@tiptap/core");
+var { Fragment, Slice } = hostedModule("@tiptap/pm/model");
+var { Plugin, PluginKey, TextSelection } = hostedModule("@tiptap/pm/state");
+var { Mapping, ReplaceStep } = hostedModule("@tiptap/pm/transform");
+
+// src/bridge_api.ts
+var SET_CONTENT_META = "leptosTiptapSetContent";
+
+// src/bridge_extension_helpers.ts
+function registerOfficialExtension(descriptor2) {
+  bridgeBindings.registerExtension(descriptor2);
+}
+
+// src/extensions/tiptap_track_changes.ts
+var INSERTION = "insertion";
+var DELETION = "deletion";
+var trackChangesKey = new PluginKey("leptosTiptapTrackChanges");
+var nextChangeId = 1;
+function createChangeId() {
+  const id = `${Date.now().toString(36)}-${nextChangeId.toString(36)}`;
+  nextChangeId += 1;
+  return id;
+}
+function createChangeMark(name, tag) {
+  return Mark.create({
+    name,
+    inclusive: false,
+    addAttributes: () => ({
+      id: {
+        default: null,
+        parseHTML: (element) => element.getAttribute("data-change-id"),
+        renderHTML: (attributes) => ({ "data-change-id": attributes.id })
+      },
+      author: {
+        default: "",
+        parseHTML: (element) => element.getAttribute("data-author") ?? "",
+        renderHTML: (attributes) => ({ "data-author": attributes.author })
+      },
+      timestamp: {
+        default: 0,
+        parseHTML: (element) => Number(element.getAttribute("data-timestamp") ?? 0),
+        renderHTML: (attributes) => ({ "data-timestamp": String(attributes.timestamp) })
+      }
+    }),
+    parseHTML: () => [{ tag: `${tag}[data-change-id]` }],
+    renderHTML: ({ HTMLAttributes }) => [tag, mergeAttributes({ class: `tracked-${name}` }, HTMLAttributes), 0]
+  });
+}
+function changeMarkTypes(state) {
+  return { insertion: state.schema.marks[INSERTION], deletion: state.schema.marks[DELETION] };
+}
+function isTracked(transaction) {
+  return transaction.docChanged && transaction.getMeta(trackChangesKey) == null && transaction.getMeta(SET_CONTENT_META) == null && transaction.getMeta("history$") == null && transaction.getMeta("collab$") == null && transaction.getMeta("y-sync$") == null;
+}
+function markDeleted(fragment, author, deletion, insertion, mark) {
+  const nodes = [];
+  fragment.forEach((node) => {
+    if (!node.isInline) {
+      nodes.push(node.copy(markDeleted(node.content, author, deletion, insertion, mark)));
+      return;
+    }
+    if (node.marks.some((existing) => existing.type === insertion && existing.attrs.author === author)) {
+      return;
+    }
+    nodes.push(deletion.isInSet(node.marks) != null ? node : node.mark(mark().addToSet(node.marks)));
+  });
+  return Fragment.fromArray(nodes);
+}
+function trackTransactions(transactions, oldState, newState, author) {
+  const { insertion, deletion } = changeMarkTypes(newState);
+  const tr = newState.tr;
+  const timestamp = Date.now();
+  const mapping = new Mapping();
+  for (const transaction of transactions) {
+    for (const map of transaction.mapping.maps) {
+      mapping.appendMap(map);
+    }
+  }
+  const toCurrent = (stepIndex2, position, assoc) => tr.mapping.map(mapping.slice(stepIndex2 + 1).map(position, assoc), assoc);
+  let stepIndex = -1;
+  for (const transaction of transactions) {
+    transaction.steps.forEach((step, index) => {
+      stepIndex += 1;
+      if (!isTracked(transaction) || !(step instanceof ReplaceStep)) {
+        return;
+      }
+      const before = transaction.docs[index];
+      const start = toCurrent(stepIndex, step.from, -1);
+      if (step.slice.size > 0) {
+        const end = toCurrent(stepIndex, step.from + step.slice.size, 1);
+        const previous = tr.doc.resolve(start).nodeBefore?.marks.find((mark) => mark.type === insertion && mark.attrs.author === author);
+        tr.removeMark(start, end, deletion);
+        tr.addMark(start, end, insertion.create({
+          id: previous?.attrs.id ?? createChangeId(),
+          author,
+          timestamp
+        }));
+      }
+      if (step.to > step.from) {
+        const deleted = before.slice(step.from, step.to);
+        const id = createChangeId();
+        const content = markDeleted(
+          deleted.content,
+          author,
+          deletion,
+          insertion,
+          () => deletion.create({ id, author, timestamp })
+        );
+        if (content.size === 0) {
+          return;
+        }
+        try {
+          tr.replace(start, start, new Slice(content, deleted.openStart, deleted.openEnd));
+        } catch {
+          return;
+        }
+        const { selection } = oldState;
+        if (step.slice.size === 0 && selection.empty && selection.head === step.to) {
+          tr.setSelection(TextSelection.create(tr.doc, start));
+        }
+      }
+    });
+  }
+  return tr.docChanged ? tr.setMeta(trackChangesKey, { kind: "untracked" }) : null;
+}
+function resolveChanges(state, ids, accept) {
+  const { insertion, deletion } = changeMarkTypes(state);
+  const tr = state.tr;
+  const removed = [];
+  state.doc.descendants((node, position) => {
+    if (!node.isInline) {
+      return true;
+    }
+    for (const mark of node.marks) {
+      if (mark.type !== insertion && mark.type !== deletion || ids != null && !ids.has(String(mark.attrs.id))) {
+        continue;
+      }
+      if (mark.type === insertion === accept) {
+        tr.removeMark(position, position + node.nodeSize, mark);
+      } else {
+        removed.push({ from: position, to: position + node.nodeSize });
+      }
+    }
+    return false;
+  });
+  if (removed.length === 0 && !tr.docChanged) {
+    return null;
+  }
+  for (const { from, to } of removed.reverse()) {
+    tr.delete(tr.mapping.map(from), tr.mapping.map(to));
+  }
+  return tr.setMeta(trackChangesKey, { kind: "untracked" });
+}
+function collectTrackedChanges(doc) {
+  const changes = /* @__PURE__ */ new Map();
+  doc.descendants((node, position) => {
+    if (!node.isInline) {
+      return true;
+    }
+    for (const mark of node.marks) {
+      const kind = mark.type.name === INSERTION ? "insertion" : mark.type.name === DELETION ? "deletion" : null;
+      if (kind == null) {
+        continue;
+      }
+      const key = `${kind}:${String(mark.attrs.id)}`;
+      const text = node.text ?? "";
+      const existing = changes.get(key);
+      if (existing != null) {
+        existing.range.to = position + node.nodeSize;
+        existing.text += text;
+        continue;
+      }
+      changes.set(key, {
+        id: String(mark.attrs.id),
+        kind,
+        author: String(mark.attrs.author),
+        timestamp: Number(mark.attrs.timestamp),
+        range: { from: position, to: position + node.nodeSize },
+        text
+      });
+    }
+    return false;
+  });
+  return [...changes.values()];
+}
+function createTrackChangesPlugin(onChanges) {
+  return new Plugin({
+    key: trackChangesKey,
+    state: {
+      init: () => ({ author: null }),
+      apply: (transaction, state) => {
+        const meta = transaction.getMeta(trackChangesKey);
+        return meta?.kind === "configure" ? { author: meta.author } : state;
+      }
+    },
+    appendTransaction: (transactions, oldState, newState) => {
+      const author = trackChangesKey.getState(newState)?.author;
+      return author == null ? null : trackTransactions(transactions, oldState, newState, author);
+    },
+    view: (view) => {
+      let last = JSON.stringify([]);
+      const report = (doc) => {
+        const changes = collectTrackedChanges(doc);
+        const serialized = JSON.stringify(changes);
+        if (serialized !== last) {
+          last = serialized;
+          onChanges(changes);
+        }
+      };
+      report(view.state.doc);
+      return {
+        update: (updated, previous) => {
+          if (!updated.state.doc.eq(previous.doc)) {
+            report(updated.state.doc);
+          }
+        }
+      };
+    }
+  });
+}
+function dispatchResolved(editor, ids, accept) {
+  const transaction = resolveChanges(editor.state, ids, accept);
+  if (transaction == null) {
+    return false;
+  }
+  editor.view.dispatch(transaction);
+  return true;
+}
+var descriptor = {
+  name: "track_changes",
+  create: ({ on_tracked_changes }) => [
+    createChangeMark(INSERTION, "ins"),
+    createChangeMark(DELETION, "del"),
+    Extension.create({
+      name: "trackChanges",
+      addProseMirrorPlugins: () => [createTrackChangesPlugin(on_tracked_changes ?? (() => {
+      }))]
+    })
+  ],
+  commands: {
+    set_track_changes: (editor, command) => {
+      editor.view.dispatch(
+        editor.state.tr.setMeta(trackChangesKey, { kind: "configure", author: command.author ?? null }).setMeta("addToHistory", false)
+      );
+      return true;
+    },
+    accept_change: (editor, command) => dispatchResolved(editor, /* @__PURE__ */ new Set([command.change_id]), true),
+    reject_change: (editor, command) => dispatchResolved(editor, /* @__PURE__ */ new Set([command.change_id]), false),
+    accept_all_changes: (editor) => dispatchResolved(editor, null, true),
+    reject_all_changes: (editor) => dispatchResolved(editor, null, false)
+  }
+};
+function register_track_changes() {
+  registerOfficialExtension(descriptor);
+}
+var __testing = {
+  collectTrackedChanges,
+  createChangeMark,
+  createTrackChangesPlugin,
+  resolveChanges,
+  trackChangesKey
+};
+export {
+  __testing,
+  register_track_changes
+};
@@ -10,17 +10,37 @@ mod runtime;
 
 #[cfg(feature = "component")]
 pub use api::component::TiptapEditor;
+#[cfg(feature = "component")]
+pub use api::viewer::{TiptapNodeRenderer, TiptapNodeRendererProps, TiptapViewer};
 pub use api::{
-    TiptapActiveKey, TiptapActiveState, TiptapAttributes, TiptapCodeBlockAttributes, TiptapContent,
-    TiptapEditorError, TiptapEditorHandle, TiptapEditorInstance, TiptapEditorReport,
-    TiptapEditorResult, TiptapExtension, TiptapFocusOptions, TiptapFocusTarget, TiptapHeadingLevel,
-    TiptapHighlightAttributes, TiptapImageResource, TiptapInsertContentOptions, TiptapLinkResource,
-    TiptapListKind, TiptapMarkName, TiptapMarkOptions, TiptapNodeName, TiptapParseOptions,
-    TiptapPositionOrRange, TiptapRange, TiptapSchemaTarget, TiptapSelectionState,
-    TiptapSetContentOptions, TiptapSplitBlockOptions, TiptapTextAlign, TiptapToggleListOptions,
-    TiptapWhitespaceMode, TiptapYoutubeVideoResource, UseTiptapEditorAttrs, UseTiptapEditorInput,
-    UseTiptapEditorProps, UseTiptapEditorReturn, use_tiptap_editor,
+    TiptapActiveKey, TiptapActiveState, TiptapAttributeChange, TiptapAttributes,
+    TiptapAutosaveOptions, TiptapAutosaveRequest, TiptapAutosaveResponse, TiptapAutosaveStatus,
+    TiptapCodeBlockAttributes, TiptapCollaborationConfig, TiptapCollaborationUser,
+    TiptapCommentThread, TiptapContent, TiptapCustomExtension, TiptapDecoration,
+    TiptapDecorationClick, TiptapDiffOperation, TiptapDocumentChange, TiptapDocumentChangeFormat,
+    TiptapDocumentChangePayload, TiptapDocumentDiff, TiptapDocumentMirror, TiptapDraft,
+    TiptapDraftConfig, TiptapDraftStore, TiptapDropEvent, TiptapEditorError, TiptapEditorHandle,
+    TiptapEditorInstance, TiptapEditorReport, TiptapEditorResult, TiptapExtension,
+    TiptapFocusOptions, TiptapFocusTarget, TiptapFormFormat, TiptapHeadingLevel,
+    TiptapHighlightAttributes, TiptapImageResource, TiptapImageUpload, TiptapIndexedDbDraftStore,
+    TiptapInputRule, TiptapInsertContentOptions, TiptapJsonPatchOperation, TiptapLinkResource,
+    TiptapListKind, TiptapLocalStorageDraftStore, TiptapMarkName, TiptapMarkOptions,
+    TiptapMarkSpec, TiptapMemoryDraftStore, TiptapMemoryVersionStore, TiptapNodeName,
+    TiptapNodeSpec, TiptapNodeView, TiptapNodeViewContent, TiptapNodeViewProps, TiptapParseOptions,
+    TiptapPasteEvent, TiptapPasteRule, TiptapPositionOrRange, TiptapRange, TiptapRemoteCursor,
+    TiptapSchemaTarget, TiptapSearchMatches, TiptapSearchOptions, TiptapSelectionRange,
+    TiptapSelectionState, TiptapSetContentOptions, TiptapShortcuts, TiptapSplitBlockOptions,
+    TiptapTextAlign, TiptapTextChange, TiptapToggleListOptions, TiptapTrackedChange,
+    TiptapTrackedChangeKind, TiptapTransaction, TiptapTransactionOrigin, TiptapTransferAction,
+    TiptapTransferFile, TiptapUploadProgress, TiptapVersion, TiptapVersionDetails,
+    TiptapVersionHistory, TiptapVersionStore, TiptapWhitespaceMode, TiptapYoutubeVideoResource,
+    UseTiptapAutosaveReturn, UseTiptapEditorAttrs, UseTiptapEditorInput, UseTiptapEditorProps,
+    UseTiptapEditorReturn, use_tiptap_autosave, use_tiptap_autosave_with_options,
+    use_tiptap_editor,
 };
+#[cfg(feature = "collab")]
+pub use api::{TiptapCollabAuthority, TiptapCollabUpdate};
+pub use api::{TiptapCollabConfig, TiptapCollabSteps};
 #[cfg(feature = "component")]
 pub use leptos_classes;
 #[cfg(feature = "component")]
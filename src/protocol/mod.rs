@@ -2,17 +2,40 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "code_block")]
 use crate::TiptapCodeBlockAttributes;
+#[cfg(all(feature = "collab", not(feature = "ssr")))]
+use crate::TiptapCollabConfig;
+#[cfg(feature = "collab")]
+use crate::TiptapCollabSteps;
+#[cfg(all(feature = "collaboration", not(feature = "ssr")))]
+use crate::TiptapCollaborationConfig;
+#[cfg(not(feature = "ssr"))]
+use crate::TiptapCommentThread;
+#[cfg(not(feature = "ssr"))]
+use crate::TiptapDecorationClick;
+#[cfg(not(feature = "ssr"))]
+use crate::TiptapDocumentChangeFormat;
 #[cfg(feature = "highlight")]
 use crate::TiptapHighlightAttributes;
 #[cfg(not(feature = "ssr"))]
+use crate::TiptapSearchMatches;
+#[cfg(not(feature = "ssr"))]
+use crate::TiptapSelectionRange;
+#[cfg(not(feature = "ssr"))]
 use crate::TiptapSelectionState;
 #[cfg(feature = "text_align")]
 use crate::TiptapTextAlign;
+#[cfg(not(feature = "ssr"))]
+use crate::TiptapTrackedChange;
+#[cfg(not(feature = "ssr"))]
+use crate::TiptapTransaction;
 use crate::{
-    TiptapAttributes, TiptapContent, TiptapEditorError, TiptapFocusOptions, TiptapFocusTarget,
-    TiptapInsertContentOptions, TiptapMarkOptions, TiptapParseOptions, TiptapPositionOrRange,
-    TiptapRange, TiptapSetContentOptions, TiptapWhitespaceMode,
+    TiptapAttributes, TiptapContent, TiptapDecoration, TiptapDocumentChange, TiptapEditorError,
+    TiptapFocusOptions, TiptapFocusTarget, TiptapImageResource, TiptapInsertContentOptions,
+    TiptapMarkOptions, TiptapParseOptions, TiptapPositionOrRange, TiptapRange, TiptapRemoteCursor,
+    TiptapSearchOptions, TiptapSetContentOptions, TiptapWhitespaceMode,
 };
+#[cfg(not(feature = "ssr"))]
+use crate::{TiptapInputRule, TiptapMarkSpec, TiptapNodeSpec, TiptapPasteRule};
 
 #[cfg_attr(not(feature = "ssr"), derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -45,13 +68,56 @@ impl TryFrom<TiptapContent> for ContentPayload {
 }
 
 #[cfg(not(feature = "ssr"))]
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+// The flags enable independent event streams of the JS runtime.
+#[allow(clippy::struct_excessive_bools)]
 pub(crate) struct CreateRequest {
     pub(crate) id: String,
     pub(crate) content: ContentPayload,
     pub(crate) editable: bool,
     pub(crate) extensions: Vec<&'static str>,
     pub(crate) placeholder: Option<String>,
+    pub(crate) document_changes: Option<TiptapDocumentChangeFormat>,
+    #[cfg(feature = "collab")]
+    pub(crate) collab: Option<TiptapCollabConfig>,
+    #[cfg(feature = "collaboration")]
+    pub(crate) collaboration: Option<TiptapCollaborationConfig>,
+    pub(crate) local_selection: bool,
+    pub(crate) custom_extensions: Vec<CustomExtensionPayload>,
+    pub(crate) node_specs: Vec<TiptapNodeSpec>,
+    pub(crate) mark_specs: Vec<TiptapMarkSpec>,
+    pub(crate) node_views: Vec<String>,
+    pub(crate) shortcuts: Option<ShortcutsPayload>,
+    pub(crate) input_rules: Vec<TiptapInputRule>,
+    pub(crate) paste_rules: Vec<TiptapPasteRule>,
+    pub(crate) decoration_clicks: bool,
+    pub(crate) transfer_handlers: TransferHandlersPayload,
+    pub(crate) transactions: bool,
+}
+
+/// Whether pastes and drops are passed to Rust before Tiptap handles them.
+#[cfg(not(feature = "ssr"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub(crate) struct TransferHandlersPayload {
+    pub(crate) paste: bool,
+    pub(crate) drop: bool,
+}
+
+/// The key combinations bound through `TiptapShortcuts`, and the priority of their extension.
+#[cfg(not(feature = "ssr"))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct ShortcutsPayload {
+    pub(crate) priority: u32,
+    pub(crate) keys: Vec<String>,
+}
+
+/// A custom extension descriptor, handed to the JS runtime as the object the app created.
+#[cfg(not(feature = "ssr"))]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct CustomExtensionPayload {
+    pub(crate) name: String,
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    pub(crate) descriptor: wasm_bindgen::JsValue,
 }
 
 #[cfg(not(feature = "ssr"))]
@@ -59,6 +125,7 @@ pub(crate) struct CreateRequest {
 pub(crate) struct ReadyPayload {
     pub(crate) generation: u32,
     pub(crate) selection_state: TiptapSelectionState,
+    pub(crate) document_snapshot: Option<TiptapDocumentChange>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -428,6 +495,8 @@ pub(crate) enum EditorCommand {
     Undo,
     #[cfg(feature = "history")]
     Redo,
+    #[cfg(feature = "history")]
+    ClearHistory,
     #[cfg(feature = "image")]
     SetImage {
         src: String,
@@ -450,6 +519,35 @@ pub(crate) enum EditorCommand {
     },
     #[cfg(feature = "link")]
     UnsetLink,
+    #[cfg(feature = "track_changes")]
+    SetTrackChanges {
+        author: Option<String>,
+    },
+    #[cfg(feature = "track_changes")]
+    AcceptChange {
+        change_id: String,
+    },
+    #[cfg(feature = "track_changes")]
+    RejectChange {
+        change_id: String,
+    },
+    #[cfg(feature = "track_changes")]
+    AcceptAllChanges,
+    #[cfg(feature = "track_changes")]
+    RejectAllChanges,
+    #[cfg(feature = "comments")]
+    AddComment {
+        thread_id: String,
+    },
+    #[cfg(feature = "comments")]
+    RemoveComment {
+        thread_id: String,
+    },
+    #[cfg(feature = "comments")]
+    SetCommentResolved {
+        thread_id: String,
+        resolved: bool,
+    },
     #[cfg(feature = "youtube")]
     SetYoutubeVideo {
         src: String,
@@ -460,6 +558,100 @@ pub(crate) enum EditorCommand {
     SetEditable {
         editable: bool,
     },
+    SetRemoteCursors {
+        cursors: Vec<TiptapRemoteCursor>,
+    },
+    SetDecorations {
+        layer_id: String,
+        decorations: Vec<TiptapDecoration>,
+    },
+    SetSelection {
+        anchor: u32,
+        head: u32,
+    },
+    ShowDiff {
+        document: serde_json::Value,
+        highlights: Vec<DiffHighlight>,
+    },
+    HideDiff,
+    ClearSearch,
+    SelectNextMatch,
+    SelectPreviousMatch,
+    ReplaceCurrentMatch {
+        replacement: String,
+    },
+    ReplaceAllMatches {
+        replacement: String,
+    },
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    StartImageUpload {
+        upload_id: u32,
+        position: Option<u32>,
+    },
+    SetImageUploadProgress {
+        upload_id: u32,
+        progress: f64,
+    },
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    FinishImageUpload {
+        upload_id: u32,
+        image: TiptapImageResource,
+    },
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    CancelImageUpload {
+        upload_id: u32,
+    },
+    SpecCommand {
+        action: SpecCommandAction,
+        name: String,
+        attributes: Option<TiptapAttributes>,
+    },
+    UpdateNodeViewAttributes {
+        view_id: u32,
+        attributes: TiptapAttributes,
+    },
+}
+
+/// A change shown by [`EditorCommand::ShowDiff`]. Blocks are top-level nodes by index, and text
+/// offsets count UTF-16 code units of the text nodes within a block.
+#[cfg_attr(not(feature = "ssr"), derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(not(feature = "ssr"), serde(tag = "kind", rename_all = "snake_case"))]
+pub(crate) enum DiffHighlight {
+    Block {
+        block: u32,
+        change: DiffChange,
+    },
+    Text {
+        block: u32,
+        from: u32,
+        to: u32,
+        change: DiffChange,
+    },
+    DeletedText {
+        block: u32,
+        offset: u32,
+        text: String,
+    },
+}
+
+#[cfg_attr(not(feature = "ssr"), derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(feature = "ssr"), serde(rename_all = "snake_case"))]
+pub(crate) enum DiffChange {
+    Inserted,
+    Deleted,
+    Changed,
+}
+
+/// The generated command of a node or mark spec: `<action>_<name>`.
+#[cfg_attr(not(feature = "ssr"), derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(feature = "ssr"), serde(rename_all = "snake_case"))]
+pub(crate) enum SpecCommandAction {
+    Set,
+    Toggle,
+    Unset,
 }
 
 impl EditorCommand {
@@ -526,12 +718,29 @@ impl EditorCommand {
             Self::WrapIn { .. } => Some("wrap_in"),
             Self::WrapInList { .. } => Some("wrap_in_list"),
             Self::SetEditable { .. } => Some("set_editable"),
+            Self::SetRemoteCursors { .. } => Some("set_remote_cursors"),
+            Self::SetDecorations { .. } => Some("set_decorations"),
+            Self::SetSelection { .. } => Some("set_selection"),
+            Self::ShowDiff { .. } => Some("show_diff"),
+            Self::HideDiff => Some("hide_diff"),
+            Self::ClearSearch => Some("clear_search"),
+            Self::SelectNextMatch => Some("select_next_match"),
+            Self::SelectPreviousMatch => Some("select_previous_match"),
+            Self::ReplaceCurrentMatch { .. } => Some("replace_current_match"),
+            Self::ReplaceAllMatches { .. } => Some("replace_all_matches"),
+            Self::StartImageUpload { .. } => Some("start_image_upload"),
+            Self::SetImageUploadProgress { .. } => Some("set_image_upload_progress"),
+            Self::FinishImageUpload { .. } => Some("finish_image_upload"),
+            Self::CancelImageUpload { .. } => Some("cancel_image_upload"),
+            Self::SpecCommand { .. } => Some("spec_command"),
+            Self::UpdateNodeViewAttributes { .. } => Some("update_node_view_attributes"),
             #[allow(unreachable_patterns)]
             _ => None,
         }
     }
 
     #[cfg(not(feature = "ssr"))]
+    #[allow(clippy::too_many_lines)]
     fn extension_operation_name(&self) -> Option<&'static str> {
         match self {
             #[cfg(feature = "blockquote")]
@@ -604,6 +813,8 @@ impl EditorCommand {
             Self::Undo => Some("undo"),
             #[cfg(feature = "history")]
             Self::Redo => Some("redo"),
+            #[cfg(feature = "history")]
+            Self::ClearHistory => Some("clear_history"),
             #[cfg(feature = "image")]
             Self::SetImage { .. } => Some("set_image"),
             #[cfg(feature = "link")]
@@ -614,6 +825,22 @@ impl EditorCommand {
             Self::UnsetLink => Some("unset_link"),
             #[cfg(feature = "youtube")]
             Self::SetYoutubeVideo { .. } => Some("set_youtube_video"),
+            #[cfg(feature = "track_changes")]
+            Self::SetTrackChanges { .. } => Some("set_track_changes"),
+            #[cfg(feature = "track_changes")]
+            Self::AcceptChange { .. } => Some("accept_change"),
+            #[cfg(feature = "track_changes")]
+            Self::RejectChange { .. } => Some("reject_change"),
+            #[cfg(feature = "track_changes")]
+            Self::AcceptAllChanges => Some("accept_all_changes"),
+            #[cfg(feature = "track_changes")]
+            Self::RejectAllChanges => Some("reject_all_changes"),
+            #[cfg(feature = "comments")]
+            Self::AddComment { .. } => Some("add_comment"),
+            #[cfg(feature = "comments")]
+            Self::RemoveComment { .. } => Some("remove_comment"),
+            #[cfg(feature = "comments")]
+            Self::SetCommentResolved { .. } => Some("set_comment_resolved"),
             #[allow(unreachable_patterns)]
             _ => None,
         }
@@ -627,10 +854,37 @@ pub(crate) enum DocumentRequest {
     GetContent {
         format: ContentFormat,
     },
+    GetText,
     SetContent {
         content: ContentPayload,
         options: Option<SetContentOptionsPayload>,
     },
+    GetDocumentSnapshot,
+    #[cfg(feature = "collab")]
+    GetCollabState,
+    #[cfg(feature = "collab")]
+    ReceiveTransaction {
+        steps: Vec<serde_json::Value>,
+        client_ids: Vec<String>,
+    },
+    #[cfg(feature = "collaboration")]
+    ApplyCollaborationUpdate {
+        update: Vec<u8>,
+    },
+    #[cfg(feature = "collaboration")]
+    GetCollaborationStateVector,
+    #[cfg(feature = "collaboration")]
+    EncodeCollaborationState {
+        state_vector: Option<Vec<u8>>,
+    },
+    CustomCommand {
+        name: String,
+        payload: serde_json::Value,
+    },
+    Find {
+        query: String,
+        options: TiptapSearchOptions,
+    },
 }
 
 impl DocumentRequest {
@@ -643,7 +897,21 @@ impl DocumentRequest {
             Self::GetContent {
                 format: ContentFormat::Json,
             } => "get_content_json",
+            Self::GetText => "get_text",
             Self::SetContent { .. } => "set_content",
+            Self::GetDocumentSnapshot => "get_document_snapshot",
+            #[cfg(feature = "collab")]
+            Self::GetCollabState => "get_collab_state",
+            #[cfg(feature = "collab")]
+            Self::ReceiveTransaction { .. } => "receive_transaction",
+            #[cfg(feature = "collaboration")]
+            Self::ApplyCollaborationUpdate { .. } => "apply_collaboration_update",
+            #[cfg(feature = "collaboration")]
+            Self::GetCollaborationStateVector => "get_collaboration_state_vector",
+            #[cfg(feature = "collaboration")]
+            Self::EncodeCollaborationState { .. } => "encode_collaboration_state",
+            Self::CustomCommand { .. } => "custom_command",
+            Self::Find { .. } => "find",
         }
     }
 }
@@ -653,10 +921,143 @@ impl DocumentRequest {
 #[cfg_attr(feature = "ssr", allow(dead_code))]
 #[cfg_attr(not(feature = "ssr"), serde(tag = "kind", rename_all = "snake_case"))]
 pub(crate) enum DocumentResponse {
-    Content { content: ContentPayload },
+    Content {
+        content: ContentPayload,
+    },
+    Text {
+        text: String,
+    },
+    DocumentSnapshot {
+        change: TiptapDocumentChange,
+    },
+    #[cfg(feature = "collab")]
+    CollabState {
+        version: u64,
+        sendable: Option<TiptapCollabSteps>,
+    },
+    #[cfg(feature = "collaboration")]
+    CollaborationStateVector {
+        state_vector: Vec<u8>,
+    },
+    #[cfg(feature = "collaboration")]
+    CollaborationUpdate {
+        update: Vec<u8>,
+    },
+    CustomCommand {
+        value: serde_json::Value,
+    },
+    SearchMatches {
+        matches: Vec<TiptapRange>,
+    },
     Empty,
 }
 
+/// Notifications pushed from the JS bridge outside of a request/response round trip.
+#[cfg(not(feature = "ssr"))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum EditorEvent {
+    DocumentChange {
+        change: TiptapDocumentChange,
+    },
+    #[cfg(feature = "collab")]
+    CollabSendable {
+        steps: TiptapCollabSteps,
+    },
+    #[cfg(feature = "collaboration")]
+    CollaborationUpdate {
+        update: Vec<u8>,
+    },
+    LocalSelection {
+        selection: TiptapSelectionRange,
+    },
+    DecorationClick {
+        click: TiptapDecorationClick,
+    },
+    SearchMatches {
+        matches: TiptapSearchMatches,
+    },
+    FocusChange {
+        focused: bool,
+    },
+    Transaction {
+        transaction: TiptapTransaction,
+    },
+    HistoryChange {
+        undo_depth: u32,
+        redo_depth: u32,
+    },
+    TrackedChanges {
+        changes: Vec<TiptapTrackedChange>,
+    },
+    Comments {
+        threads: Vec<TiptapCommentThread>,
+    },
+    ActiveComments {
+        thread_ids: Vec<String>,
+    },
+}
+
+/// Events handled while JS dispatches the browser event, answered with whether Rust handled them.
+#[cfg(not(feature = "ssr"))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum HandledEvent {
+    Shortcut {
+        keys: String,
+    },
+    Paste {
+        transfer: TransferPayload,
+    },
+    Drop {
+        transfer: TransferPayload,
+        position: Option<u32>,
+        moved: bool,
+    },
+}
+
+/// The content of a paste or a drop. Files are passed through as browser objects.
+#[cfg(not(feature = "ssr"))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(crate) struct TransferPayload {
+    pub(crate) html: Option<String>,
+    pub(crate) text: Option<String>,
+    pub(crate) files: Vec<TransferFilePayload>,
+}
+
+#[cfg(not(feature = "ssr"))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(crate) struct TransferFilePayload {
+    pub(crate) name: String,
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    pub(crate) file: leptos::web_sys::Blob,
+}
+
+/// Lifecycle of the node views rendered by Leptos components. The elements of mounted views are
+/// passed next to the event, as they can not be deserialized.
+#[cfg(not(feature = "ssr"))]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum NodeViewEvent {
+    Mount {
+        view_id: u32,
+        name: String,
+        attributes: TiptapAttributes,
+        selected: bool,
+    },
+    Update {
+        view_id: u32,
+        attributes: TiptapAttributes,
+    },
+    Select {
+        view_id: u32,
+        selected: bool,
+    },
+    Destroy {
+        view_id: u32,
+    },
+}
+
 #[cfg(not(feature = "ssr"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
@@ -670,6 +1071,7 @@ pub(crate) struct CommandInvocation {
     pub(crate) id: String,
     pub(crate) generation: u32,
     pub(crate) command: EditorCommand,
+    pub(crate) focus: bool,
 }
 
 #[cfg(not(feature = "ssr"))]
@@ -798,5 +1200,429 @@ mod tests {
         assert_that!(EditorCommand::Undo.operation_name()).is_equal_to("undo");
         #[cfg(feature = "history")]
         assert_that!(EditorCommand::Redo.operation_name()).is_equal_to("redo");
+        #[cfg(feature = "history")]
+        assert_that!(EditorCommand::ClearHistory.operation_name()).is_equal_to("clear_history");
+        #[cfg(feature = "track_changes")]
+        assert_that!(
+            EditorCommand::AcceptChange {
+                change_id: "c1".to_owned(),
+            }
+            .operation_name()
+        )
+        .is_equal_to("accept_change");
+        #[cfg(feature = "comments")]
+        assert_that!(
+            EditorCommand::SetCommentResolved {
+                thread_id: "t1".to_owned(),
+                resolved: true,
+            }
+            .operation_name()
+        )
+        .is_equal_to("set_comment_resolved");
+    }
+
+    #[cfg(all(feature = "collaboration", not(feature = "ssr")))]
+    #[test]
+    fn serializes_collaboration_bytes_as_number_arrays() {
+        assert_that!(
+            serde_json::to_value(DocumentRequest::EncodeCollaborationState {
+                state_vector: Some(vec![1, 0, 255]),
+            })
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "kind": "encode_collaboration_state",
+            "state_vector": [1, 0, 255],
+        }));
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "collaboration_update",
+                "update": [7, 8],
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::CollaborationUpdate { update: vec![7, 8] });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn serializes_remote_cursors_and_local_selections() {
+        assert_that!(
+            serde_json::to_value(EditorCommand::SetRemoteCursors {
+                cursors: vec![TiptapRemoteCursor {
+                    user: crate::TiptapCollaborationUser {
+                        id: "bob".to_owned(),
+                        name: "Bob".to_owned(),
+                        color: "#ff0000".to_owned(),
+                    },
+                    selection: TiptapSelectionRange { anchor: 4, head: 2 },
+                }],
+            })
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "kind": "set_remote_cursors",
+            "cursors": [{
+                "user": {"id": "bob", "name": "Bob", "color": "#ff0000"},
+                "selection": {"anchor": 4, "head": 2},
+            }],
+        }));
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "local_selection",
+                "selection": {"anchor": 3, "head": 3},
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::LocalSelection {
+            selection: TiptapSelectionRange::caret(3),
+        });
+        assert_that!(
+            serde_json::to_value(EditorCommand::SetSelection { anchor: 4, head: 2 }).unwrap()
+        )
+        .is_equal_to(json!({"kind": "set_selection", "anchor": 4, "head": 2}));
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn serializes_decoration_layers_and_clicks() {
+        assert_that!(
+            serde_json::to_value(EditorCommand::SetDecorations {
+                layer_id: "spelling".to_owned(),
+                decorations: vec![TiptapDecoration::node("figure", 7)],
+            })
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "kind": "set_decorations",
+            "layer_id": "spelling",
+            "decorations": [{
+                "id": "figure",
+                "target": {"kind": "node", "position": 7},
+                "class": null,
+                "style": null,
+                "attributes": {},
+            }],
+        }));
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "decoration_click",
+                "click": {"layer_id": "spelling", "decoration_id": "typo"},
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::DecorationClick {
+            click: TiptapDecorationClick {
+                layer_id: "spelling".to_owned(),
+                decoration_id: "typo".to_owned(),
+            },
+        });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn serializes_diff_highlights() {
+        assert_that!(
+            serde_json::to_value(EditorCommand::ShowDiff {
+                document: json!({"type": "doc", "content": []}),
+                highlights: vec![
+                    DiffHighlight::Block {
+                        block: 0,
+                        change: DiffChange::Deleted,
+                    },
+                    DiffHighlight::Text {
+                        block: 1,
+                        from: 2,
+                        to: 5,
+                        change: DiffChange::Inserted,
+                    },
+                    DiffHighlight::DeletedText {
+                        block: 1,
+                        offset: 5,
+                        text: "old".to_owned(),
+                    },
+                ],
+            })
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "kind": "show_diff",
+            "document": {"type": "doc", "content": []},
+            "highlights": [
+                {"kind": "block", "block": 0, "change": "deleted"},
+                {"kind": "text", "block": 1, "from": 2, "to": 5, "change": "inserted"},
+                {"kind": "deleted_text", "block": 1, "offset": 5, "text": "old"},
+            ],
+        }));
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn deserializes_focus_changes() {
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({"kind": "focus_change", "focused": true}))
+                .unwrap()
+        )
+        .is_equal_to(EditorEvent::FocusChange { focused: true });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn deserializes_history_changes() {
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "history_change",
+                "undo_depth": 3,
+                "redo_depth": 1,
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::HistoryChange {
+            undo_depth: 3,
+            redo_depth: 1,
+        });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn deserializes_tracked_changes() {
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "tracked_changes",
+                "changes": [{
+                    "id": "c1",
+                    "kind": "deletion",
+                    "author": "Ada",
+                    "timestamp": 1_760_000_000_000_u64,
+                    "range": {"from": 3, "to": 8},
+                    "text": "draft",
+                }],
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::TrackedChanges {
+            changes: vec![TiptapTrackedChange {
+                id: "c1".to_owned(),
+                kind: crate::TiptapTrackedChangeKind::Deletion,
+                author: "Ada".to_owned(),
+                timestamp: 1_760_000_000_000,
+                range: TiptapRange { from: 3, to: 8 },
+                text: "draft".to_owned(),
+            }],
+        });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn deserializes_comment_threads() {
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "comments",
+                "threads": [{
+                    "thread_id": "t1",
+                    "ranges": [{"from": 1, "to": 4}, {"from": 9, "to": 12}],
+                    "resolved": false,
+                }],
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::Comments {
+            threads: vec![TiptapCommentThread {
+                thread_id: "t1".to_owned(),
+                ranges: vec![
+                    TiptapRange { from: 1, to: 4 },
+                    TiptapRange { from: 9, to: 12 },
+                ],
+                resolved: false,
+            }],
+        });
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "active_comments",
+                "thread_ids": ["t1"],
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::ActiveComments {
+            thread_ids: vec!["t1".to_owned()],
+        });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn deserializes_transactions() {
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "transaction",
+                "transaction": {
+                    "doc_changed": true,
+                    "selection": {"anchor": 2, "head": 4},
+                    "meta": {"source": "sync"},
+                    "add_to_history": false,
+                    "origin": "input_rule",
+                },
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::Transaction {
+            transaction: TiptapTransaction {
+                doc_changed: true,
+                selection: Some(TiptapSelectionRange { anchor: 2, head: 4 }),
+                meta: [("source".to_owned(), json!("sync"))].into_iter().collect(),
+                add_to_history: false,
+                origin: crate::TiptapTransactionOrigin::InputRule,
+            },
+        });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn serializes_search_requests_and_matches() {
+        assert_that!(
+            serde_json::to_value(DocumentRequest::Find {
+                query: "colou?r".to_owned(),
+                options: TiptapSearchOptions {
+                    regex: true,
+                    ..TiptapSearchOptions::default()
+                },
+            })
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "kind": "find",
+            "query": "colou?r",
+            "options": {"case_sensitive": false, "whole_word": false, "regex": true},
+        }));
+        assert_that!(
+            serde_json::from_value::<DocumentResponse>(json!({
+                "kind": "search_matches",
+                "matches": [{"from": 1, "to": 6}],
+            }))
+            .unwrap()
+        )
+        .is_equal_to(DocumentResponse::SearchMatches {
+            matches: vec![TiptapRange { from: 1, to: 6 }],
+        });
+        assert_that!(
+            serde_json::from_value::<EditorEvent>(json!({
+                "kind": "search_matches",
+                "matches": {"count": 3, "current": null},
+            }))
+            .unwrap()
+        )
+        .is_equal_to(EditorEvent::SearchMatches {
+            matches: TiptapSearchMatches {
+                count: 3,
+                current: None,
+            },
+        });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn serializes_image_upload_commands() {
+        assert_that!(
+            serde_json::to_value(EditorCommand::StartImageUpload {
+                upload_id: 7,
+                position: None,
+            })
+            .unwrap()
+        )
+        .is_equal_to(json!({"kind": "start_image_upload", "upload_id": 7, "position": null}));
+        assert_that!(
+            serde_json::to_value(EditorCommand::FinishImageUpload {
+                upload_id: 7,
+                image: TiptapImageResource {
+                    src: "https://example.com/cat.png".to_owned(),
+                    alt: Some("A cat".to_owned()),
+                    title: None,
+                },
+            })
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "kind": "finish_image_upload",
+            "upload_id": 7,
+            "image": {"src": "https://example.com/cat.png", "alt": "A cat", "title": null},
+        }));
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn serializes_spec_commands_with_their_action() {
+        assert_that!(
+            serde_json::to_value(EditorCommand::SpecCommand {
+                action: SpecCommandAction::Toggle,
+                name: "callout".to_owned(),
+                attributes: Some([("kind", "warning")].into_iter().collect()),
+            })
+            .unwrap()
+        )
+        .is_equal_to(json!({
+            "kind": "spec_command",
+            "action": "toggle",
+            "name": "callout",
+            "attributes": {"kind": "warning"},
+        }));
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn deserializes_paste_and_drop_events() {
+        assert_that!(
+            serde_json::from_value::<HandledEvent>(json!({
+                "kind": "paste",
+                "transfer": {"html": "<p>Pasted</p>", "text": "Pasted", "files": []},
+            }))
+            .unwrap()
+        )
+        .is_equal_to(HandledEvent::Paste {
+            transfer: TransferPayload {
+                html: Some("<p>Pasted</p>".to_owned()),
+                text: Some("Pasted".to_owned()),
+                files: Vec::new(),
+            },
+        });
+        assert_that!(
+            serde_json::from_value::<HandledEvent>(json!({
+                "kind": "drop",
+                "transfer": {"html": null, "text": "Dropped", "files": []},
+                "position": 12,
+                "moved": false,
+            }))
+            .unwrap()
+        )
+        .is_equal_to(HandledEvent::Drop {
+            transfer: TransferPayload {
+                html: None,
+                text: Some("Dropped".to_owned()),
+                files: Vec::new(),
+            },
+            position: Some(12),
+            moved: false,
+        });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    #[test]
+    fn deserializes_node_view_events() {
+        let mount: NodeViewEvent = serde_json::from_value(json!({
+            "kind": "mount",
+            "view_id": 3,
+            "name": "poll",
+            "attributes": {"votes": 2},
+            "selected": false,
+        }))
+        .unwrap();
+        let destroy: NodeViewEvent =
+            serde_json::from_value(json!({"kind": "destroy", "view_id": 3})).unwrap();
+
+        assert_that!(mount).is_equal_to(NodeViewEvent::Mount {
+            view_id: 3,
+            name: "poll".to_owned(),
+            attributes: [("votes", 2)].into_iter().collect(),
+            selected: false,
+        });
+        assert_that!(destroy).is_equal_to(NodeViewEvent::Destroy { view_id: 3 });
     }
 }
@@ -1,13 +1,23 @@
+#[cfg(all(feature = "collab", not(feature = "ssr")))]
+use crate::TiptapCollabConfig;
+#[cfg(all(feature = "collaboration", not(feature = "ssr")))]
+use crate::TiptapCollaborationConfig;
 use crate::TiptapEditorError;
 #[cfg(not(feature = "ssr"))]
-use crate::TiptapExtension;
-#[cfg(not(feature = "ssr"))]
 use crate::protocol::ContentPayload;
 #[cfg(not(feature = "ssr"))]
 use crate::protocol::EmptyResponse;
 #[cfg(not(feature = "ssr"))]
-use crate::protocol::{CommandInvocation, CreateRequest, DocumentInvocation, ReadyPayload};
+use crate::protocol::{
+    CommandInvocation, CreateRequest, CustomExtensionPayload, DocumentInvocation, ReadyPayload,
+    ShortcutsPayload, TransferHandlersPayload,
+};
 use crate::protocol::{DocumentRequest, DocumentResponse, EditorCommand};
+#[cfg(not(feature = "ssr"))]
+use crate::{
+    TiptapCustomExtension, TiptapDocumentChangeFormat, TiptapExtension, TiptapInputRule,
+    TiptapMarkSpec, TiptapNodeSpec, TiptapPasteRule,
+};
 use cfg_if::cfg_if;
 #[cfg(not(feature = "ssr"))]
 use serde::Deserialize;
@@ -144,15 +154,35 @@ fn format_js_value(value: &JsValue) -> String {
 pub(crate) struct CreateCallbacks<'a> {
     pub(crate) change: &'a ScopedClosure<'static, dyn Fn()>,
     pub(crate) selection: &'a ScopedClosure<'static, dyn Fn(JsValue)>,
+    pub(crate) event: &'a ScopedClosure<'static, dyn Fn(JsValue)>,
+    pub(crate) node_view: &'a ScopedClosure<'static, dyn Fn(JsValue, JsValue, JsValue)>,
+    pub(crate) handled: &'a ScopedClosure<'static, dyn Fn(JsValue) -> bool>,
 }
 
 #[cfg(not(feature = "ssr"))]
+#[allow(clippy::struct_excessive_bools)]
 pub(crate) struct CreateOptions {
     pub(crate) id: String,
     pub(crate) content: ContentPayload,
     pub(crate) editable: bool,
     pub(crate) extensions: Vec<TiptapExtension>,
     pub(crate) placeholder: Option<String>,
+    pub(crate) document_changes: Option<TiptapDocumentChangeFormat>,
+    #[cfg(feature = "collab")]
+    pub(crate) collab: Option<TiptapCollabConfig>,
+    #[cfg(feature = "collaboration")]
+    pub(crate) collaboration: Option<TiptapCollaborationConfig>,
+    pub(crate) local_selection: bool,
+    pub(crate) custom_extensions: Vec<TiptapCustomExtension>,
+    pub(crate) node_specs: Vec<TiptapNodeSpec>,
+    pub(crate) mark_specs: Vec<TiptapMarkSpec>,
+    pub(crate) node_views: Vec<String>,
+    pub(crate) shortcuts: Option<ShortcutsPayload>,
+    pub(crate) input_rules: Vec<TiptapInputRule>,
+    pub(crate) paste_rules: Vec<TiptapPasteRule>,
+    pub(crate) decoration_clicks: bool,
+    pub(crate) transfer_handlers: TransferHandlersPayload,
+    pub(crate) transactions: bool,
 }
 
 #[cfg(not(feature = "ssr"))]
@@ -172,15 +202,45 @@ pub(crate) fn create(
             .map(TiptapExtension::js_name)
             .collect(),
         placeholder: request.placeholder,
+        document_changes: request.document_changes,
+        #[cfg(feature = "collab")]
+        collab: request.collab,
+        #[cfg(feature = "collaboration")]
+        collaboration: request.collaboration,
+        local_selection: request.local_selection,
+        custom_extensions: request
+            .custom_extensions
+            .into_iter()
+            .map(|extension| CustomExtensionPayload {
+                name: extension.name().to_owned(),
+                descriptor: extension.descriptor(),
+            })
+            .collect(),
+        node_specs: request.node_specs,
+        mark_specs: request.mark_specs,
+        node_views: request.node_views,
+        shortcuts: request.shortcuts,
+        input_rules: request.input_rules,
+        paste_rules: request.paste_rules,
+        decoration_clicks: request.decoration_clicks,
+        transfer_handlers: request.transfer_handlers,
+        transactions: request.transactions,
     })?;
 
-    let response =
-        ffi::create(request, callbacks.change, callbacks.selection).map_err(|value| {
-            TiptapEditorError::BridgeError(format!(
-                "JS bridge create threw an exception: {}",
-                format_js_value(&value),
-            ))
-        })?;
+    let response = ffi::create(
+        request,
+        callbacks.change,
+        callbacks.selection,
+        callbacks.event,
+        callbacks.node_view,
+        callbacks.handled,
+    )
+    .map_err(|value| {
+        TiptapEditorError::BridgeError(format!(
+            "JS bridge create threw an exception: {}",
+            format_js_value(&value),
+        ))
+    })?;
 
     response_to_result(deserialize_response(response)?)
 }
@@ -198,6 +258,7 @@ pub(crate) fn command(
     id: String,
     generation: u32,
     command: EditorCommand,
+    focus: bool,
 ) -> Result<(), TiptapEditorError> {
     cfg_if! {if #[cfg(not(feature = "ssr"))] {
         let _operation = command.operation_name();
@@ -206,12 +267,13 @@ pub(crate) fn command(
                 id,
                 generation,
                 command,
+                focus,
             },
             ffi::command,
         )
         .map(|_| ())
     } else {
-        drop((id, generation, command));
+        drop((id, generation, command, focus));
         Ok(())
     }}
 }
@@ -10,7 +10,10 @@ use crate::protocol::{
     EditorCommand, EmptyResponse, ReadyPayload, SetContentOptionsPayload,
     TiptapParseOptionsPayload, TiptapWhitespaceModeKeyword, TiptapWhitespaceModePayload,
 };
-use crate::{TiptapActiveKey, TiptapAttributes, TiptapEditorError};
+use crate::{
+    TiptapActiveKey, TiptapAttributes, TiptapDocumentChange, TiptapDocumentChangeFormat,
+    TiptapDocumentChangePayload, TiptapEditorError,
+};
 
 fn property(value: &JsValue, name: &str) -> JsValue {
     Reflect::get(value, &JsValue::from_str(name)).expect("property access should succeed")
@@ -45,6 +48,23 @@ fn create_request_matches_typescript_shape_and_uses_plain_json_objects() {
         editable: true,
         extensions: vec!["document", "paragraph", "text"],
         placeholder: None,
+        document_changes: Some(TiptapDocumentChangeFormat::JsonPatch),
+        collab: None,
+        collaboration: None,
+        local_selection: false,
+        custom_extensions: Vec::new(),
+        node_specs: Vec::new(),
+        mark_specs: Vec::new(),
+        node_views: Vec::new(),
+        shortcuts: None,
+        input_rules: Vec::new(),
+        paste_rules: Vec::new(),
+        decoration_clicks: false,
+        transfer_handlers: TransferHandlersPayload {
+            paste: false,
+            drop: false,
+        },
+        transactions: false,
     };
 
     let request = serialize_request(&request).expect("create request should serialize");
@@ -52,6 +72,7 @@ fn create_request_matches_typescript_shape_and_uses_plain_json_objects() {
     assert_string_property(&request, "id", "json-editor");
     assert_that!(property(&request, "editable").as_bool()).is_equal_to(Some(true));
     assert_that!(property(&request, "placeholder").is_undefined()).is_true();
+    assert_string_property(&request, "document_changes", "json_patch");
 
     let extensions = property(&request, "extensions");
     assert_that!(Array::is_array(&extensions)).is_true();
@@ -139,6 +160,7 @@ fn command_requests_use_plain_objects_for_attributes_and_metadata() {
             type_or_name: "link".to_owned(),
             attributes: Some(attributes),
         },
+        focus: true,
     };
 
     let invocation = serialize_request(&set_mark).expect("set_mark request should serialize");
@@ -160,6 +182,7 @@ fn command_requests_use_plain_objects_for_attributes_and_metadata() {
             key: "bridge_abi".to_owned(),
             value: json!({"nested": {"count": 2}}),
         },
+        focus: false,
     };
     let invocation = serialize_request(&set_meta).expect("set_meta request should serialize");
     let command = property(&invocation, "command");
@@ -185,6 +208,29 @@ fn bridge_success_results_deserialize_ready_and_content_payloads() {
     let ready = response_to_result(ready).expect("ready result should be successful");
     assert_that!(ready.generation).is_equal_to(42);
     assert_that!(ready.selection_state.is_active(TiptapActiveKey::Bold)).is_true();
+    assert_that!(ready.document_snapshot).is_none();
+
+    let ready_with_snapshot_response = parsed_json(json!({
+        "ok": true,
+        "value": {
+            "generation": 43,
+            "selection_state": {"active": {}},
+            "document_snapshot": {
+                "version": 0,
+                "payload": {"format": "snapshot", "document": {"type": "doc"}}
+            }
+        }
+    }));
+    let ready: JsInteropResponse<ReadyPayload> = deserialize_response(ready_with_snapshot_response)
+        .expect("ready result with snapshot should deserialize");
+    assert_that!(response_to_result(ready).unwrap().document_snapshot).is_equal_to(Some(
+        TiptapDocumentChange {
+            version: 0,
+            payload: TiptapDocumentChangePayload::Snapshot {
+                document: json!({"type": "doc"}),
+            },
+        },
+    ));
 
     let content_response = parsed_json(json!({
         "ok": true,
@@ -0,0 +1,28 @@
+use cfg_if::cfg_if;
+
+#[cfg(target_arch = "wasm32")]
+mod js {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = Date)]
+        pub fn now() -> f64;
+    }
+}
+
+/// Returns the current time in milliseconds since the Unix epoch.
+pub(crate) fn now_millis() -> u64 {
+    cfg_if! {if #[cfg(target_arch = "wasm32")] {
+        // `SystemTime` is not available in the browser.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let now = js::now() as u64;
+        now
+    } else {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| {
+                u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+            })
+    }}
+}
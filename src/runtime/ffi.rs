@@ -12,6 +12,9 @@ mod js {
             request: JsValue,
             on_change: &ScopedClosure<'static, dyn Fn()>,
             on_selection: &ScopedClosure<'static, dyn Fn(JsValue)>,
+            on_event: &ScopedClosure<'static, dyn Fn(JsValue)>,
+            on_node_view: &ScopedClosure<'static, dyn Fn(JsValue, JsValue, JsValue)>,
+            on_handled: &ScopedClosure<'static, dyn Fn(JsValue) -> bool>,
         ) -> Result<JsValue, JsValue>;
         pub fn destroy(id: String);
         pub fn command(request: JsValue) -> JsValue;
@@ -185,11 +188,41 @@ mod js {
         #[wasm_bindgen(catch)]
         pub fn register_youtube() -> Result<(), JsValue>;
     }
+
+    #[cfg(feature = "collaboration")]
+    #[wasm_bindgen(module = "/src/js/generated/tiptap_collaboration.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub fn register_collaboration() -> Result<(), JsValue>;
+    }
+
+    #[cfg(feature = "track_changes")]
+    #[wasm_bindgen(module = "/src/js/generated/tiptap_track_changes.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub fn register_track_changes() -> Result<(), JsValue>;
+    }
+
+    #[cfg(feature = "comments")]
+    #[wasm_bindgen(module = "/src/js/generated/tiptap_comments.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub fn register_comments() -> Result<(), JsValue>;
+    }
+
+    #[cfg(feature = "collab")]
+    #[wasm_bindgen(module = "/src/js/generated/collab_module.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub fn install_collab_module() -> Result<(), JsValue>;
+    }
 }
 
 #[cfg(not(feature = "ssr"))]
 pub(crate) use js::{command, create, destroy, document, init_bridge_runtime};
 
+#[cfg(all(not(feature = "ssr"), feature = "collab"))]
+pub(crate) use js::install_collab_module;
 #[cfg(all(not(feature = "ssr"), feature = "blockquote"))]
 pub(crate) use js::register_blockquote;
 #[cfg(all(not(feature = "ssr"), feature = "bold"))]
@@ -200,6 +233,10 @@ pub(crate) use js::register_bullet_list;
 pub(crate) use js::register_code;
 #[cfg(all(not(feature = "ssr"), feature = "code_block"))]
 pub(crate) use js::register_code_block;
+#[cfg(all(not(feature = "ssr"), feature = "collaboration"))]
+pub(crate) use js::register_collaboration;
+#[cfg(all(not(feature = "ssr"), feature = "comments"))]
+pub(crate) use js::register_comments;
 #[cfg(all(not(feature = "ssr"), feature = "document"))]
 pub(crate) use js::register_document;
 #[cfg(all(not(feature = "ssr"), feature = "dropcursor"))]
@@ -236,5 +273,7 @@ pub(crate) use js::register_strike;
 pub(crate) use js::register_text;
 #[cfg(all(not(feature = "ssr"), feature = "text_align"))]
 pub(crate) use js::register_text_align;
+#[cfg(all(not(feature = "ssr"), feature = "track_changes"))]
+pub(crate) use js::register_track_changes;
 #[cfg(all(not(feature = "ssr"), feature = "youtube"))]
 pub(crate) use js::register_youtube;
@@ -1,9 +1,17 @@
 mod bridge;
+mod clock;
 mod ffi;
+#[cfg(not(feature = "ssr"))]
+mod node_views;
 mod registration;
 mod session;
+mod storage;
 
 #[cfg(not(feature = "ssr"))]
 pub(crate) use bridge::{CreateCallbacks, CreateOptions, create};
 pub(crate) use bridge::{command, destroy, document};
+pub(crate) use clock::now_millis;
 pub(crate) use session::{TiptapRuntimeMountOptions, TiptapRuntimeSession};
+pub(crate) use storage::{
+    IndexedDbDrafts, local_storage_get, local_storage_remove, local_storage_set,
+};
@@ -0,0 +1,178 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use leptos::mount::{UnmountHandle, mount_to_renderer};
+use leptos::prelude::*;
+use leptos::tachys::view::any_view::AnyViewState;
+use leptos::web_sys::Element;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::{JsCast, JsValue};
+
+use super::session::report_runtime_error;
+use crate::api::TiptapNodeViewContentElement;
+use crate::protocol::NodeViewEvent;
+use crate::{
+    TiptapAttributes, TiptapEditorError, TiptapEditorHandle, TiptapEditorReport, TiptapNodeView,
+    TiptapNodeViewProps,
+};
+
+struct MountedNodeView {
+    attributes: RwSignal<TiptapAttributes>,
+    selected: RwSignal<bool>,
+    // Dropping the handle unmounts the view and disposes its reactive owner.
+    _mounted: UnmountHandle<AnyViewState>,
+}
+
+/// The Leptos components rendering the node views of one editor, and the views currently mounted.
+pub(crate) struct TiptapNodeViewRegistry {
+    views: Vec<TiptapNodeView>,
+    owner: Option<Owner>,
+    editor: TiptapEditorHandle,
+    on_error: Option<Callback<TiptapEditorReport>>,
+    mounted: RefCell<HashMap<u32, MountedNodeView>>,
+}
+
+impl TiptapNodeViewRegistry {
+    pub(crate) fn new(
+        views: Vec<TiptapNodeView>,
+        owner: Option<Owner>,
+        editor: TiptapEditorHandle,
+        on_error: Option<Callback<TiptapEditorReport>>,
+    ) -> Self {
+        Self {
+            views,
+            owner,
+            editor,
+            on_error,
+            mounted: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn handle_event(&self, event: JsValue, dom: JsValue, content_dom: JsValue) {
+        let result = serde_wasm_bindgen::from_value(event)
+            .map_err(|err| {
+                TiptapEditorError::InvalidBridgePayload(format!(
+                    "could not parse node view event from JS: {err}"
+                ))
+            })
+            .and_then(|event| self.apply(event, dom, content_dom));
+        if let Err(err) = result {
+            report_runtime_error(self.on_error, err);
+        }
+    }
+
+    fn apply(
+        &self,
+        event: NodeViewEvent,
+        dom: JsValue,
+        content_dom: JsValue,
+    ) -> Result<(), TiptapEditorError> {
+        match event {
+            NodeViewEvent::Mount {
+                view_id,
+                name,
+                attributes,
+                selected,
+            } => {
+                let dom = dom.dyn_into::<Element>().map_err(|_| {
+                    TiptapEditorError::InvalidBridgePayload(format!(
+                        "node view '{name}' was mounted without an element"
+                    ))
+                })?;
+                let content = content_dom.dyn_into::<Element>().ok();
+                let mounted = self.mount(view_id, &name, attributes, selected, &dom, content)?;
+                // Views are mounted without holding the borrow, as components may call back into
+                // the editor while rendering.
+                self.mounted.borrow_mut().insert(view_id, mounted);
+            }
+            NodeViewEvent::Update {
+                view_id,
+                attributes,
+            } => {
+                let view = self
+                    .mounted
+                    .borrow()
+                    .get(&view_id)
+                    .map(|view| view.attributes);
+                view.inspect(|view| view.set(attributes));
+            }
+            NodeViewEvent::Select { view_id, selected } => {
+                let view = self
+                    .mounted
+                    .borrow()
+                    .get(&view_id)
+                    .map(|view| view.selected);
+                view.inspect(|view| view.set(selected));
+            }
+            NodeViewEvent::Destroy { view_id } => {
+                let view = self.mounted.borrow_mut().remove(&view_id);
+                drop(view);
+            }
+        }
+        Ok(())
+    }
+
+    fn mount(
+        &self,
+        view_id: u32,
+        name: &str,
+        attributes: TiptapAttributes,
+        selected: bool,
+        dom: &Element,
+        content: Option<Element>,
+    ) -> Result<MountedNodeView, TiptapEditorError> {
+        let view = self
+            .views
+            .iter()
+            .find(|view| view.name() == name)
+            .cloned()
+            .ok_or_else(|| {
+                TiptapEditorError::BridgeError(format!(
+                    "received a node view for '{name}', which no component renders"
+                ))
+            })?;
+
+        let editor = self.editor;
+        let on_error = self.on_error;
+        let signals = Rc::new(Cell::new(None));
+        let render = {
+            let signals = Rc::clone(&signals);
+            move || {
+                let attributes = RwSignal::new(attributes);
+                let selected = RwSignal::new(selected);
+                signals.set(Some((attributes, selected)));
+                if let Some(content) = content {
+                    provide_context(TiptapNodeViewContentElement(SendWrapper::new(content)));
+                }
+
+                view.render(TiptapNodeViewProps {
+                    attributes: attributes.into(),
+                    selected: selected.into(),
+                    update_attributes: Callback::new(move |attributes| {
+                        if let Err(err) = editor.update_node_view_attributes(view_id, attributes) {
+                            tracing::error!(?err, "Could not update the TipTap node view.");
+                            on_error.inspect(|cb| cb.run(err));
+                        }
+                    }),
+                })
+            }
+        };
+
+        // The owner of the editor is the parent of the view, so contexts provided above the
+        // editor reach the component.
+        let mounted = match &self.owner {
+            Some(owner) => owner.with(|| mount_to_renderer(dom, render)),
+            None => mount_to_renderer(dom, render),
+        };
+        let (attributes, selected) = signals
+            .take()
+            .expect("node view signals are created while mounting");
+
+        Ok(MountedNodeView {
+            attributes,
+            selected,
+            _mounted: mounted,
+        })
+    }
+}
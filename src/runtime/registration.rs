@@ -49,6 +49,10 @@ fn ensure_runtime_initialized() -> Result<(), TiptapEditorError> {
 fn register_compiled_extensions() -> Result<(), TiptapEditorError> {
     ensure_runtime_initialized()?;
 
+    #[cfg(feature = "collab")]
+    ffi::install_collab_module()
+        .map_err(|value| map_registration_error("collab_module", &value))?;
+
     #[cfg(feature = "blockquote")]
     register_extension("blockquote", ffi::register_blockquote)?;
     #[cfg(feature = "bold")]
@@ -97,6 +101,12 @@ fn register_compiled_extensions() -> Result<(), TiptapEditorError> {
     register_extension("placeholder", ffi::register_placeholder)?;
     #[cfg(feature = "youtube")]
     register_extension("youtube", ffi::register_youtube)?;
+    #[cfg(feature = "collaboration")]
+    register_extension("collaboration", ffi::register_collaboration)?;
+    #[cfg(feature = "track_changes")]
+    register_extension("track_changes", ffi::register_track_changes)?;
+    #[cfg(feature = "comments")]
+    register_extension("comments", ffi::register_comments)?;
 
     Ok(())
 }
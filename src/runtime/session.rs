@@ -1,13 +1,24 @@
 #[cfg(not(feature = "ssr"))]
 use crate::TiptapEditorInstance;
 #[cfg(not(feature = "ssr"))]
-use crate::protocol::ReadyPayload;
+use crate::TiptapTransferFile;
+#[cfg(not(feature = "ssr"))]
+use crate::protocol::{
+    EditorEvent, HandledEvent, ReadyPayload, TransferFilePayload, TransferHandlersPayload,
+};
+#[cfg(not(feature = "ssr"))]
+use crate::runtime::node_views::TiptapNodeViewRegistry;
 use crate::runtime::{self};
 #[cfg(not(feature = "ssr"))]
 use crate::runtime::{CreateCallbacks, CreateOptions};
+use crate::{TiptapCollabConfig, TiptapCollabSteps};
+use crate::{TiptapCollaborationConfig, TiptapSelectionRange};
 use crate::{
-    TiptapContent, TiptapEditorError, TiptapEditorHandle, TiptapEditorReport, TiptapExtension,
-    TiptapSelectionState,
+    TiptapContent, TiptapCustomExtension, TiptapDecorationClick, TiptapDocumentChange,
+    TiptapDocumentChangeFormat, TiptapDropEvent, TiptapEditorError, TiptapEditorHandle,
+    TiptapEditorReport, TiptapExtension, TiptapImageUpload, TiptapInputRule, TiptapMarkSpec,
+    TiptapNodeSpec, TiptapNodeView, TiptapPasteEvent, TiptapPasteRule, TiptapSelectionState,
+    TiptapShortcuts, TiptapTransaction, TiptapTransferAction,
 };
 use leptos::prelude::*;
 use send_wrapper::SendWrapper;
@@ -17,11 +28,16 @@ use wasm_bindgen::closure::ScopedClosure;
 #[cfg(not(feature = "ssr"))]
 use wasm_bindgen::prelude::Closure;
 
+type NodeViewClosure = ScopedClosure<'static, dyn Fn(JsValue, JsValue, JsValue)>;
+
 /// Stored closures, called by the `TipTap` JS runtime.
 #[allow(dead_code)]
 struct TiptapRuntimeCallbacks {
     content_change: SendWrapper<ScopedClosure<'static, dyn Fn()>>,
     selection_change: SendWrapper<ScopedClosure<'static, dyn Fn(JsValue)>>,
+    editor_event: SendWrapper<ScopedClosure<'static, dyn Fn(JsValue)>>,
+    node_view: SendWrapper<NodeViewClosure>,
+    handled_event: SendWrapper<ScopedClosure<'static, dyn Fn(JsValue) -> bool>>,
 }
 
 #[cfg_attr(feature = "ssr", allow(dead_code))]
@@ -47,11 +63,35 @@ pub(crate) struct TiptapRuntimeMountOptions {
     pub(crate) initial_content: TiptapContent,
     pub(crate) initial_editable: bool,
     pub(crate) extensions: Vec<TiptapExtension>,
+    pub(crate) custom_extensions: Vec<TiptapCustomExtension>,
+    pub(crate) node_specs: Vec<TiptapNodeSpec>,
+    pub(crate) mark_specs: Vec<TiptapMarkSpec>,
+    pub(crate) node_views: Vec<TiptapNodeView>,
+    pub(crate) shortcuts: Option<TiptapShortcuts>,
+    pub(crate) input_rules: Vec<TiptapInputRule>,
+    pub(crate) paste_rules: Vec<TiptapPasteRule>,
+    /// The owner Leptos node views are mounted under.
+    pub(crate) owner: Option<Owner>,
     pub(crate) placeholder: Option<String>,
     pub(crate) on_ready: Option<Callback<()>>,
     pub(crate) on_change: Option<Callback<()>>,
     pub(crate) on_error: Option<Callback<TiptapEditorReport>>,
     pub(crate) on_selection_change: Option<Callback<TiptapSelectionState>>,
+    pub(crate) on_focus: Option<Callback<()>>,
+    pub(crate) on_blur: Option<Callback<()>>,
+    pub(crate) on_transaction: Option<Callback<TiptapTransaction>>,
+    pub(crate) on_document_change: Option<Callback<TiptapDocumentChange>>,
+    pub(crate) document_change_format: TiptapDocumentChangeFormat,
+    pub(crate) collab: Option<TiptapCollabConfig>,
+    pub(crate) on_collab_sendable: Option<Callback<TiptapCollabSteps>>,
+    pub(crate) collaboration: Option<TiptapCollaborationConfig>,
+    pub(crate) on_local_update: Option<Callback<Vec<u8>>>,
+    pub(crate) on_local_selection: Option<Callback<TiptapSelectionRange>>,
+    pub(crate) on_decoration_click: Option<Callback<TiptapDecorationClick>>,
+    pub(crate) on_active_comments: Option<Callback<Vec<String>>>,
+    pub(crate) on_paste: Option<Callback<TiptapPasteEvent, TiptapTransferAction>>,
+    pub(crate) on_drop: Option<Callback<TiptapDropEvent, TiptapTransferAction>>,
+    pub(crate) image_upload: Option<TiptapImageUpload>,
 }
 
 #[cfg_attr(feature = "ssr", allow(dead_code))]
@@ -80,11 +120,71 @@ fn mark_local_editor_failed(
     editor.mark_create_failed();
 }
 
-fn report_runtime_error(on_error: Option<Callback<TiptapEditorReport>>, err: TiptapEditorError) {
+pub(super) fn report_runtime_error(
+    on_error: Option<Callback<TiptapEditorReport>>,
+    err: TiptapEditorError,
+) {
     tracing::error!(?err, "TipTap runtime error.");
     on_error.inspect(move |cb| cb.run(err.into()));
 }
 
+/// Rejects collaboration configurations whose Cargo feature is disabled, because the editor would
+/// otherwise mount without ever exchanging changes.
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+fn validate_collaboration_features(
+    collab: bool,
+    collaboration: bool,
+) -> Result<(), TiptapEditorError> {
+    if collab && !cfg!(feature = "collab") {
+        return Err(TiptapEditorError::BridgeError(
+            "`collab` is configured, but the `collab` feature of leptos-tiptap is disabled"
+                .to_owned(),
+        ));
+    }
+    if collaboration && !cfg!(feature = "collaboration") {
+        return Err(TiptapEditorError::BridgeError(
+            "`collaboration` is configured, but the `collaboration` feature of leptos-tiptap is \
+             disabled"
+                .to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "ssr"))]
+fn transfer_files(files: Vec<TransferFilePayload>) -> Vec<TiptapTransferFile> {
+    files
+        .into_iter()
+        .map(|file| TiptapTransferFile::new(file.name, file.file))
+        .collect()
+}
+
+/// Carries out the action an `on_paste` or `on_drop` callback chose, and returns whether the
+/// handling of Tiptap is prevented.
+#[cfg(not(feature = "ssr"))]
+fn apply_transfer_action(
+    editor: TiptapEditorHandle,
+    action: TiptapTransferAction,
+    position: Option<u32>,
+    on_error: Option<Callback<TiptapEditorReport>>,
+) -> bool {
+    let content = match action {
+        TiptapTransferAction::Proceed => return false,
+        TiptapTransferAction::Cancel => return true,
+        TiptapTransferAction::Insert(content) => content,
+    };
+
+    let inserted = match position {
+        Some(position) => editor.insert_content_at(position, content, None),
+        None => editor.insert_content(content, None),
+    };
+    if let Err(err) = inserted {
+        tracing::error!(?err, "Could not insert the substituted TipTap content.");
+        on_error.inspect(|cb| cb.run(err));
+    }
+    true
+}
+
 impl TiptapRuntimeSession {
     pub(crate) fn new(id: String, editor: TiptapEditorHandle) -> Self {
         editor.mark_not_ready();
@@ -118,11 +218,34 @@ impl TiptapRuntimeSession {
                 initial_content,
                 initial_editable,
                 extensions,
+                custom_extensions,
+                node_specs,
+                mark_specs,
+                node_views,
+                shortcuts,
+                input_rules,
+                paste_rules,
+                owner,
                 placeholder,
                 on_ready,
                 on_change,
                 on_error,
                 on_selection_change,
+                on_focus,
+                on_blur,
+                on_transaction,
+                on_document_change,
+                document_change_format,
+                collab,
+                on_collab_sendable,
+                collaboration,
+                on_local_update,
+                on_local_selection,
+                on_decoration_click,
+                on_active_comments,
+                on_paste,
+                on_drop,
+                image_upload,
             } = options;
 
             let initial_content = match crate::protocol::ContentPayload::try_from(initial_content) {
@@ -139,6 +262,23 @@ impl TiptapRuntimeSession {
                 }
             };
 
+            if let Err(err) =
+                validate_collaboration_features(collab.is_some(), collaboration.is_some())
+            {
+                mark_local_editor_failed(
+                    self.lifecycle,
+                    self.callbacks,
+                    self.applied_editable,
+                    self.editor,
+                );
+                report_runtime_error(on_error, err);
+                return;
+            }
+            #[cfg(not(feature = "collab"))]
+            let _ = (collab, on_collab_sendable);
+            #[cfg(not(feature = "collaboration"))]
+            let _ = (collaboration, on_local_update);
+
             if let Err(err) = TiptapExtension::validate_extension_set(&extensions) {
                 mark_local_editor_failed(
                     self.lifecycle,
@@ -156,12 +296,27 @@ impl TiptapRuntimeSession {
             let applied_editable = self.applied_editable;
             let editor = self.editor;
 
+            let drafts = editor.draft_binding();
+            let save_draft = {
+                let drafts = drafts.clone();
+                move || {
+                    if let Some(drafts) = &drafts {
+                        drafts.schedule_save(editor, move |err| {
+                            tracing::error!(?err, "Could not save the TipTap draft.");
+                            on_error.inspect(|cb| cb.run(err));
+                        });
+                    }
+                }
+            };
+
             let on_content_change_closure = SendWrapper::new(Closure::new(move || {
                 if matches!(
                     *lifecycle.read_value(),
                     TiptapRuntimeLifecycle::Ready { .. }
                 ) {
                     on_change.inspect(|cb| cb.run(()));
+                    editor.notify_content_changed();
+                    save_draft();
                 }
             }));
 
@@ -185,12 +340,200 @@ impl TiptapRuntimeSession {
                     on_selection_change.inspect(|cb| cb.run(selection_state));
                 }));
 
+            let on_error_for_event = on_error;
+            let on_editor_event_closure =
+                SendWrapper::new(Closure::new(move |editor_event_as_js_value| {
+                    if !matches!(
+                        *lifecycle.read_value(),
+                        TiptapRuntimeLifecycle::Ready { .. }
+                    ) {
+                        return;
+                    }
+
+                    let editor_event: EditorEvent =
+                        match serde_wasm_bindgen::from_value(editor_event_as_js_value) {
+                            Ok(editor_event) => editor_event,
+                            Err(err) => {
+                                report_runtime_error(
+                                    on_error_for_event,
+                                    TiptapEditorError::InvalidBridgePayload(format!(
+                                        "could not parse editor event from JS: {err}"
+                                    )),
+                                );
+                                return;
+                            }
+                        };
+
+                    match editor_event {
+                        EditorEvent::DocumentChange { change } => {
+                            on_document_change.inspect(|cb| cb.run(change));
+                        }
+                        #[cfg(feature = "collab")]
+                        EditorEvent::CollabSendable { steps } => {
+                            on_collab_sendable.inspect(|cb| cb.run(steps));
+                        }
+                        #[cfg(feature = "collaboration")]
+                        EditorEvent::CollaborationUpdate { update } => {
+                            on_local_update.inspect(|cb| cb.run(update));
+                        }
+                        EditorEvent::LocalSelection { selection } => {
+                            if let Some(drafts) = &drafts {
+                                drafts.record_selection(selection);
+                            }
+                            on_local_selection.inspect(|cb| cb.run(selection));
+                        }
+                        EditorEvent::DecorationClick { click } => {
+                            on_decoration_click.inspect(|cb| cb.run(click));
+                        }
+                        EditorEvent::SearchMatches { matches } => {
+                            editor.set_search_matches(matches);
+                        }
+                        EditorEvent::FocusChange { focused } => {
+                            editor.set_focused(focused);
+                            if focused { on_focus } else { on_blur }.inspect(|cb| cb.run(()));
+                        }
+                        EditorEvent::Transaction { transaction } => {
+                            on_transaction.inspect(|cb| cb.run(transaction));
+                        }
+                        EditorEvent::HistoryChange {
+                            undo_depth,
+                            redo_depth,
+                        } => {
+                            editor.set_history_depth(undo_depth, redo_depth);
+                        }
+                        EditorEvent::TrackedChanges { changes } => {
+                            editor.set_tracked_changes(changes);
+                        }
+                        EditorEvent::Comments { threads } => {
+                            editor.set_comments(threads);
+                        }
+                        EditorEvent::ActiveComments { thread_ids } => {
+                            on_active_comments.inspect(|cb| cb.run(thread_ids));
+                        }
+                    }
+                }));
+
+            let node_view_names = node_views
+                .iter()
+                .map(|view| view.name().to_owned())
+                .collect();
+            // Node views are mounted while the editor is created, so their events are handled
+            // regardless of the lifecycle.
+            let node_view_registry =
+                TiptapNodeViewRegistry::new(node_views, owner, editor, on_error);
+            let on_node_view_closure = SendWrapper::new(Closure::new(
+                move |event: JsValue, dom: JsValue, content_dom: JsValue| {
+                    node_view_registry.handle_event(event, dom, content_dom);
+                },
+            ));
+
+            let shortcut_payload = shortcuts.as_ref().map(TiptapShortcuts::payload);
+            let transfer_handlers = TransferHandlersPayload {
+                paste: on_paste.is_some() || image_upload.is_some(),
+                drop: on_drop.is_some() || image_upload.is_some(),
+            };
+            let on_error_for_handled_event = on_error;
+            let on_handled_event_closure =
+                SendWrapper::new(Closure::new(move |handled_event_as_js_value| {
+                    if !matches!(
+                        *lifecycle.read_value(),
+                        TiptapRuntimeLifecycle::Ready { .. }
+                    ) {
+                        return false;
+                    }
+
+                    match serde_wasm_bindgen::from_value(handled_event_as_js_value) {
+                        Ok(HandledEvent::Shortcut { keys }) => shortcuts
+                            .as_ref()
+                            .is_some_and(|shortcuts| shortcuts.handle(&keys)),
+                        Ok(HandledEvent::Paste { transfer }) => {
+                            let files = transfer_files(transfer.files);
+                            // Files pasted together with HTML are already part of its content.
+                            let uploads = transfer.html.is_none().then(|| files.clone());
+                            let action =
+                                on_paste.map_or_else(TiptapTransferAction::default, |cb| {
+                                    cb.run(TiptapPasteEvent {
+                                        html: transfer.html,
+                                        text: transfer.text,
+                                        files,
+                                    })
+                                });
+                            match (action, image_upload.as_ref(), uploads) {
+                                (
+                                    TiptapTransferAction::Proceed,
+                                    Some(image_upload),
+                                    Some(files),
+                                ) => image_upload.start(
+                                    editor,
+                                    files,
+                                    None,
+                                    on_error_for_handled_event,
+                                ),
+                                (action, _, _) => apply_transfer_action(
+                                    editor,
+                                    action,
+                                    None,
+                                    on_error_for_handled_event,
+                                ),
+                            }
+                        }
+                        Ok(HandledEvent::Drop {
+                            transfer,
+                            position,
+                            moved,
+                        }) => {
+                            let files = transfer_files(transfer.files);
+                            let uploads =
+                                (transfer.html.is_none() && !moved).then(|| files.clone());
+                            let action = on_drop.map_or_else(TiptapTransferAction::default, |cb| {
+                                cb.run(TiptapDropEvent {
+                                    html: transfer.html,
+                                    text: transfer.text,
+                                    files,
+                                    position,
+                                    moved,
+                                })
+                            });
+                            match (action, image_upload.as_ref(), uploads) {
+                                (
+                                    TiptapTransferAction::Proceed,
+                                    Some(image_upload),
+                                    Some(files),
+                                ) => image_upload.start(
+                                    editor,
+                                    files,
+                                    position,
+                                    on_error_for_handled_event,
+                                ),
+                                (action, _, _) => apply_transfer_action(
+                                    editor,
+                                    action,
+                                    position,
+                                    on_error_for_handled_event,
+                                ),
+                            }
+                        }
+                        Err(err) => {
+                            report_runtime_error(
+                                on_error_for_handled_event,
+                                TiptapEditorError::InvalidBridgePayload(format!(
+                                    "could not parse handled event from JS: {err}"
+                                )),
+                            );
+                            false
+                        }
+                    }
+                }));
+
             lifecycle.update_value(|state| *state = TiptapRuntimeLifecycle::Creating);
             applied_editable.update_value(|value| *value = Some(initial_editable));
             callbacks.update_value(|slot| {
                 *slot = Some(Arc::new(TiptapRuntimeCallbacks {
                     content_change: on_content_change_closure,
                     selection_change: on_selection_change_closure,
+                    editor_event: on_editor_event_closure,
+                    node_view: on_node_view_closure,
+                    handled_event: on_handled_event_closure,
                 }));
             });
 
@@ -206,21 +549,47 @@ impl TiptapRuntimeSession {
                     editable: initial_editable,
                     extensions,
                     placeholder,
+                    document_changes: on_document_change
+                        .is_some()
+                        .then_some(document_change_format),
+                    #[cfg(feature = "collab")]
+                    collab,
+                    #[cfg(feature = "collaboration")]
+                    collaboration,
+                    local_selection: on_local_selection.is_some()
+                        || editor.draft_binding().is_some(),
+                    custom_extensions,
+                    node_specs,
+                    mark_specs,
+                    node_views: node_view_names,
+                    shortcuts: shortcut_payload,
+                    input_rules,
+                    paste_rules,
+                    decoration_clicks: on_decoration_click.is_some(),
+                    transfer_handlers,
+                    transactions: on_transaction.is_some(),
                 },
                 CreateCallbacks {
                     change: &editor_callbacks.content_change,
                     selection: &editor_callbacks.selection_change,
+                    event: &editor_callbacks.editor_event,
+                    node_view: &editor_callbacks.node_view,
+                    handled: &editor_callbacks.handled_event,
                 },
             ) {
                 Ok(ReadyPayload {
                     generation,
                     selection_state,
+                    document_snapshot,
                 }) => {
                     lifecycle.update_value(|state| {
                         *state = TiptapRuntimeLifecycle::Ready { generation };
                     });
                     editor
                         .set_instance(TiptapEditorInstance::new(editor_id.get_value(), generation));
+                    if let Some(document_snapshot) = document_snapshot {
+                        on_document_change.inspect(|cb| cb.run(document_snapshot));
+                    }
                     on_ready.inspect(|cb| cb.run(()));
                     on_selection_change.inspect(|cb| cb.run(selection_state));
                 }
@@ -252,6 +621,7 @@ impl TiptapRuntimeSession {
                 crate::protocol::EditorCommand::SetEditable {
                     editable: desired_editable,
                 },
+                true,
             ) {
                 report_runtime_error(on_error, err);
             } else {
@@ -262,6 +632,13 @@ impl TiptapRuntimeSession {
     }
 
     pub(crate) fn cleanup(self) {
+        // Save changes made since the last scheduled save, while the editor can still be read.
+        if let Some(drafts) = self.editor.draft_binding()
+            && let Err(err) = drafts.flush(self.editor)
+        {
+            tracing::error!(?err, "Could not save the TipTap draft.");
+        }
+
         if matches!(
             *self.lifecycle.read_value(),
             TiptapRuntimeLifecycle::Creating | TiptapRuntimeLifecycle::Ready { .. }
@@ -312,4 +689,13 @@ mod tests {
             assert_that!(unavailable_error(handle)).is_equal_to(TiptapEditorError::NotReady);
         });
     }
+
+    #[test]
+    fn rejects_collaboration_configs_of_disabled_features() {
+        assert_that!(validate_collaboration_features(false, false)).is_ok();
+        assert_that!(validate_collaboration_features(true, false).is_ok())
+            .is_equal_to(cfg!(feature = "collab"));
+        assert_that!(validate_collaboration_features(false, true).is_ok())
+            .is_equal_to(cfg!(feature = "collaboration"));
+    }
 }
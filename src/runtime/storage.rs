@@ -0,0 +1,194 @@
+use crate::TiptapEditorError;
+use cfg_if::cfg_if;
+#[cfg(not(feature = "ssr"))]
+use send_wrapper::SendWrapper;
+
+#[cfg(not(feature = "ssr"))]
+mod js {
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen_futures::js_sys;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(catch, js_namespace = localStorage, js_name = getItem)]
+        pub fn get_item(key: &str) -> Result<Option<String>, JsValue>;
+        #[wasm_bindgen(catch, js_namespace = localStorage, js_name = setItem)]
+        pub fn set_item(key: &str, value: &str) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch, js_namespace = localStorage, js_name = removeItem)]
+        pub fn remove_item(key: &str) -> Result<(), JsValue>;
+    }
+
+    // Drafts are kept as JSON strings in the `drafts` object store, keyed by editor id.
+    #[wasm_bindgen(inline_js = r#"
+const STORE = "drafts";
+
+function settled(request) {
+    return new Promise((resolve, reject) => {
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    });
+}
+
+export function open_database(name) {
+    const request = indexedDB.open(name, 1);
+    request.onupgradeneeded = () => request.result.createObjectStore(STORE);
+    return settled(request);
+}
+
+export async function read_entries(database) {
+    const store = database.transaction(STORE).objectStore(STORE);
+    const [keys, values] = await Promise.all([
+        settled(store.getAllKeys()),
+        settled(store.getAll()),
+    ]);
+    return keys.map((key, index) => [key, values[index]]);
+}
+
+export function put_entry(database, key, value) {
+    return settled(database.transaction(STORE, "readwrite").objectStore(STORE).put(value, key));
+}
+
+export function delete_entry(database, key) {
+    return settled(database.transaction(STORE, "readwrite").objectStore(STORE).delete(key));
+}
+"#)]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub fn open_database(name: &str) -> Result<js_sys::Promise, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub fn read_entries(database: &JsValue) -> Result<js_sys::Promise, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub fn put_entry(
+            database: &JsValue,
+            key: &str,
+            value: &str,
+        ) -> Result<js_sys::Promise, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub fn delete_entry(database: &JsValue, key: &str) -> Result<js_sys::Promise, JsValue>;
+    }
+}
+
+// There is no browser storage under SSR: nothing is found, and writes are dropped.
+
+#[cfg_attr(feature = "ssr", allow(clippy::unnecessary_wraps))]
+pub(crate) fn local_storage_get(key: &str) -> Result<Option<String>, TiptapEditorError> {
+    cfg_if! {if #[cfg(not(feature = "ssr"))] {
+        js::get_item(key).map_err(|err| storage_error(&err))
+    } else {
+        let _ = key;
+        Ok(None)
+    }}
+}
+
+#[cfg_attr(feature = "ssr", allow(clippy::unnecessary_wraps))]
+pub(crate) fn local_storage_set(key: &str, value: &str) -> Result<(), TiptapEditorError> {
+    cfg_if! {if #[cfg(not(feature = "ssr"))] {
+        js::set_item(key, value).map_err(|err| storage_error(&err))
+    } else {
+        let _ = (key, value);
+        Ok(())
+    }}
+}
+
+#[cfg_attr(feature = "ssr", allow(clippy::unnecessary_wraps))]
+pub(crate) fn local_storage_remove(key: &str) -> Result<(), TiptapEditorError> {
+    cfg_if! {if #[cfg(not(feature = "ssr"))] {
+        js::remove_item(key).map_err(|err| storage_error(&err))
+    } else {
+        let _ = key;
+        Ok(())
+    }}
+}
+
+#[cfg(not(feature = "ssr"))]
+fn storage_error(err: &wasm_bindgen::JsValue) -> TiptapEditorError {
+    TiptapEditorError::DraftStorage(err.as_string().unwrap_or_else(|| format!("{err:?}")))
+}
+
+/// An open `IndexedDB` database of drafts.
+///
+/// Writes are started right away, so they apply in order, and finish in the background.
+#[derive(Debug, Clone)]
+pub(crate) struct IndexedDbDrafts {
+    #[cfg(not(feature = "ssr"))]
+    database: SendWrapper<wasm_bindgen::JsValue>,
+}
+
+impl IndexedDbDrafts {
+    /// Opens the database named `name`, creating it when missing, and reads all of its entries.
+    #[cfg_attr(feature = "ssr", allow(clippy::unused_async))]
+    pub(crate) async fn open(
+        name: &str,
+    ) -> Result<(Self, Vec<(String, String)>), TiptapEditorError> {
+        cfg_if! {if #[cfg(not(feature = "ssr"))] {
+            let database = settle(js::open_database(name)).await?;
+            let entries = settle(js::read_entries(&database)).await?;
+            let entries = serde_wasm_bindgen::from_value(entries).map_err(|err| {
+                TiptapEditorError::DraftStorage(format!("could not read the stored drafts: {err}"))
+            })?;
+            Ok((
+                Self {
+                    database: SendWrapper::new(database),
+                },
+                entries,
+            ))
+        } else {
+            let _ = name;
+            Ok((Self {}, Vec::new()))
+        }}
+    }
+
+    /// A handle without a database, for tests that do not write.
+    #[cfg(test)]
+    pub(crate) fn detached() -> Self {
+        Self {
+            #[cfg(not(feature = "ssr"))]
+            database: SendWrapper::new(wasm_bindgen::JsValue::NULL),
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(clippy::unused_self))]
+    pub(crate) fn put(
+        &self,
+        key: &str,
+        value: &str,
+        on_error: impl FnOnce(TiptapEditorError) + 'static,
+    ) {
+        cfg_if! {if #[cfg(not(feature = "ssr"))] {
+            finish_in_background(js::put_entry(&self.database, key, value), on_error);
+        } else {
+            let _ = (key, value, on_error);
+        }}
+    }
+
+    #[cfg_attr(feature = "ssr", allow(clippy::unused_self))]
+    pub(crate) fn delete(&self, key: &str, on_error: impl FnOnce(TiptapEditorError) + 'static) {
+        cfg_if! {if #[cfg(not(feature = "ssr"))] {
+            finish_in_background(js::delete_entry(&self.database, key), on_error);
+        } else {
+            let _ = (key, on_error);
+        }}
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn settle(
+    promise: Result<wasm_bindgen_futures::js_sys::Promise, wasm_bindgen::JsValue>,
+) -> Result<wasm_bindgen::JsValue, TiptapEditorError> {
+    let promise = promise.map_err(|err| storage_error(&err))?;
+    wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map_err(|err| storage_error(&err))
+}
+
+#[cfg(not(feature = "ssr"))]
+fn finish_in_background(
+    promise: Result<wasm_bindgen_futures::js_sys::Promise, wasm_bindgen::JsValue>,
+    on_error: impl FnOnce(TiptapEditorError) + 'static,
+) {
+    leptos::task::spawn_local(async move {
+        if let Err(err) = settle(promise).await {
+            on_error(err);
+        }
+    });
+}
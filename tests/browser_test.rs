@@ -19,8 +19,10 @@ use rootcause_tracing::{RootcauseLayer, SpanCollector};
 use tracing_subscriber::filter::LevelFilter;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::{Layer, Registry};
+use ui_tests::collaboration::ExchangesCollaborationUpdatesBetweenEditors;
 use ui_tests::duplicate_editor_id::RejectsDuplicateLiveEditorId;
 use ui_tests::extension_subset::ActivatesExtensionSubset;
+use ui_tests::form::SubmitsValidatesAndResetsWithForm;
 use ui_tests::handle_lifecycle::{
     RemountsDestroyedHandleThroughNotReady, RetriesFailedHandleThroughNotReady,
 };
@@ -85,7 +87,9 @@ async fn browser_tests() -> Result<(), Report> {
         .with(PreservesJsonObjectsAcrossTheWasmBridge)
         .with(ObservesSelectionStateBoldFlag)
         .with(RemountsDestroyedHandleThroughNotReady)
-        .with(RetriesFailedHandleThroughNotReady);
+        .with(RetriesFailedHandleThroughNotReady)
+        .with(ExchangesCollaborationUpdatesBetweenEditors)
+        .with(SubmitsValidatesAndResetsWithForm);
 
     BrowserTestRunner::new()
         .with_visibility(BrowserTestVisibility::from_env())
@@ -0,0 +1,33 @@
+use crate::Context;
+use crate::ui_tests::{click_button, goto, wait_for_text_contains, wait_for_visible};
+use browser_test::thirtyfour::WebDriver;
+use browser_test::{BrowserTest, async_trait};
+use rootcause::Report;
+use std::borrow::Cow;
+
+/// Drives two editors bound to separate Yjs documents whose local updates are applied to each
+/// other. Asserts that an edit in either editor arrives in the other one, in both directions.
+pub struct ExchangesCollaborationUpdatesBetweenEditors;
+
+#[async_trait]
+impl BrowserTest<Context> for ExchangesCollaborationUpdatesBetweenEditors {
+    fn name(&self) -> Cow<'_, str> {
+        "exchanges-collaboration-updates-between-editors".into()
+    }
+
+    async fn run(&self, driver: &WebDriver, context: &Context) -> Result<(), Report> {
+        goto(driver, &format!("{}/collaboration", context.base_url)).await?;
+
+        wait_for_visible(driver, "#collaboration-a .ProseMirror").await?;
+        wait_for_visible(driver, "#collaboration-b .ProseMirror").await?;
+
+        click_button(driver, "Write A").await?;
+        wait_for_text_contains(driver, "#collaboration-b .ProseMirror", "Hello from A.").await?;
+
+        click_button(driver, "Write B").await?;
+        wait_for_text_contains(driver, "#collaboration-a .ProseMirror", "Reply from B.").await?;
+        wait_for_text_contains(driver, "#collaboration-b .ProseMirror", "Hello from A.").await?;
+
+        Ok(())
+    }
+}
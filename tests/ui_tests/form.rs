@@ -0,0 +1,45 @@
+use crate::Context;
+use crate::ui_tests::{
+    click_button, goto, wait_for_text_contains, wait_for_text_equals, wait_for_visible,
+};
+use browser_test::thirtyfour::WebDriver;
+use browser_test::{BrowserTest, async_trait};
+use rootcause::Report;
+use std::borrow::Cow;
+
+/// Drives a required editor inside a form. Asserts that the document is submitted as HTML under
+/// the editor's name, that an empty document fails validation, and that resetting the form
+/// restores the initial content.
+pub struct SubmitsValidatesAndResetsWithForm;
+
+#[async_trait]
+impl BrowserTest<Context> for SubmitsValidatesAndResetsWithForm {
+    fn name(&self) -> Cow<'_, str> {
+        "submits-validates-and-resets-with-form".into()
+    }
+
+    async fn run(&self, driver: &WebDriver, context: &Context) -> Result<(), Report> {
+        goto(driver, &format!("{}/form", context.base_url)).await?;
+
+        wait_for_visible(driver, "#form-editor .ProseMirror").await?;
+
+        click_button(driver, "Submit").await?;
+        wait_for_text_equals(driver, "#form-submitted", "<p>Initial form content.</p>").await?;
+        click_button(driver, "Check validity").await?;
+        wait_for_text_equals(driver, "#form-validity", "valid").await?;
+
+        click_button(driver, "Clear").await?;
+        click_button(driver, "Check validity").await?;
+        wait_for_text_equals(driver, "#form-validity", "invalid").await?;
+
+        click_button(driver, "Reset").await?;
+        wait_for_text_contains(driver, "#form-editor .ProseMirror", "Initial form content.")
+            .await?;
+        click_button(driver, "Check validity").await?;
+        wait_for_text_equals(driver, "#form-validity", "valid").await?;
+        click_button(driver, "Submit").await?;
+        wait_for_text_equals(driver, "#form-submitted", "<p>Initial form content.</p>").await?;
+
+        Ok(())
+    }
+}
@@ -2,8 +2,10 @@ use browser_test::thirtyfour::prelude::ElementQueryable;
 use browser_test::thirtyfour::{By, WebDriver, WebElement};
 use rootcause::Report;
 
+pub mod collaboration;
 pub mod duplicate_editor_id;
 pub mod extension_subset;
+pub mod form;
 pub mod handle_lifecycle;
 pub mod hydrate_and_round_trip;
 pub mod json_bridge;
@@ -29,10 +29,25 @@ const SHARED_BASE_MODULES = [
     "@tiptap/pm/view",
 ]
 
+// Third-party bindings such as y-prosemirror import ProseMirror directly. Resolve those imports to
+// the hosted `@tiptap/pm` modules, so that all bundles share one ProseMirror instance.
+/** @type {Record<string, string | undefined>} */
+const PROSEMIRROR_MODULE_ALIASES = {
+    "prosemirror-model": "@tiptap/pm/model",
+    "prosemirror-state": "@tiptap/pm/state",
+    "prosemirror-transform": "@tiptap/pm/transform",
+    "prosemirror-view": "@tiptap/pm/view",
+}
+
 const bridgeRuntimeEntry = {
     bridge_runtime: "./src/bridge_runtime.ts",
 }
 
+// Modules that only some Cargo features need. They install themselves into the hosted modules.
+const optionalModuleEntries = {
+    collab_module: "./src/collab_module.ts",
+}
+
 /** @type {Map<string, string[]>} */
 const exportNameCache = new Map()
 
@@ -49,12 +64,13 @@ function createHostedModulePlugin(hostedModuleNames) {
             pluginBuild.onResolve({
                 filter: /.*/,
             }, (args) => {
-                if (!hostedModules.has(args.path)) {
+                const modulePath = PROSEMIRROR_MODULE_ALIASES[args.path] ?? args.path
+                if (!hostedModules.has(modulePath)) {
                     return null
                 }
 
                 return {
-                    path: args.path,
+                    path: modulePath,
                     namespace: "hosted-module",
                 }
             })
@@ -197,6 +213,7 @@ const metafiles = [await buildEntryPoints(bridgeRuntimeEntry)]
 const hostedModulePlugin = createHostedModulePlugin(SHARED_BASE_MODULES)
 
 metafiles.push(await buildEntryPoints(extensionEntries, [hostedModulePlugin]))
+metafiles.push(await buildEntryPoints(optionalModuleEntries, [hostedModulePlugin]))
 
 await validateImportFreeArtifacts(outputDir)
 await writeThirdPartyNotices(metafiles, {workingDirectory: moduleDirectory})
@@ -348,24 +348,18 @@ async function validateBridgeDrift() {
         "export type DocumentResponse =",
         "TypeScript document request union",
     )
-    const rustDocumentSection = extractBetween(
-        protocol,
-        "pub(crate) enum DocumentRequest",
-        "impl DocumentRequest",
-        "Rust document request enum",
+    const rustDocumentFields = parseRustVariantFields(
+        extractRustEnumBody(protocol, "pub(crate) enum DocumentRequest {"),
     )
     assertSameSet(
         "Document request wire contract",
         "tiptap/src/bridge_api.ts",
         matches(tsDocumentSection, /kind:\s*"([^"]+)"/g),
         "src/protocol/mod.rs",
-        matches(rustDocumentSection, /^\s+([A-Z][A-Za-z0-9]*)\s*\{/gm).map(toSnakeCase),
+        [...rustDocumentFields.keys()],
     )
 
     const tsDocumentFields = parseTsVariantFields(tsDocumentSection)
-    const rustDocumentFields = parseRustVariantFields(
-        extractRustEnumBody(protocol, "pub(crate) enum DocumentRequest {"),
-    )
     assertSameVariantFields(
         "Document request payload contract",
         "tiptap/src/bridge_api.ts",
@@ -374,6 +368,31 @@ async function validateBridgeDrift() {
         rustDocumentFields,
     )
 
+    const tsEventSection = extractBetween(
+        bridgeApi,
+        "export type EditorEvent =",
+        "export type OnEditorEvent =",
+        "TypeScript editor event union",
+    )
+    const tsEventFields = parseTsVariantFields(tsEventSection)
+    const rustEventFields = parseRustVariantFields(
+        extractRustEnumBody(protocol, "pub(crate) enum EditorEvent {"),
+    )
+    assertSameSet(
+        "Editor event wire contract",
+        "tiptap/src/bridge_api.ts",
+        [...tsEventFields.keys()],
+        "src/protocol/mod.rs",
+        [...rustEventFields.keys()],
+    )
+    assertSameVariantFields(
+        "Editor event payload contract",
+        "tiptap/src/bridge_api.ts",
+        tsEventFields,
+        "src/protocol/mod.rs",
+        rustEventFields,
+    )
+
     const tsActiveKeySection = extractBetween(
         bridgeApi,
         "export type ActiveKey =",
@@ -0,0 +1,88 @@
+// Runs commands through the real generated artifacts. They are bundled separately from the sources
+// covered by the unit tests, so this catches artifacts that were not regenerated after a change.
+
+import assert from "node:assert/strict"
+import path from "node:path"
+import test from "node:test"
+import {fileURLToPath, pathToFileURL} from "node:url"
+
+const moduleDirectory = path.dirname(fileURLToPath(import.meta.url))
+const generatedDirectory = path.resolve(moduleDirectory, "../src/js/generated")
+const extensionNames = ["document", "paragraph", "text", "bold"]
+
+/** @param {string} artifactName */
+function importArtifact(artifactName) {
+    return import(pathToFileURL(path.join(generatedDirectory, artifactName)).href)
+}
+
+/**
+ * A minimal editor recording the commands chained on it and whether it took focus.
+ */
+class RecordingEditor {
+    focused = false
+    /** @type {string[]} */
+    chainCalls = []
+
+    chain() {
+        /** @type {unknown} */
+        const chain = new Proxy({}, {
+            get: (_target, property) => {
+                if (property === "run") {
+                    return () => true
+                }
+
+                return () => {
+                    this.chainCalls.push(String(property))
+                    if (property === "focus") {
+                        this.focused = true
+                    }
+                    return chain
+                }
+            },
+        })
+        return chain
+    }
+
+    isActive() {
+        return false
+    }
+
+    destroy() {
+    }
+}
+
+test("runs extension commands of handles without focus without focusing the editor", async () => {
+    const runtime = await importArtifact("bridge_runtime.js")
+    runtime.init_bridge_runtime()
+    for (const name of extensionNames) {
+        const extension = await importArtifact(`tiptap_${name}.js`)
+        extension[`register_${name}`]()
+    }
+
+    const editor = new RecordingEditor()
+    runtime.__testing.setDocument({getElementById: () => ({})})
+    runtime.__testing.setEditorFactory(() => editor)
+    const created = runtime.create(
+        {id: "id", content: {format: "html", value: "<p>hello</p>"}, editable: true, extensions: extensionNames},
+        () => {
+        },
+        () => {
+        },
+    )
+    assert.equal(created.ok, true)
+
+    const run = (/** @type {boolean} */ focus) => runtime.command({
+        id: "id",
+        generation: created.value.generation,
+        command: {kind: "toggle_bold"},
+        focus,
+    })
+
+    assert.deepEqual(run(false), {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(editor.chainCalls, ["toggleBold"])
+    assert.equal(editor.focused, false)
+
+    assert.deepEqual(run(true), {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(editor.chainCalls, ["toggleBold", "focus", "toggleBold"])
+    assert.equal(editor.focused, true)
+})
@@ -1,4 +1,4 @@
-import type {Editor, EditorOptions} from "@tiptap/core"
+import type {ChainedCommands, Editor, EditorOptions} from "@tiptap/core"
 
 import {BRIDGE_GLOBAL_KEY, bridgeScopeKey} from "../bridge-config.mjs"
 
@@ -6,6 +6,9 @@ export {BRIDGE_GLOBAL_KEY}
 
 const BRIDGE_SCOPE_KEY = bridgeScopeKey(import.meta.url)
 
+// Transaction metadata marking a replacement of the whole document through `set_content`.
+export const SET_CONTENT_META = "leptosTiptapSetContent"
+
 export type ContentFormat = "html" | "json"
 
 export type ErrorKind =
@@ -60,8 +63,12 @@ export type ActiveKey =
 
 export type ActiveState = Partial<Record<ActiveKey, boolean>>
 
+// Active states contributed by custom extensions, keyed by names the application chooses.
+export type CustomActiveState = Record<string, boolean>
+
 export type SelectionState = {
     active: ActiveState
+    custom?: CustomActiveState
 }
 
 export type ContentPayload =
@@ -74,17 +81,174 @@ export type ContentPayload =
         value: unknown
     }
 
+export type DocumentChangeFormat = "json_patch" | "steps"
+
+export type JsonPatchOperation =
+    | { op: "add"; path: string; value: unknown }
+    | { op: "remove"; path: string }
+    | { op: "replace"; path: string; value: unknown }
+
+export type DocumentChangePayload =
+    | { format: "snapshot"; document: unknown }
+    | { format: "json_patch"; operations: JsonPatchOperation[] }
+    | { format: "steps"; steps: unknown[] }
+
+export type DocumentChange = {
+    version: number
+    payload: DocumentChangePayload
+}
+
+export type CollabConfig = {
+    version: number
+    client_id: string
+}
+
+export type CollabSteps = {
+    version: number
+    steps: unknown[]
+    client_id: string
+}
+
+export type CollaborationConfig = {
+    field: string
+    initial_update?: number[] | null
+}
+
+export type SelectionRange = {
+    anchor: number
+    head: number
+}
+
+export type CollaborationUser = {
+    id: string
+    name: string
+    color: string
+}
+
+export type RemoteCursor = {
+    user: CollaborationUser
+    selection: SelectionRange
+}
+
+export type Decoration = {
+    id: string
+    target:
+        | { kind: "inline"; from: number; to: number }
+        | { kind: "node"; position: number }
+        | { kind: "widget"; position: number }
+    class?: string | null
+    style?: string | null
+    attributes: Record<string, string>
+    // The text content of a widget.
+    text?: string | null
+}
+
+export type DiffChange = "inserted" | "deleted" | "changed"
+
+// Blocks are top-level nodes by index. Text offsets count UTF-16 code units of the text nodes
+// within a block.
+export type DiffHighlight =
+    | { kind: "block"; block: number; change: DiffChange }
+    | { kind: "text"; block: number; from: number; to: number; change: DiffChange }
+    | { kind: "deleted_text"; block: number; offset: number; text: string }
+
+export type DecorationClick = {
+    layer_id: string
+    decoration_id: string
+}
+
+export type SearchOptions = {
+    case_sensitive: boolean
+    whole_word: boolean
+    regex: boolean
+}
+
+export type SearchMatches = {
+    count: number
+    current: number | null
+}
+
+// Where a transaction came from. `api` transactions were dispatched while Rust called into the
+// runtime, such as for commands and content requests.
+export type TransactionOrigin = "api" | "paste" | "drop" | "cut" | "input_rule" | "other"
+
+export type TransactionInfo = {
+    doc_changed: boolean
+    // The new selection, when the transaction moved it.
+    selection: SelectionRange | null
+    // The values of the keys written through `set_meta`.
+    meta: Record<string, unknown>
+    add_to_history: boolean
+    origin: TransactionOrigin
+}
+
+export type ImageResource = {
+    src: string
+    alt?: string | null
+    title?: string | null
+}
+
 export type CreateRequest = {
     id: string
     content: ContentPayload
     editable: boolean
     extensions: string[]
     placeholder?: string | null
+    document_changes?: DocumentChangeFormat | null
+    collab?: CollabConfig | null
+    collaboration?: CollaborationConfig | null
+    local_selection?: boolean
+    custom_extensions?: CustomExtensionRequest[]
+    node_specs?: NodeSpecRequest[]
+    mark_specs?: MarkSpecRequest[]
+    node_views?: string[]
+    shortcuts?: ShortcutsRequest | null
+    input_rules?: InputRuleRequest[]
+    paste_rules?: PasteRuleRequest[]
+    decoration_clicks?: boolean
+    transfer_handlers?: TransferHandlers
+    transactions?: boolean
+}
+
+// Rules match JS regular expressions. Paste rules only replace text or apply marks.
+export type RuleAction =
+    | { kind: "replace"; text: string }
+    | { kind: "mark"; name: string; attributes?: AttributesPayload | null }
+    | { kind: "wrap_in"; name: string; attributes?: AttributesPayload | null }
+    | { kind: "set_block"; name: string; attributes?: AttributesPayload | null }
+
+export type InputRuleRequest = {
+    find: string
+    action: RuleAction
+}
+
+export type PasteRuleRequest = {
+    find: string
+    action: Extract<RuleAction, { kind: "replace" | "mark" }>
+}
+
+// Key combinations, such as "Mod-s", bound to Rust callbacks. Bindings with a higher priority run first.
+// Whether pastes and drops are passed to Rust before Tiptap handles them.
+export type TransferHandlers = {
+    paste: boolean
+    drop: boolean
+}
+
+export type TransferPayload = {
+    html: string | null
+    text: string | null
+    files: Array<{ name: string; file: File }>
+}
+
+export type ShortcutsRequest = {
+    priority: number
+    keys: string[]
 }
 
 export type ReadyPayload = {
     generation: number
     selection_state: SelectionState
+    document_snapshot?: DocumentChange | null
 }
 
 export type TextAlign = "left" | "center" | "right" | "justify"
@@ -106,6 +270,21 @@ export type Range = {
 
 export type PositionOrRange = number | Range
 
+export type TrackedChange = {
+    id: string
+    kind: "insertion" | "deletion"
+    author: string
+    timestamp: number
+    range: Range
+    text: string
+}
+
+export type CommentThread = {
+    thread_id: string
+    ranges: Range[]
+    resolved: boolean
+}
+
 export type FocusTarget = "start" | "end" | "all" | number | null
 
 export type FocusOptions = {
@@ -136,6 +315,9 @@ export type MarkOptions = {
     extend_empty_mark_range?: boolean
 }
 
+// The generated command of a node or mark spec: `<action>_<name>`.
+export type SpecCommandAction = "set" | "toggle" | "unset"
+
 // Intentionally unsupported core commands/overloads:
 // - `command`, `first`, and `for_each`: require executable callbacks over the bridge.
 // - `set_meta` with `Plugin` or `PluginKey`: non-serializable runtime objects.
@@ -217,6 +399,7 @@ export type ExtensionCommand =
     | { kind: "unset_highlight" }
     | { kind: "undo" }
     | { kind: "redo" }
+    | { kind: "clear_history" }
     | { kind: "set_horizontal_rule" }
     | { kind: "set_image"; src: string; alt?: string | null; title?: string | null }
     | { kind: "set_italic" }
@@ -236,11 +419,37 @@ export type ExtensionCommand =
     | { kind: "toggle_text_align"; alignment: TextAlign }
     | { kind: "unset_text_align" }
     | { kind: "set_youtube_video"; src: string; start?: number | null; width?: number | null; height?: number | null }
+    | { kind: "set_track_changes"; author?: string | null }
+    | { kind: "accept_change"; change_id: string }
+    | { kind: "reject_change"; change_id: string }
+    | { kind: "accept_all_changes" }
+    | { kind: "reject_all_changes" }
+    | { kind: "add_comment"; thread_id: string }
+    | { kind: "remove_comment"; thread_id: string }
+    | { kind: "set_comment_resolved"; thread_id: string; resolved: boolean }
 
 export type RuntimeCommand =
     | { kind: "set_editable"; editable: boolean }
-
-export type EditorCommand = CoreCommand | ExtensionCommand | RuntimeCommand
+    | { kind: "set_remote_cursors"; cursors: RemoteCursor[] }
+    | { kind: "set_decorations"; layer_id: string; decorations: Decoration[] }
+    | { kind: "set_selection"; anchor: number; head: number }
+    | { kind: "show_diff"; document: unknown; highlights: DiffHighlight[] }
+    | { kind: "hide_diff" }
+    | { kind: "clear_search" }
+    | { kind: "select_next_match" }
+    | { kind: "select_previous_match" }
+    | { kind: "replace_current_match"; replacement: string }
+    | { kind: "replace_all_matches"; replacement: string }
+    | { kind: "start_image_upload"; upload_id: number; position?: number | null }
+    | { kind: "set_image_upload_progress"; upload_id: number; progress: number }
+    | { kind: "finish_image_upload"; upload_id: number; image: ImageResource }
+    | { kind: "cancel_image_upload"; upload_id: number }
+
+export type SpecCommand = { kind: "spec_command"; action: SpecCommandAction; name: string; attributes?: AttributesPayload | null }
+
+export type NodeViewCommand = { kind: "update_node_view_attributes"; view_id: number; attributes: AttributesPayload }
+
+export type EditorCommand = CoreCommand | ExtensionCommand | RuntimeCommand | SpecCommand | NodeViewCommand
 
 export type CommandKind = EditorCommand["kind"]
 export type CoreCommandKind = CoreCommand["kind"]
@@ -249,12 +458,62 @@ export type RuntimeCommandKind = RuntimeCommand["kind"]
 
 export type DocumentRequest =
     | { kind: "get_content"; format: ContentFormat }
+    | { kind: "get_text" }
     | { kind: "set_content"; content: ContentPayload; options?: SetContentOptions | null }
+    | { kind: "get_document_snapshot" }
+    | { kind: "get_collab_state" }
+    | { kind: "receive_transaction"; steps: unknown[]; client_ids: string[] }
+    | { kind: "apply_collaboration_update"; update: number[] }
+    | { kind: "get_collaboration_state_vector" }
+    | { kind: "encode_collaboration_state"; state_vector?: number[] | null }
+    | { kind: "custom_command"; name: string; payload: unknown }
+    | { kind: "find"; query: string; options: SearchOptions }
 
 export type DocumentResponse =
     | { kind: "content"; content: ContentPayload }
+    | { kind: "text"; text: string }
+    | { kind: "document_snapshot"; change: DocumentChange }
+    | { kind: "collab_state"; version: number; sendable: CollabSteps | null }
+    | { kind: "collaboration_state_vector"; state_vector: number[] }
+    | { kind: "collaboration_update"; update: number[] }
+    | { kind: "custom_command"; value: unknown }
+    | { kind: "search_matches"; matches: Range[] }
     | EmptyResponse
 
+export type EditorEvent =
+    | { kind: "document_change"; change: DocumentChange }
+    | { kind: "collab_sendable"; steps: CollabSteps }
+    | { kind: "collaboration_update"; update: number[] }
+    | { kind: "local_selection"; selection: SelectionRange }
+    | { kind: "decoration_click"; click: DecorationClick }
+    | { kind: "search_matches"; matches: SearchMatches }
+    | { kind: "focus_change"; focused: boolean }
+    | { kind: "transaction"; transaction: TransactionInfo }
+    | { kind: "history_change"; undo_depth: number; redo_depth: number }
+    | { kind: "tracked_changes"; changes: TrackedChange[] }
+    | { kind: "comments"; threads: CommentThread[] }
+    | { kind: "active_comments"; thread_ids: string[] }
+
+export type OnEditorEvent = (event: EditorEvent) => void
+
+// Lifecycle of the node views rendered by Leptos components. Mounted views pass their element
+// and content element next to the event.
+export type NodeViewEvent =
+    | { kind: "mount"; view_id: number; name: string; attributes: AttributesPayload; selected: boolean }
+    | { kind: "update"; view_id: number; attributes: AttributesPayload }
+    | { kind: "select"; view_id: number; selected: boolean }
+    | { kind: "destroy"; view_id: number }
+
+export type OnNodeViewEvent = (event: NodeViewEvent, dom?: HTMLElement, contentDom?: HTMLElement | null) => void
+
+// Events Rust handles while the browser event is dispatched, returning whether it handled them.
+export type HandledEvent =
+    | { kind: "shortcut"; keys: string }
+    | { kind: "paste"; transfer: TransferPayload }
+    | { kind: "drop"; transfer: TransferPayload; position: number | null; moved: boolean }
+
+export type OnHandledEvent = (event: HandledEvent) => boolean
+
 export type DocumentCall = {
     id: string
     generation: number
@@ -262,16 +521,37 @@ export type DocumentCall = {
 }
 
 export type TiptapExtension = NonNullable<EditorOptions["extensions"]>[number]
+export type CommandOptions = {
+    focus: boolean
+}
 export type ExtensionCommandHandler<K extends ExtensionCommandKind = ExtensionCommandKind> = (
     editor: Editor,
     command: Extract<ExtensionCommand, { kind: K }>,
+    options: CommandOptions,
 ) => boolean | void
 export type ExtensionCommandHandlers = Partial<{
     [K in ExtensionCommandKind]: ExtensionCommandHandler<K>
 }>
 
+// A Y.Doc bound to one editor. Updates cross the bridge as plain byte arrays.
+export type CollaborationDocument = {
+    apply_update: (update: number[]) => void
+    encode_state_vector: () => number[]
+    encode_state_as_update: (stateVector?: number[] | null) => number[]
+    destroy: () => void
+}
+
+export type CollaborationCreateContext = CollaborationConfig & {
+    on_local_update: (update: number[]) => void
+    bind: (document: CollaborationDocument) => void
+}
+
 export type ExtensionCreateContext = {
     placeholder?: string | null
+    collaboration?: CollaborationCreateContext | null
+    on_tracked_changes?: (changes: TrackedChange[]) => void
+    on_comments?: (threads: CommentThread[]) => void
+    on_active_comments?: (threadIds: string[]) => void
 }
 
 export type ExtensionDescriptor = {
@@ -282,6 +562,53 @@ export type ExtensionDescriptor = {
     active_state?: (editor: Editor) => ActiveState
 }
 
+// Extensions supplied by the application through `TiptapCustomExtension`. They are created from
+// the hosted modules, so they share the Tiptap and ProseMirror instances of the bridge.
+export type CustomExtensionCreateContext = {
+    modules: Record<string, unknown>
+}
+
+export type CustomCommandHandler = (editor: Editor, payload: unknown) => unknown
+
+export type CustomExtensionDescriptor = {
+    name?: string
+    create: (context: CustomExtensionCreateContext) => TiptapExtension | TiptapExtension[]
+    commands?: Record<string, CustomCommandHandler>
+    active_keys?: string[]
+    active_state?: (editor: Editor) => CustomActiveState
+}
+
+// The descriptor is the object returned by the application's JS module, passed through as is.
+export type CustomExtensionRequest = {
+    name: string
+    descriptor: unknown
+}
+
+// Nodes and marks declared in Rust through `TiptapNodeSpec` and `TiptapMarkSpec`.
+export type RenderTemplate = {
+    tag: string
+    attributes: AttributesPayload
+}
+
+export type NodeSpecRequest = {
+    name: string
+    group?: string | null
+    content?: string | null
+    inline: boolean
+    atom: boolean
+    attributes: AttributesPayload
+    parse_html: string[]
+    render_html?: RenderTemplate | null
+}
+
+export type MarkSpecRequest = {
+    name: string
+    inclusive?: boolean | null
+    attributes: AttributesPayload
+    parse_html: string[]
+    render_html?: RenderTemplate | null
+}
+
 export type BridgeBindings = {
     modules: Record<string, unknown>
     registerExtension: (descriptor: ExtensionDescriptor) => void
@@ -300,6 +627,12 @@ export function emptySelectionState(): SelectionState {
     return {active: {}}
 }
 
+// Focusing also scrolls the selection into view, so commands dispatched without focus do neither.
+export function commandChain(editor: Editor, options: CommandOptions): ChainedCommands {
+    const chain = editor.chain()
+    return options.focus ? chain.focus() : chain
+}
+
 function createBridgeBindings(): BridgeBindings {
     return {
         modules: {},
@@ -3,6 +3,8 @@ import type {Editor} from "@tiptap/core"
 import type {ActiveKey, ActiveState, ExtensionDescriptor} from "./bridge_api.ts"
 import {getBridgeBindings} from "./bridge_api.ts"
 
+export {commandChain} from "./bridge_api.ts"
+
 const bridgeBindings = getBridgeBindings()
 
 export function activeState(
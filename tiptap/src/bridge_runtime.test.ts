@@ -1,6 +1,8 @@
 import assert from "node:assert/strict"
 import test from "node:test"
 import type {Editor, EditorOptions} from "@tiptap/core"
+import {Schema} from "@tiptap/pm/model"
+import {AllSelection, EditorState, TextSelection} from "@tiptap/pm/state"
 
 import type {
     ActiveState,
@@ -8,6 +10,8 @@ import type {
     BridgeResult,
     CreateRequest,
     EditorCommand,
+    EditorEvent,
+    HandledEvent,
     ReadyPayload,
     SelectionState,
 } from "./bridge_api.ts"
@@ -15,13 +19,16 @@ import {
     BRIDGE_GLOBAL_KEY,
     getBridgeBindings,
     getOrCreateBridgeBindings,
+    SET_CONTENT_META,
 } from "./bridge_api.ts"
 import {__testing, command, create as createRuntime, destroy, document} from "./bridge_runtime.ts"
+import type {DocumentNode} from "./document_changes.ts"
 import {register_blockquote} from "./extensions/tiptap_blockquote.ts"
 import {register_bold} from "./extensions/tiptap_bold.ts"
 import {register_bullet_list} from "./extensions/tiptap_bullet_list.ts"
 import {register_code} from "./extensions/tiptap_code.ts"
 import {register_code_block} from "./extensions/tiptap_code_block.ts"
+import {__testing as commentsTesting} from "./extensions/tiptap_comments.ts"
 import {register_document} from "./extensions/tiptap_document.ts"
 import {register_dropcursor} from "./extensions/tiptap_dropcursor.ts"
 import {register_gapcursor} from "./extensions/tiptap_gapcursor.ts"
@@ -40,6 +47,7 @@ import {register_placeholder} from "./extensions/tiptap_placeholder.ts"
 import {register_strike} from "./extensions/tiptap_strike.ts"
 import {register_text} from "./extensions/tiptap_text.ts"
 import {register_text_align} from "./extensions/tiptap_text_align.ts"
+import {__testing as trackChangesTesting} from "./extensions/tiptap_track_changes.ts"
 import {register_youtube} from "./extensions/tiptap_youtube.ts"
 
 const DEFAULT_EXTENSION_NAMES: string[] = [
@@ -144,6 +152,36 @@ function registerDefaultExtensions(): void {
     register_youtube()
 }
 
+function fakeDocument(...paragraphs: string[]): DocumentNode {
+    const children = paragraphs.map((text): DocumentNode => ({
+        type: "paragraph",
+        isText: false,
+        attrs: {text},
+        marks: [],
+        childCount: 0,
+        child: () => {
+            throw new Error("fake paragraphs have no children")
+        },
+        toJSON: () => ({type: "paragraph", attrs: {text}}),
+    }))
+
+    return {
+        type: "doc",
+        isText: false,
+        attrs: {},
+        marks: [],
+        childCount: children.length,
+        child: (index) => {
+            const child = children[index]
+            if (child == null) {
+                throw new Error(`fake document has no child at ${index}`)
+            }
+            return child
+        },
+        toJSON: () => ({type: "doc", content: children.map((child) => child.toJSON())}),
+    }
+}
+
 class FakeEditor {
     destroyed = false
     editable: boolean | undefined
@@ -152,12 +190,17 @@ class FakeEditor {
     onUpdate: NonNullable<EditorOptions["onUpdate"]> | undefined
     onSelectionUpdate: NonNullable<EditorOptions["onSelectionUpdate"]> | undefined
     onTransaction: NonNullable<EditorOptions["onTransaction"]> | undefined
+    onFocus: NonNullable<EditorOptions["onFocus"]> | undefined
+    onBlur: NonNullable<EditorOptions["onBlur"]> | undefined
     activeStates: Record<string, boolean> = {}
     state = {
         selection: {
             from: 1,
             to: 1,
+            anchor: 1,
+            head: 1,
         },
+        doc: fakeDocument(),
     }
     getHtmlError: Error | undefined
     getJsonError: Error | undefined
@@ -169,13 +212,28 @@ class FakeEditor {
     chainRunResult = true
     chainCalls: Array<{ name: string; args: unknown[] }> = []
     commandCalls: Array<{ name: string; args: unknown[] }> = []
+    schema = {}
+    dispatchedTransactions: unknown[] = []
+    view = {
+        dispatch: (transaction: unknown) => {
+            this.dispatchedTransactions.push(transaction)
+            this.emitTransaction(transaction)
+        },
+    }
 
-    constructor(readonly options: Pick<Partial<EditorOptions>, "content" | "extensions" | "onUpdate" | "onSelectionUpdate" | "onTransaction">) {
+    constructor(
+        readonly options: Pick<
+            Partial<EditorOptions>,
+            "content" | "extensions" | "onUpdate" | "onSelectionUpdate" | "onTransaction" | "onFocus" | "onBlur"
+        >,
+    ) {
         this.content = options.content
         this.extensions = options.extensions
         this.onUpdate = options.onUpdate
         this.onSelectionUpdate = options.onSelectionUpdate
         this.onTransaction = options.onTransaction
+        this.onFocus = options.onFocus
+        this.onBlur = options.onBlur
     }
 
     commands = new Proxy({}, {
@@ -212,6 +270,10 @@ class FakeEditor {
         return "<p>fake</p>"
     }
 
+    getText(): string {
+        return "fake"
+    }
+
     getJSON(): unknown {
         if (this.getJsonError != null) {
             throw this.getJsonError
@@ -220,6 +282,10 @@ class FakeEditor {
         return {type: "doc", content: []}
     }
 
+    get isEditable(): boolean {
+        return this.editable ?? true
+    }
+
     setEditable(editable: boolean): void {
         this.editable = editable
     }
@@ -271,13 +337,21 @@ class FakeEditor {
         this.onSelectionUpdate?.({editor: this as unknown as Editor} as never)
     }
 
-    emitTransaction(): void {
-        this.onTransaction?.({editor: this as unknown as Editor} as never)
+    emitTransaction(transaction?: unknown): void {
+        this.onTransaction?.({editor: this as unknown as Editor, transaction} as never)
     }
 
     emitUpdate(): void {
         this.onUpdate?.({editor: this as unknown as Editor} as never)
     }
+
+    emitFocus(): void {
+        this.onFocus?.({editor: this as unknown as Editor} as never)
+    }
+
+    emitBlur(): void {
+        this.onBlur?.({editor: this as unknown as Editor} as never)
+    }
 }
 
 function createFakeDocument(
@@ -395,6 +469,7 @@ function createAndGetGeneration(request = createRequest()): number {
 function assertCommandDispatch(
     dispatchedCommand: EditorCommand,
     expectedChainCalls: Array<{ name: string; args: unknown[] }>,
+    focus?: boolean,
 ): void {
     const editor = new FakeEditor({content: "<p>hello</p>"})
     setupAdapterTest({
@@ -406,6 +481,7 @@ function assertCommandDispatch(
         id: "id",
         generation,
         command: dispatchedCommand,
+        focus,
     })
 
     assert.equal(result.ok, true)
@@ -631,7 +707,15 @@ test("supports base schema extensions when they are requested explicitly", () =>
     const extensionNames = createdEditors[0]?.extensions?.map(
         (extension) => (extension as {name?: string}).name,
     )
-    assert.deepEqual(extensionNames, ["doc", "paragraph", "text"])
+    assert.deepEqual(extensionNames, [
+        "doc",
+        "paragraph",
+        "text",
+        "leptosTiptapRemoteCursors",
+        "leptosTiptapDecorations",
+        "leptosTiptapSearch",
+        "leptosTiptapImageUploads",
+    ])
 
     const result = command({
         id: "id",
@@ -849,6 +933,16 @@ test("dispatches parity commands to the expected chained editor methods", () =>
     }
 })
 
+test("dispatches extension commands without focus when requested", () => {
+    assertCommandDispatch({kind: "toggle_bold"}, [{name: "toggleBold", args: []}], false)
+    assertCommandDispatch(
+        {kind: "set_link", href: "https://example.com"},
+        [{name: "setLink", args: [{href: "https://example.com"}]}],
+        false,
+    )
+    assertCommandDispatch({kind: "undo"}, [{name: "focus", args: []}, {name: "undo", args: []}], true)
+})
+
 test("dispatches core commands directly to editor.commands without implicit focus", () => {
     const cases: Array<{
         name: string
@@ -1096,7 +1190,7 @@ test("reports invalid JSON on document set_content distinctly from editor availa
     assert.match(result.error.message, /Could not parse Tiptap JSON content/)
 })
 
-test("forwards document set_content options to editor.commands.setContent", () => {
+test("forwards document set_content options to the setContent command", () => {
     const editor = new FakeEditor({content: "<p>hello</p>"})
     setupAdapterTest({
         makeEditor: () => editor,
@@ -1126,22 +1220,25 @@ test("forwards document set_content options to editor.commands.setContent", () =
     })
 
     assert.equal(result.ok, true)
-    assert.deepEqual(editor.commandCalls, [{
-        name: "setContent",
-        args: [
-            "<p>updated</p>",
-            true,
-            {
-                preserveWhitespace: "full",
-                from: 1,
-                to: 2,
-            },
-            {
-                errorOnInvalidContent: true,
-            },
-        ],
-    }])
-    assert.deepEqual(editor.chainCalls, [])
+    assert.deepEqual(editor.chainCalls, [
+        {name: "setMeta", args: ["leptosTiptapSetContent", true]},
+        {
+            name: "setContent",
+            args: [
+                "<p>updated</p>",
+                true,
+                {
+                    preserveWhitespace: "full",
+                    from: 1,
+                    to: 2,
+                },
+                {
+                    errorOnInvalidContent: true,
+                },
+            ],
+        },
+    ])
+    assert.deepEqual(editor.commandCalls, [])
 })
 
 test("defaults document set_content to emitting an update", () => {
@@ -1165,15 +1262,18 @@ test("defaults document set_content to emitting an update", () => {
     })
 
     assert.equal(result.ok, true)
-    assert.deepEqual(editor.commandCalls, [{
-        name: "setContent",
-        args: [
-            "<p>updated</p>",
-            true,
-            {},
-            {errorOnInvalidContent: undefined},
-        ],
-    }])
+    assert.deepEqual(editor.chainCalls, [
+        {name: "setMeta", args: ["leptosTiptapSetContent", true]},
+        {
+            name: "setContent",
+            args: [
+                "<p>updated</p>",
+                true,
+                {},
+                {errorOnInvalidContent: undefined},
+            ],
+        },
+    ])
 })
 
 test("allows document set_content updates to be silenced explicitly", () => {
@@ -1200,15 +1300,18 @@ test("allows document set_content updates to be silenced explicitly", () => {
     })
 
     assert.equal(result.ok, true)
-    assert.deepEqual(editor.commandCalls, [{
-        name: "setContent",
-        args: [
-            "<p>updated</p>",
-            false,
-            {},
-            {errorOnInvalidContent: undefined},
-        ],
-    }])
+    assert.deepEqual(editor.chainCalls, [
+        {name: "setMeta", args: ["leptosTiptapSetContent", true]},
+        {
+            name: "setContent",
+            args: [
+                "<p>updated</p>",
+                false,
+                {},
+                {errorOnInvalidContent: undefined},
+            ],
+        },
+    ])
 })
 
 test("forwards insert_content invalid-content handling options to editor.commands.insertContent", () => {
@@ -1822,3 +1925,1413 @@ test("reports commands from inactive extensions as extension_unavailable", () =>
     assert.equal(result.error.kind, "extension_unavailable")
     assert.equal(result.error.operation, "toggle_bold")
 })
+
+test("emits versioned json patch document changes after the ready snapshot", () => {
+    const createdEditors = setupAdapterTest()
+    const events: EditorEvent[] = []
+
+    const result = createRuntime(
+        {...createRequest(), document_changes: "json_patch"},
+        () => {
+        },
+        () => {
+        },
+        (event) => {
+            events.push(event)
+        },
+    )
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+    assert.deepEqual(result.value.document_snapshot, {
+        version: 0,
+        payload: {format: "snapshot", document: {type: "doc", content: []}},
+    })
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+
+    editor.emitTransaction()
+    assert.deepEqual(events, [])
+
+    editor.state.doc = fakeDocument("a")
+    editor.emitTransaction()
+    editor.state.doc = fakeDocument("b")
+    editor.emitTransaction()
+
+    assert.deepEqual(events, [
+        {
+            kind: "document_change",
+            change: {
+                version: 1,
+                payload: {
+                    format: "json_patch",
+                    operations: [{op: "add", path: "/content", value: [{type: "paragraph", attrs: {text: "a"}}]}],
+                },
+            },
+        },
+        {
+            kind: "document_change",
+            change: {
+                version: 2,
+                payload: {
+                    format: "json_patch",
+                    operations: [{op: "replace", path: "/content/0/attrs", value: {text: "b"}}],
+                },
+            },
+        },
+    ])
+
+    const snapshot = document({
+        id: "id",
+        generation: result.value.generation,
+        request: {kind: "get_document_snapshot"},
+    })
+    assert.deepEqual(snapshot, {
+        ok: true,
+        value: {
+            kind: "document_snapshot",
+            change: {
+                version: 2,
+                payload: {format: "snapshot", document: {type: "doc", content: [{type: "paragraph", attrs: {text: "b"}}]}},
+            },
+        },
+    })
+})
+
+test("rejects document snapshots for editors without a change stream", () => {
+    setupAdapterTest()
+    const generation = createAndGetGeneration()
+
+    const snapshot = withSuppressedConsoleError(() => document({
+        id: "id",
+        generation,
+        request: {kind: "get_document_snapshot"},
+    }))
+
+    assert.equal(snapshot.ok, false)
+    if (snapshot.ok) {
+        throw new Error("snapshot request should fail")
+    }
+    assert.equal(snapshot.error.kind, "operation_failed")
+    assert.equal(snapshot.error.operation, "get_document_snapshot")
+})
+
+test("reports sendable collaboration steps and dispatches received transactions", () => {
+    const createdEditors = setupAdapterTest()
+    const events: EditorEvent[] = []
+    const modules = getOrCreateBridgeBindings().modules
+    let version = 3
+    let unconfirmed: Array<{ toJSON(): unknown }> = []
+    const received: Array<{ steps: unknown[]; clientIDs: unknown[] }> = []
+    modules["@tiptap/pm/collab"] = {
+        collab: (config: unknown) => ({config}),
+        getVersion: () => version,
+        sendableSteps: () => unconfirmed.length === 0 ? null : {version, steps: unconfirmed, clientID: "alice"},
+        receiveTransaction: (_state: unknown, steps: unknown[], clientIDs: unknown[]) => {
+            received.push({steps, clientIDs})
+            return "receive-transaction"
+        },
+    }
+    modules["@tiptap/pm/transform"] = {
+        Step: {fromJSON: (_schema: unknown, json: unknown) => ({parsed: json})},
+    }
+
+    const result = createRuntime(
+        {...createRequest(), collab: {version: 3, client_id: "alice"}},
+        () => {
+        },
+        () => {
+        },
+        (event) => {
+            events.push(event)
+        },
+    )
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+
+    editor.emitTransaction()
+    assert.deepEqual(events, [])
+
+    unconfirmed = [{toJSON: () => ({stepType: "replace", from: 1})}]
+    editor.emitTransaction()
+    editor.emitTransaction()
+    assert.deepEqual(events, [{
+        kind: "collab_sendable",
+        steps: {version: 3, steps: [{stepType: "replace", from: 1}], client_id: "alice"},
+    }])
+
+    assert.deepEqual(document({id: "id", generation: result.value.generation, request: {kind: "get_collab_state"}}), {
+        ok: true,
+        value: {
+            kind: "collab_state",
+            version: 3,
+            sendable: {version: 3, steps: [{stepType: "replace", from: 1}], client_id: "alice"},
+        },
+    })
+
+    version = 5
+    const receiveResult = document({
+        id: "id",
+        generation: result.value.generation,
+        request: {kind: "receive_transaction", steps: [{stepType: "addMark"}, {stepType: "replace"}], client_ids: ["bob", "bob"]},
+    })
+    assert.deepEqual(receiveResult, {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(received, [{
+        steps: [{parsed: {stepType: "addMark"}}, {parsed: {stepType: "replace"}}],
+        clientIDs: ["bob", "bob"],
+    }])
+    assert.deepEqual(editor.dispatchedTransactions, ["receive-transaction"])
+    assert.deepEqual(events.at(-1), {
+        kind: "collab_sendable",
+        steps: {version: 5, steps: [{stepType: "replace", from: 1}], client_id: "alice"},
+    })
+})
+
+test("rejects collaboration requests for editors without collaboration", () => {
+    setupAdapterTest()
+    const generation = createAndGetGeneration()
+
+    const state = withSuppressedConsoleError(() => document({
+        id: "id",
+        generation,
+        request: {kind: "get_collab_state"},
+    }))
+
+    assert.equal(state.ok, false)
+    if (state.ok) {
+        throw new Error("collab state request should fail")
+    }
+    assert.equal(state.error.kind, "operation_failed")
+    assert.equal(state.error.operation, "get_collab_state")
+})
+
+test("binds collaboration documents and forwards their updates", () => {
+    const createdEditors = setupAdapterTest()
+    const events: EditorEvent[] = []
+    const applied: number[][] = []
+    let destroyed = false
+    let emitLocalUpdate: (update: number[]) => void = () => {
+    }
+    __testing.registerExtension({
+        name: "collaboration",
+        create: ({collaboration}) => {
+            if (collaboration == null) {
+                return []
+            }
+
+            emitLocalUpdate = collaboration.on_local_update
+            collaboration.bind({
+                apply_update: (update) => applied.push(update),
+                encode_state_vector: () => [collaboration.field.length],
+                encode_state_as_update: (stateVector) => [...(stateVector ?? []), ...(collaboration.initial_update ?? [])],
+                destroy: () => {
+                    destroyed = true
+                },
+            })
+            return {name: "collaboration"} as never
+        },
+    })
+
+    const result = createRuntime(
+        {
+            ...createRequest(),
+            extensions: ["document", "paragraph", "text", "collaboration"],
+            collaboration: {field: "body", initial_update: [1, 2]},
+        },
+        () => {
+        },
+        () => {
+        },
+        (event) => {
+            events.push(event)
+        },
+    )
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+    assert.equal(createdEditors[0]?.content, undefined)
+
+    emitLocalUpdate([7, 8])
+    assert.deepEqual(events, [{kind: "collaboration_update", update: [7, 8]}])
+
+    const {generation} = result.value
+    assert.deepEqual(
+        document({id: "id", generation, request: {kind: "apply_collaboration_update", update: [3]}}),
+        {ok: true, value: {kind: "empty"}},
+    )
+    assert.deepEqual(applied, [[3]])
+    assert.deepEqual(document({id: "id", generation, request: {kind: "get_collaboration_state_vector"}}), {
+        ok: true,
+        value: {kind: "collaboration_state_vector", state_vector: [4]},
+    })
+    assert.deepEqual(
+        document({id: "id", generation, request: {kind: "encode_collaboration_state", state_vector: [9]}}),
+        {ok: true, value: {kind: "collaboration_update", update: [9, 1, 2]}},
+    )
+
+    destroy("id")
+    assert.equal(destroyed, true)
+})
+
+test("rejects collaboration documents without the collaboration extension", () => {
+    setupAdapterTest()
+
+    const result = withSuppressedConsoleError(() => createRuntime(
+        {...createRequest(), collaboration: {field: "default"}},
+        () => {
+        },
+        () => {
+        },
+    ))
+
+    assert.equal(result.ok, false)
+    if (result.ok) {
+        throw new Error("create should fail")
+    }
+    assert.equal(result.error.kind, "extension_unavailable")
+    assert.equal(__testing.getSlotCount(), 0)
+})
+
+test("reports local selection changes only when requested", () => {
+    const createdEditors = setupAdapterTest()
+    const events: EditorEvent[] = []
+
+    const result = createRuntime(
+        {...createRequest(), local_selection: true},
+        () => {
+        },
+        () => {
+        },
+        (event) => {
+            events.push(event)
+        },
+    )
+    assert.equal(result.ok, true)
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+
+    editor.emitTransaction()
+    assert.deepEqual(events, [])
+
+    editor.state.selection = {from: 2, to: 4, anchor: 4, head: 2}
+    editor.emitTransaction()
+    editor.emitTransaction()
+    assert.deepEqual(events, [{kind: "local_selection", selection: {anchor: 4, head: 2}}])
+})
+
+test("dispatches remote cursors as transaction metadata", () => {
+    const createdEditors = setupAdapterTest()
+
+    const result = createRuntime(createRequest(), () => {
+    }, () => {
+    })
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+
+    const meta = new Map<string, unknown>()
+    const transaction = {
+        setMeta(key: string, value: unknown) {
+            meta.set(key, value)
+            return transaction
+        },
+    }
+    Object.assign(editor.state, {tr: transaction})
+
+    const cursors = [{user: {id: "bob", name: "Bob", color: "#ff0000"}, selection: {anchor: 1, head: 3}}]
+    assert.deepEqual(
+        command({id: "id", generation: result.value.generation, command: {kind: "set_remote_cursors", cursors}}),
+        {ok: true, value: {kind: "empty"}},
+    )
+    assert.deepEqual(editor.dispatchedTransactions, [transaction])
+    assert.deepEqual(meta.get("leptosTiptapRemoteCursors"), cursors)
+    assert.equal(meta.get("addToHistory"), false)
+})
+
+test("reads the text content of the document", () => {
+    setupAdapterTest()
+
+    const result = createRuntime(createRequest(), () => {
+    }, () => {
+    })
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    assert.deepEqual(document({id: "id", generation: result.value.generation, request: {kind: "get_text"}}), {
+        ok: true,
+        value: {kind: "text", text: "fake"},
+    })
+})
+
+test("creates custom extensions and dispatches their commands with a payload", () => {
+    const createdEditors = setupAdapterTest()
+    let receivedModules: Record<string, unknown> | undefined
+    let mentionActive = false
+
+    const mention = {
+        create: ({modules}: { modules: Record<string, unknown> }) => {
+            receivedModules = modules
+            return {name: "mention"} as never
+        },
+        commands: {
+            insert_mention: (_editor: Editor, payload: unknown) => ({inserted: payload}),
+            clear_mentions: () => undefined,
+        },
+        active_keys: ["mention"],
+        active_state: () => ({mention: mentionActive}),
+    }
+
+    const selections: SelectionState[] = []
+    const result = createRuntime(
+        {...createRequest(), custom_extensions: [{name: "mention", descriptor: mention}]},
+        () => {
+        },
+        (selectionState) => {
+            selections.push(selectionState)
+        },
+    )
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    assert.notEqual(receivedModules?.["@tiptap/core"], undefined)
+    assert.deepEqual(
+        createdEditors[0]?.extensions?.map((extension) => (extension as {name?: string}).name).slice(-5),
+        [
+            "mention",
+            "leptosTiptapRemoteCursors",
+            "leptosTiptapDecorations",
+            "leptosTiptapSearch",
+            "leptosTiptapImageUploads",
+        ],
+    )
+    assert.deepEqual(result.value.selection_state.custom, {mention: false})
+
+    mentionActive = true
+    createdEditors[0]?.emitTransaction()
+    assert.deepEqual(selections.at(-1)?.custom, {mention: true})
+
+    const {generation} = result.value
+    assert.deepEqual(
+        document({id: "id", generation, request: {kind: "custom_command", name: "insert_mention", payload: {id: 7}}}),
+        {ok: true, value: {kind: "custom_command", value: {inserted: {id: 7}}}},
+    )
+    assert.deepEqual(
+        document({id: "id", generation, request: {kind: "custom_command", name: "clear_mentions", payload: null}}),
+        {ok: true, value: {kind: "custom_command", value: null}},
+    )
+
+    const missing = withSuppressedConsoleError(() =>
+        document({id: "id", generation, request: {kind: "custom_command", name: "unknown", payload: null}}),
+    )
+    assert.equal(missing.ok, false)
+    if (!missing.ok) {
+        assert.equal(missing.error.kind, "extension_unavailable")
+        assert.equal(missing.error.operation, "unknown")
+    }
+})
+
+test("rejects invalid and conflicting custom extension descriptors", () => {
+    const createdEditors = setupAdapterTest()
+    const createWith = (custom_extensions: CreateRequest["custom_extensions"]) =>
+        withSuppressedConsoleError(() =>
+            createRuntime({...createRequest(), custom_extensions}, () => {
+            }, () => {
+            }),
+        )
+    const assertRejected = (result: BridgeResult<ReadyPayload>, message: RegExp) => {
+        assert.equal(result.ok, false)
+        if (!result.ok) {
+            assert.equal(result.error.kind, "extension_registration_failed")
+            assert.match(result.error.message, message)
+        }
+    }
+
+    assertRejected(createWith([{name: "broken", descriptor: {}}]), /does not provide a create function/)
+    assertRejected(
+        createWith([{name: "mention", descriptor: {name: "other", create: () => []}}]),
+        /provides a descriptor named "other"/,
+    )
+    assertRejected(
+        createWith([
+            {name: "a", descriptor: {create: () => [], commands: {run: () => null}}},
+            {name: "b", descriptor: {create: () => [], commands: {run: () => null}}},
+        ]),
+        /multiple selected custom extensions handle command "run"/,
+    )
+    assert.equal(createdEditors.length, 0)
+})
+
+test("creates node and mark specs with generated commands and active states", () => {
+    const createdEditors = setupAdapterTest()
+    const selections: SelectionState[] = []
+    const result = createRuntime(
+        {
+            ...createRequest(),
+            node_specs: [
+                {
+                    name: "callout",
+                    group: "block",
+                    content: "paragraph+",
+                    inline: false,
+                    atom: false,
+                    attributes: {kind: "info"},
+                    parse_html: ["div.callout"],
+                    render_html: {tag: "div", attributes: {class: "callout"}},
+                },
+                {name: "divider", group: "block", inline: false, atom: true, attributes: {}, parse_html: []},
+            ],
+            mark_specs: [{name: "badge", attributes: {}, parse_html: []}],
+        },
+        () => {
+        },
+        (selectionState) => {
+            selections.push(selectionState)
+        },
+    )
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("expected an editor")
+    }
+    type SpecExtension = {
+        name: string
+        config: {
+            parseHTML: () => unknown
+            renderHTML: (props: { HTMLAttributes: Record<string, unknown> }) => unknown
+        }
+    }
+    const specExtensions = (editor.extensions as SpecExtension[] | undefined)?.filter(({name}) =>
+        ["callout", "divider", "badge"].includes(name),
+    ) ?? []
+    assert.deepEqual(specExtensions.map(({name}) => name), ["callout", "divider", "badge"])
+    const [callout, divider, badge] = specExtensions
+    assert.deepEqual(callout?.config.parseHTML(), [{tag: "div.callout"}])
+    assert.deepEqual(
+        callout?.config.renderHTML({HTMLAttributes: {kind: "warning"}}),
+        ["div", {class: "callout", kind: "warning"}, 0],
+    )
+    assert.deepEqual(divider?.config.renderHTML({HTMLAttributes: {}}), ["div", {"data-type": "divider"}])
+    assert.deepEqual(badge?.config.parseHTML(), [{tag: "span[data-type=\"badge\"]"}])
+
+    assert.deepEqual(result.value.selection_state.custom, {callout: false, divider: false, badge: false})
+    editor.activeStates.badge = true
+    editor.emitTransaction()
+    assert.deepEqual(selections.at(-1)?.custom, {callout: false, divider: false, badge: true})
+
+    const {generation} = result.value
+    const runSpec = (action: "set" | "toggle" | "unset", name: string, attributes?: Record<string, unknown>) => {
+        editor.chainCalls = []
+        return command({id: "id", generation, command: {kind: "spec_command", action, name, attributes}})
+    }
+
+    assert.deepEqual(runSpec("toggle", "callout", {kind: "warning"}), {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(editor.chainCalls, [
+        {name: "focus", args: []},
+        {name: "toggleWrap", args: ["callout", {kind: "warning"}]},
+    ])
+    assert.deepEqual(runSpec("set", "divider"), {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(editor.chainCalls, [
+        {name: "focus", args: []},
+        {name: "insertContent", args: [{type: "divider", attrs: undefined}]},
+    ])
+    assert.deepEqual(runSpec("unset", "badge"), {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(editor.chainCalls, [{name: "focus", args: []}, {name: "unsetMark", args: ["badge"]}])
+
+    const missing = withSuppressedConsoleError(() => runSpec("toggle", "divider"))
+    assert.equal(missing.ok, false)
+    if (!missing.ok) {
+        assert.equal(missing.error.kind, "extension_unavailable")
+        assert.equal(missing.error.operation, "toggle_divider")
+    }
+})
+
+test("rejects node and mark specs sharing a name", () => {
+    setupAdapterTest()
+
+    const result = withSuppressedConsoleError(() =>
+        createRuntime(
+            {
+                ...createRequest(),
+                node_specs: [{name: "badge", inline: true, atom: true, attributes: {}, parse_html: []}],
+                mark_specs: [{name: "badge", attributes: {}, parse_html: []}],
+            },
+            () => {
+            },
+            () => {
+            },
+        ),
+    )
+    assert.equal(result.ok, false)
+    if (!result.ok) {
+        assert.equal(result.error.kind, "extension_registration_failed")
+        assert.match(result.error.message, /multiple node or mark specs are named "badge"/)
+    }
+})
+
+test("mounts node views for node specs and updates their attributes", () => {
+    const createdEditors = setupAdapterTest()
+    const events: Array<{ event: unknown; dom?: unknown; contentDom?: unknown }> = []
+    const result = createRuntime(
+        {
+            ...createRequest(),
+            node_specs: [{name: "poll", group: "block", atom: true, attributes: {votes: 0}, parse_html: []}],
+            node_views: ["poll"],
+        },
+        () => {
+        },
+        () => {
+        },
+        () => {
+        },
+        (event, dom, contentDom) => {
+            events.push({event, dom, contentDom})
+        },
+    )
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("expected an editor")
+    }
+    type FakeElement = {
+        tag: string
+        attributes: Record<string, string>
+        classes: Set<string>
+        setAttribute: (name: string, value: string) => void
+        contains: (target: unknown) => boolean
+        classList: { add: (name: string) => void; remove: (name: string) => void }
+    }
+    const createElement = (tag: string): FakeElement => {
+        const element: FakeElement = {
+            tag,
+            attributes: {},
+            classes: new Set(),
+            setAttribute: (name, value) => {
+                element.attributes[name] = value
+            },
+            contains: (target) => target === element,
+            classList: {
+                add: (name) => element.classes.add(name),
+                remove: (name) => element.classes.delete(name),
+            },
+        }
+        return element
+    }
+    type NodeViewExtension = {
+        config: {
+            addNodeView: () => (props: unknown) => {
+                dom: FakeElement
+                contentDOM: FakeElement | null
+                update: (node: unknown) => boolean
+                selectNode: () => void
+                destroy: () => void
+            }
+        }
+    }
+    const poll = editor.extensions?.find(({name}) => name === "poll") as unknown as NodeViewExtension
+    const pollType = {name: "poll"}
+    const view = poll.config.addNodeView()({
+        editor: {view: {dom: {ownerDocument: {createElement}}}},
+        node: {type: pollType, attrs: {votes: 0}},
+        getPos: () => 3,
+        HTMLAttributes: {votes: 0},
+        decorations: [],
+        extension: poll,
+    })
+
+    const viewId = (events[0]?.event as { view_id: number }).view_id
+    assert.deepEqual(view.dom.attributes, {"data-type": "poll", votes: "0", "data-node-view": "poll"})
+    assert.equal(view.contentDOM, null)
+    assert.deepEqual(events, [{
+        event: {kind: "mount", view_id: viewId, name: "poll", attributes: {votes: 0}, selected: false},
+        dom: view.dom,
+        contentDom: null,
+    }])
+
+    const transactions: unknown[][] = []
+    Object.assign(editor.state, {
+        tr: {
+            setNodeMarkup: (...args: unknown[]) => {
+                transactions.push(args)
+                return {kind: "transaction"}
+            },
+        },
+    })
+    const updateAttributes = () => command({
+        id: "id",
+        generation: result.value.generation,
+        command: {kind: "update_node_view_attributes", view_id: viewId, attributes: {votes: 1}},
+    })
+    assert.deepEqual(updateAttributes(), {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(transactions, [[3, undefined, {votes: 1}]])
+    assert.deepEqual(editor.dispatchedTransactions, [{kind: "transaction"}])
+
+    events.length = 0
+    assert.equal(view.update({type: pollType, attrs: {votes: 1}}), true)
+    assert.equal(view.update({type: pollType, attrs: {votes: 1}}), true)
+    assert.equal(view.update({type: {name: "paragraph"}, attrs: {}}), false)
+    view.selectNode()
+    view.destroy()
+    assert.deepEqual(events.map(({event}) => event), [
+        {kind: "update", view_id: viewId, attributes: {votes: 1}},
+        {kind: "select", view_id: viewId, selected: true},
+        {kind: "destroy", view_id: viewId},
+    ])
+    assert.equal(view.dom.classes.has("ProseMirror-selectednode"), true)
+
+    const destroyed = withSuppressedConsoleError(updateAttributes)
+    assert.equal(destroyed.ok, false)
+    if (!destroyed.ok) {
+        assert.equal(destroyed.error.kind, "extension_unavailable")
+        assert.equal(destroyed.error.operation, "update_node_view_attributes")
+    }
+})
+
+test("rejects node views without a node spec", () => {
+    setupAdapterTest()
+
+    const result = withSuppressedConsoleError(() =>
+        createRuntime(
+            {...createRequest(), node_views: ["poll"]},
+            () => {
+            },
+            () => {
+            },
+        ),
+    )
+    assert.equal(result.ok, false)
+    if (!result.ok) {
+        assert.equal(result.error.kind, "extension_registration_failed")
+        assert.match(result.error.message, /node view "poll" has no node spec/)
+    }
+})
+
+test("binds shortcuts to the handled event callback with their priority", () => {
+    const createdEditors = setupAdapterTest()
+    const handled: unknown[] = []
+    const result = createRuntime(
+        {...createRequest(), shortcuts: {priority: 1000, keys: ["Mod-s", "Mod-k"]}},
+        () => {
+        },
+        () => {
+        },
+        () => {
+        },
+        () => {
+        },
+        (event) => {
+            handled.push(event)
+            return event.kind === "shortcut" && event.keys === "Mod-s"
+        },
+    )
+    assert.equal(result.ok, true)
+
+    type ShortcutExtension = {
+        name: string
+        config: { priority: number; addKeyboardShortcuts: () => Record<string, () => boolean> }
+    }
+    const extension = createdEditors[0]?.extensions?.find(({name}) => name === "leptosShortcuts") as unknown as ShortcutExtension
+    assert.equal(extension.name, "leptosShortcuts")
+    assert.equal(extension.config.priority, 1000)
+
+    const bindings = extension.config.addKeyboardShortcuts()
+    assert.deepEqual(Object.keys(bindings), ["Mod-s", "Mod-k"])
+    assert.equal(bindings["Mod-s"]?.(), true)
+    assert.equal(bindings["Mod-k"]?.(), false)
+    assert.deepEqual(handled, [{kind: "shortcut", keys: "Mod-s"}, {kind: "shortcut", keys: "Mod-k"}])
+})
+
+test("passes pastes and drops to the handled event callback", () => {
+    const createdEditors = setupAdapterTest()
+    const handled: HandledEvent[] = []
+    const result = createRuntime(
+        {...createRequest(), transfer_handlers: {paste: true, drop: true}},
+        () => {
+        },
+        () => {
+        },
+        () => {
+        },
+        () => {
+        },
+        (event) => {
+            handled.push(event)
+            return event.kind === "paste"
+        },
+    )
+    assert.equal(result.ok, true)
+
+    type TransferProps = {
+        handlePaste: (view: unknown, event: unknown) => boolean
+        handleDrop: (view: unknown, event: unknown, slice: unknown, moved: boolean) => boolean
+    }
+    type TransferExtension = {
+        config: { priority: number; addProseMirrorPlugins: () => Array<{ props: TransferProps }> }
+    }
+    const extension = createdEditors[0]?.extensions?.find(
+        ({name}) => name === "leptosTransferHandlers",
+    ) as unknown as TransferExtension
+    assert.equal(extension.config.priority, 1000)
+
+    const [plugin] = extension.config.addProseMirrorPlugins()
+    const file = {name: "scan.png"}
+    const transfer = (formats: Record<string, string>, files: unknown[] = []) => ({
+        getData: (format: string) => formats[format] ?? "",
+        files,
+    })
+    const view = {posAtCoords: ({left, top}: { left: number; top: number }) => ({pos: left + top})}
+
+    assert.equal(
+        plugin?.props.handlePaste(view, {clipboardData: transfer({"text/html": "<b>Hi</b>", "text/plain": "Hi"})}),
+        true,
+    )
+    assert.equal(
+        plugin?.props.handleDrop(view, {dataTransfer: transfer({}, [file]), clientX: 3, clientY: 4}, null, false),
+        false,
+    )
+    assert.deepEqual(handled, [
+        {kind: "paste", transfer: {html: "<b>Hi</b>", text: "Hi", files: []}},
+        {kind: "drop", transfer: {html: null, text: null, files: [{name: "scan.png", file}]}, position: 7, moved: false},
+    ])
+})
+
+test("creates input and paste rules declared in Rust", () => {
+    const createdEditors = setupAdapterTest()
+    const result = createRuntime(
+        {
+            ...createRequest(),
+            input_rules: [
+                {find: "-->$", action: {kind: "replace", text: "→"}},
+                {find: "^>\\s$", action: {kind: "wrap_in", name: "blockquote"}},
+            ],
+            paste_rules: [{find: "==(.+?)==", action: {kind: "mark", name: "highlight", attributes: {color: "yellow"}}}],
+        },
+        () => {
+        },
+        () => {
+        },
+    )
+    assert.equal(result.ok, true)
+
+    type RulesExtension = {
+        name: string
+        config: {
+            addInputRules: (this: unknown) => Array<{ find: RegExp }>
+            addPasteRules: (this: unknown) => Array<{ find: RegExp }>
+        }
+    }
+    const extension = createdEditors[0]?.extensions?.find(({name}) => name === "leptosRules") as unknown as RulesExtension
+    assert.equal(extension.name, "leptosRules")
+
+    const context = (schema: { nodes: Record<string, unknown>; marks: Record<string, unknown> }) => ({editor: {schema}})
+    const schema = {nodes: {blockquote: {name: "blockquote"}}, marks: {highlight: {name: "highlight"}}}
+    assert.deepEqual(
+        extension.config.addInputRules.call(context(schema)).map(({find}) => find),
+        [/-->$/, /^>\s$/],
+    )
+    assert.deepEqual(extension.config.addPasteRules.call(context(schema)).map(({find}) => find), [/==(.+?)==/g])
+    assert.throws(
+        () => extension.config.addPasteRules.call(context({nodes: {}, marks: {}})),
+        /uses the mark "highlight", which is not in the schema/,
+    )
+})
+
+test("rejects rules with invalid patterns", () => {
+    setupAdapterTest()
+
+    const result = withSuppressedConsoleError(() =>
+        createRuntime(
+            {...createRequest(), input_rules: [{find: "(", action: {kind: "replace", text: ""}}]},
+            () => {
+            },
+            () => {
+            },
+        ),
+    )
+    assert.equal(result.ok, false)
+    if (!result.ok) {
+        assert.equal(result.error.kind, "extension_registration_failed")
+        assert.match(result.error.message, /extension "rules" failed to initialize/)
+    }
+})
+
+test("dispatches decoration layers and reports clicks on decorations", () => {
+    const createdEditors = setupAdapterTest()
+    const events: EditorEvent[] = []
+    const result = createRuntime(
+        {...createRequest(), decoration_clicks: true},
+        () => {
+        },
+        () => {
+        },
+        (event) => events.push(event),
+    )
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+    const meta = new Map<string, unknown>()
+    const transaction = {
+        setMeta(key: string, value: unknown) {
+            meta.set(key, value)
+            return transaction
+        },
+    }
+    Object.assign(editor.state, {tr: transaction})
+
+    const decorations = [{id: "typo", target: {kind: "inline" as const, from: 1, to: 4}, attributes: {}}]
+    assert.deepEqual(
+        command({
+            id: "id",
+            generation: result.value.generation,
+            command: {kind: "set_decorations", layer_id: "spelling", decorations},
+        }),
+        {ok: true, value: {kind: "empty"}},
+    )
+    assert.deepEqual(editor.dispatchedTransactions, [transaction])
+    assert.deepEqual(meta.get("leptosTiptapDecorations"), {layer_id: "spelling", decorations})
+    assert.equal(meta.get("addToHistory"), false)
+
+    type DecorationsExtension = {
+        config: {
+            addProseMirrorPlugins: () => Array<{
+                props: { handleDOMEvents: { click: (view: unknown, event: unknown) => boolean } }
+            }>
+        }
+    }
+    const extension = editor.extensions?.find(
+        ({name}) => name === "leptosTiptapDecorations",
+    ) as unknown as DecorationsExtension
+    const [plugin] = extension.config.addProseMirrorPlugins()
+    const decorated = {getAttribute: (name: string) => name === "data-decoration-layer" ? "spelling" : "typo"}
+    const view = {dom: {contains: (element: unknown) => element === decorated}}
+    const click = (closest: unknown) => plugin?.props.handleDOMEvents.click(view, {target: {closest: () => closest}})
+
+    assert.equal(click(decorated), false)
+    assert.equal(click(null), false)
+    assert.deepEqual(events, [{kind: "decoration_click", click: {layer_id: "spelling", decoration_id: "typo"}}])
+})
+
+test("shows diffs in a separate read-only view without touching the document", () => {
+    const createdEditors = setupAdapterTest()
+    const changes: unknown[] = []
+    const result = createRuntime(createRequest(), () => {
+        changes.push("change")
+    }, () => {
+    })
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+    const schema = new Schema({
+        nodes: {
+            doc: {content: "block+"},
+            paragraph: {group: "block", content: "inline*"},
+            text: {group: "inline"},
+            mention: {group: "inline", inline: true, atom: true},
+        },
+    })
+    const placed: unknown[] = []
+    const editorDom = {
+        style: {display: "block"},
+        after: (dom: unknown) => placed.push(dom),
+    }
+    Object.assign(editor, {schema, view: {...editor.view, dom: editorDom}})
+
+    type DiffViewProps = {
+        state: EditorState
+        editable: () => boolean
+        decorations: () => {
+            find: () => Array<{ from: number; to: number; spec: Record<string, unknown> }>
+        }
+    }
+    const diffViews: Array<{ props: DiffViewProps; destroyed: boolean; dom: unknown }> = []
+    const viewModule = getOrCreateBridgeBindings().modules["@tiptap/pm/view"] as Record<string, unknown>
+    getOrCreateBridgeBindings().modules["@tiptap/pm/view"] = {
+        ...viewModule,
+        EditorView: class {
+            dom = {}
+            destroyed = false
+
+            constructor(place: (dom: unknown) => void, public props: DiffViewProps) {
+                place(this.dom)
+                diffViews.push(this)
+            }
+
+            setProps(props: DiffViewProps) {
+                this.props = props
+            }
+
+            destroy() {
+                this.destroyed = true
+            }
+        },
+    }
+
+    const document = {
+        type: "doc",
+        content: [
+            {type: "paragraph", content: [{type: "text", text: "Gone"}]},
+            {
+                type: "paragraph",
+                content: [{type: "text", text: "Hello "}, {type: "mention"}, {type: "text", text: "new world"}],
+            },
+        ],
+    }
+    assert.deepEqual(
+        command({
+            id: "id",
+            generation: result.value.generation,
+            command: {
+                kind: "show_diff",
+                document,
+                highlights: [
+                    {kind: "block", block: 0, change: "deleted"},
+                    {kind: "text", block: 1, from: 10, to: 15, change: "inserted"},
+                    {kind: "deleted_text", block: 1, offset: 15, text: " here"},
+                    {kind: "block", block: 2, change: "inserted"},
+                ],
+            },
+        }),
+        {ok: true, value: {kind: "empty"}},
+    )
+
+    const [view] = diffViews
+    if (view == null) {
+        throw new Error("diff view should have been created")
+    }
+    assert.deepEqual(placed, [view.dom])
+    assert.deepEqual(view.props.state.doc.toJSON(), document)
+    assert.equal(view.props.editable(), false)
+    assert.deepEqual(
+        view.props.decorations().find().map(({from, to}) => ({from, to})),
+        [{from: 0, to: 6}, {from: 18, to: 23}, {from: 23, to: 23}],
+    )
+    assert.equal(editorDom.style.display, "none")
+    assert.equal(editor.editable, false)
+    assert.deepEqual(editor.chainCalls, [])
+    assert.deepEqual(editor.dispatchedTransactions, [])
+    assert.deepEqual(changes, [])
+
+    // Editability requested while the diff is shown applies once it is hidden.
+    for (const editorCommand of [{kind: "set_editable", editable: true}, {kind: "hide_diff"}] as const) {
+        assert.deepEqual(
+            command({id: "id", generation: result.value.generation, command: editorCommand}),
+            {ok: true, value: {kind: "empty"}},
+        )
+        assert.equal(editor.editable, editorCommand.kind === "hide_diff")
+    }
+    assert.equal(view.destroyed, true)
+    assert.equal(editorDom.style.display, "block")
+    assert.deepEqual(editor.dispatchedTransactions, [])
+    assert.deepEqual(changes, [])
+})
+
+test("restores selections with their direction and within the document", () => {
+    const schema = new Schema({
+        nodes: {
+            doc: {content: "block+"},
+            paragraph: {group: "block", content: "inline*"},
+            text: {group: "inline"},
+        },
+    })
+    const state = EditorState.create({
+        doc: schema.node("doc", null, [schema.node("paragraph", null, [schema.text("Hello world")])]),
+    })
+    const select = (anchor: number, head: number) => {
+        const {selection} = state.apply(__testing.selectionTransaction(state, anchor, head))
+        return {anchor: selection.anchor, head: selection.head}
+    }
+
+    assert.deepEqual(select(9, 2), {anchor: 9, head: 2})
+    assert.deepEqual(select(2, 9), {anchor: 2, head: 9})
+    assert.deepEqual(select(40, 0), {anchor: 12, head: 1})
+})
+
+test("finds search matches within textblocks with the requested options", () => {
+    const schema = new Schema({
+        nodes: {
+            doc: {content: "block+"},
+            paragraph: {group: "block", content: "inline*"},
+            text: {group: "inline"},
+            mention: {group: "inline", inline: true, atom: true},
+        },
+    })
+    const doc = schema.node("doc", null, [
+        schema.node("paragraph", null, [schema.text("Color and colour")]),
+        schema.node("paragraph", null, [schema.node("mention"), schema.text(" colorful color")]),
+    ])
+    const find = (query: string, options: { case_sensitive?: boolean; whole_word?: boolean; regex?: boolean }) => {
+        const pattern = __testing.createSearchPattern(query, {
+            case_sensitive: false,
+            whole_word: false,
+            regex: false,
+            ...options,
+        })
+        return pattern == null
+            ? []
+            : __testing.findSearchMatches(doc, pattern).map(({from, to, captures}) => ({from, to, captures}))
+    }
+
+    assert.deepEqual(find("color", {}), [
+        {from: 1, to: 6, captures: []},
+        {from: 21, to: 26, captures: []},
+        {from: 30, to: 35, captures: []},
+    ])
+    assert.deepEqual(find("color", {case_sensitive: true, whole_word: true}), [{from: 30, to: 35, captures: []}])
+    assert.deepEqual(find("colo(u?)r", {regex: true, whole_word: true}), [
+        {from: 1, to: 6, captures: [""]},
+        {from: 11, to: 17, captures: ["u"]},
+        {from: 30, to: 35, captures: [""]},
+    ])
+    assert.deepEqual(find("c.lor", {}), [])
+    assert.deepEqual(find("", {}), [])
+    assert.throws(() => __testing.createSearchPattern("(", {case_sensitive: false, whole_word: false, regex: true}))
+})
+
+test("dispatches image upload placeholders as transaction metadata", () => {
+    const createdEditors = setupAdapterTest()
+
+    const result = createRuntime(createRequest(), () => {
+    }, () => {
+    })
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+
+    const metas: Array<Map<string, unknown>> = []
+    let deletedSelection = false
+    Object.defineProperty(editor.state, "tr", {
+        configurable: true,
+        get: () => {
+            const meta = new Map<string, unknown>()
+            metas.push(meta)
+            const transaction = {
+                selection: {from: 3},
+                doc: {content: {size: 10}},
+                deleteSelection() {
+                    deletedSelection = true
+                    return transaction
+                },
+                setMeta(key: string, value: unknown) {
+                    meta.set(key, value)
+                    return transaction
+                },
+            }
+            return transaction
+        },
+    })
+    const run = (uploadCommand: EditorCommand) =>
+        command({id: "id", generation: result.value.generation, command: uploadCommand})
+
+    assert.deepEqual(run({kind: "start_image_upload", upload_id: 1}), {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(run({kind: "start_image_upload", upload_id: 2, position: 42}), {ok: true, value: {kind: "empty"}})
+    assert.deepEqual(run({kind: "set_image_upload_progress", upload_id: 1, progress: 1.5}), {
+        ok: true,
+        value: {kind: "empty"},
+    })
+    assert.deepEqual(run({kind: "cancel_image_upload", upload_id: 2}), {ok: true, value: {kind: "empty"}})
+
+    assert.equal(deletedSelection, true)
+    assert.deepEqual(metas.map((meta) => meta.get("leptosTiptapImageUploads")), [
+        {kind: "start", upload_id: 1, position: 3},
+        {kind: "start", upload_id: 2, position: 10},
+        {kind: "progress", upload_id: 1, progress: 1},
+        {kind: "remove", upload_id: 2},
+    ])
+    assert.deepEqual(metas.map((meta) => meta.get("addToHistory")), [undefined, undefined, false, false])
+})
+
+test("reports focus changes as editor events", () => {
+    const createdEditors = setupAdapterTest()
+    const events: EditorEvent[] = []
+
+    const result = createRuntime(
+        createRequest(),
+        () => {
+        },
+        () => {
+        },
+        (event) => events.push(event),
+    )
+    assert.equal(result.ok, true)
+
+    createdEditors[0]?.emitFocus()
+    createdEditors[0]?.emitBlur()
+    assert.deepEqual(events, [
+        {kind: "focus_change", focused: true},
+        {kind: "focus_change", focused: false},
+    ])
+})
+
+test("reports undo and redo depth changes as editor events", () => {
+    const createdEditors = setupAdapterTest()
+    const events: EditorEvent[] = []
+
+    const result = createRuntime(
+        createRequest(),
+        () => {
+        },
+        () => {
+        },
+        (event) => events.push(event),
+    )
+    assert.equal(result.ok, true)
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+    editor.emitTransaction()
+    // The state field of ProseMirror's history plugin.
+    Object.assign(editor.state, {"history$": {done: {eventCount: 2}, undone: {eventCount: 1}}})
+    editor.emitTransaction()
+    editor.emitTransaction()
+
+    assert.deepEqual(events, [
+        {kind: "history_change", undo_depth: 2, redo_depth: 1},
+    ])
+})
+
+test("reports transactions with their meta and origin when requested", () => {
+    const createdEditors = setupAdapterTest()
+    const events: EditorEvent[] = []
+
+    const result = createRuntime(
+        {...createRequest(), transactions: true},
+        () => {
+        },
+        () => {
+        },
+        (event) => events.push(event),
+    )
+    assert.equal(result.ok, true)
+    if (!result.ok) {
+        throw new Error("create should succeed")
+    }
+
+    const editor = createdEditors[0]
+    if (editor == null) {
+        throw new Error("editor should have been created")
+    }
+    const inputRules = {spec: {isInputRules: true}}
+    Object.assign(editor.state, {plugins: [{spec: {}}, inputRules]})
+    const fakeTransaction = (docChanged: boolean, meta: Map<unknown, unknown>) => {
+        const transaction = {
+            docChanged,
+            getMeta: (key: unknown) => meta.get(key),
+            setMeta(key: string, value: unknown) {
+                meta.set(key, value)
+                return transaction
+            },
+        }
+        return transaction
+    }
+
+    assert.deepEqual(
+        command({id: "id", generation: result.value.generation, command: {kind: "set_meta", key: "source", value: 1}}),
+        {ok: true, value: {kind: "empty"}},
+    )
+    editor.emitTransaction(fakeTransaction(true, new Map<unknown, unknown>([
+        ["source", "sync"],
+        ["other", true],
+        ["uiEvent", "paste"],
+        ["addToHistory", false],
+    ])))
+
+    editor.state.selection = {from: 2, to: 4, anchor: 2, head: 4}
+    editor.emitTransaction(fakeTransaction(true, new Map([[inputRules, {}]])))
+
+    Object.assign(editor.state, {tr: fakeTransaction(false, new Map())})
+    command({id: "id", generation: result.value.generation, command: {kind: "clear_search"}})
+
+    assert.deepEqual(events, [
+        {
+            kind: "transaction",
+            transaction: {doc_changed: true, selection: null, meta: {source: "sync"}, add_to_history: false, origin: "paste"},
+        },
+        {
+            kind: "transaction",
+            transaction: {
+                doc_changed: true,
+                selection: {anchor: 2, head: 4},
+                meta: {},
+                add_to_history: true,
+                origin: "input_rule",
+            },
+        },
+        {
+            kind: "transaction",
+            transaction: {doc_changed: false, selection: null, meta: {}, add_to_history: false, origin: "api"},
+        },
+    ])
+})
+
+test("tracks insertions and deletions and resolves them by id", () => {
+    const changeMark = {
+        inclusive: false,
+        attrs: {id: {default: null}, author: {default: ""}, timestamp: {default: 0}},
+    }
+    const schema = new Schema({
+        nodes: {
+            doc: {content: "block+"},
+            paragraph: {group: "block", content: "inline*"},
+            text: {group: "inline"},
+        },
+        marks: {insertion: changeMark, deletion: changeMark},
+    })
+    const {collectTrackedChanges, createTrackChangesPlugin, resolveChanges, trackChangesKey} = trackChangesTesting
+    let state = EditorState.create({
+        doc: schema.node("doc", null, [schema.node("paragraph", null, [schema.text("one two")])]),
+        plugins: [createTrackChangesPlugin(() => {})],
+    })
+    state = state.apply(state.tr.setMeta(trackChangesKey, {kind: "configure", author: "ada"}))
+
+    state = state.applyTransaction(state.tr.insertText("new ", 1)).state
+    state = state.applyTransaction(state.tr.delete(9, 12)).state
+
+    const changes = collectTrackedChanges(state.doc)
+    assert.equal(state.doc.textContent, "new one two")
+    assert.deepEqual(
+        changes.map(({kind, author, range, text}) => ({kind, author, range, text})),
+        [
+            {kind: "insertion", author: "ada", range: {from: 1, to: 5}, text: "new "},
+            {kind: "deletion", author: "ada", range: {from: 9, to: 12}, text: "two"},
+        ],
+    )
+
+    state = state.applyTransaction(state.tr.delete(1, 5)).state
+    assert.equal(state.doc.textContent, "one two")
+
+    const deletion = changes[1]!.id
+    assert.equal(resolveChanges(state, new Set(["unknown"]), true), null)
+    state = state.apply(resolveChanges(state, new Set([deletion]), true)!)
+    assert.equal(state.doc.textContent, "one ")
+    assert.deepEqual(collectTrackedChanges(state.doc), [])
+})
+
+test("tracks edits over a select-all but not replacements through set_content", () => {
+    const changeMark = {
+        inclusive: false,
+        attrs: {id: {default: null}, author: {default: ""}, timestamp: {default: 0}},
+    }
+    const schema = new Schema({
+        nodes: {
+            doc: {content: "block+"},
+            paragraph: {group: "block", content: "inline*"},
+            text: {group: "inline"},
+        },
+        marks: {insertion: changeMark, deletion: changeMark},
+    })
+    const {collectTrackedChanges, createTrackChangesPlugin, trackChangesKey} = trackChangesTesting
+    const tracked = () => {
+        const state = EditorState.create({
+            doc: schema.node("doc", null, [schema.node("paragraph", null, [schema.text("one two")])]),
+            plugins: [createTrackChangesPlugin(() => {})],
+        })
+        return state.apply(state.tr.setMeta(trackChangesKey, {kind: "configure", author: "ada"}))
+    }
+    const summary = (state: EditorState) =>
+        collectTrackedChanges(state.doc).map(({kind, author, text}) => ({kind, author, text}))
+
+    let state = tracked()
+    state = state.applyTransaction(state.tr.setSelection(new AllSelection(state.doc)).insertText("new")).state
+    assert.equal(state.doc.textContent, "one twonew")
+    assert.deepEqual(summary(state), [
+        {kind: "deletion", author: "ada", text: "one two"},
+        {kind: "insertion", author: "ada", text: "new"},
+    ])
+
+    state = tracked()
+    state = state.applyTransaction(state.tr.setSelection(new AllSelection(state.doc)).deleteSelection()).state
+    assert.equal(state.doc.textContent, "one two")
+    assert.deepEqual(summary(state), [{kind: "deletion", author: "ada", text: "one two"}])
+
+    state = tracked()
+    const replacement = schema.node("paragraph", null, [schema.text("replaced")])
+    state = state.applyTransaction(
+        state.tr.replaceWith(0, state.doc.content.size, replacement).setMeta(SET_CONTENT_META, true),
+    ).state
+    assert.equal(state.doc.textContent, "replaced")
+    assert.deepEqual(summary(state), [])
+})
+
+test("lists comment threads with their ranges and the threads at the cursor", () => {
+    const schema = new Schema({
+        nodes: {
+            doc: {content: "block+"},
+            paragraph: {group: "block", content: "inline*"},
+            text: {group: "inline"},
+        },
+        marks: {comment: {inclusive: false, excludes: "", attrs: {threadId: {default: null}, resolved: {default: false}}}},
+    })
+    const comment = (threadId: string, resolved = false) => schema.mark("comment", {threadId, resolved})
+    const doc = schema.node("doc", null, [
+        schema.node("paragraph", null, [
+            schema.text("one ", [comment("a")]),
+            schema.text("two", [comment("a"), comment("b", true)]),
+            schema.text(" three "),
+            schema.text("four", [comment("a")]),
+        ]),
+    ])
+    const {activeCommentThreads, collectCommentThreads} = commentsTesting
+    const at = (anchor: number, head = anchor) =>
+        EditorState.create({doc, selection: TextSelection.create(doc, anchor, head)})
+
+    assert.deepEqual(collectCommentThreads(doc), [
+        {thread_id: "a", ranges: [{from: 1, to: 8}, {from: 15, to: 19}], resolved: false},
+        {thread_id: "b", ranges: [{from: 5, to: 8}], resolved: true},
+    ])
+    assert.deepEqual(activeCommentThreads(at(6)), ["a", "b"])
+    assert.deepEqual(activeCommentThreads(at(2)), ["a"])
+    assert.deepEqual(activeCommentThreads(at(8)), [])
+    assert.deepEqual(activeCommentThreads(at(9, 16)), ["a"])
+})
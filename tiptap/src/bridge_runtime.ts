@@ -1,45 +1,102 @@
 import type {Content, Editor, EditorOptions} from "@tiptap/core"
 
 import {
+    commandChain,
     emptySelectionState,
     getOrCreateBridgeBindings,
+    SET_CONTENT_META,
     type ActiveKey,
     type ActiveState,
+    type AttributesPayload,
     type BridgeError,
     type BridgeResult,
+    type CollabConfig,
+    type CollabSteps,
+    type CollaborationCreateContext,
+    type CollaborationDocument,
+    type CollaborationUser,
     type CommandKind,
+    type CommandOptions,
     type ContentFormat,
     type ContentPayload,
     type CoreCommand,
     type CoreCommandKind,
+    type CustomActiveState,
+    type CustomCommandHandler,
+    type CustomExtensionDescriptor,
+    type CustomExtensionRequest,
     type ExtensionCreateContext,
     type ExtensionCommand,
     type ExtensionCommandKind,
     type FocusOptions,
+    type ImageResource,
+    type TransactionInfo,
+    type TransactionOrigin,
     type InsertContentOptions,
     type MarkOptions,
+    type MarkSpecRequest,
+    type NodeSpecRequest,
+    type NodeViewCommand,
+    type OnHandledEvent,
+    type OnNodeViewEvent,
     type ParseOptionsPayload,
     type CreateRequest,
     type DocumentCall,
+    type DocumentChangeFormat,
     type DocumentResponse,
     type EmptyResponse,
     type EditorCommand,
     type ErrorKind,
     type ExtensionDescriptor,
+    type OnEditorEvent,
     type ReadyPayload,
+    type Decoration,
+    type DecorationClick,
+    type DiffHighlight,
+    type RemoteCursor,
+    type InputRuleRequest,
+    type PasteRuleRequest,
+    type RenderTemplate,
+    type RuleAction,
+    type ShortcutsRequest,
+    type TransferHandlers,
+    type TransferPayload,
     type RuntimeCommand,
     type RuntimeCommandKind,
+    type Range,
+    type SearchMatches,
+    type SearchOptions,
+    type SelectionRange,
     type SelectionState,
+    type SpecCommand,
+    type TiptapExtension,
 } from "./bridge_api.ts"
+import {createDocumentChangeTracker, type DocumentChangeTracker} from "./document_changes.ts"
 import {installHostedModules} from "./generated/hosted_modules.ts"
 
 type TiptapCoreModule = typeof import("@tiptap/core")
+type TiptapPmStateModule = typeof import("@tiptap/pm/state")
+type TiptapPmCollabModule = typeof import("@tiptap/pm/collab")
+type TiptapPmHistoryModule = typeof import("@tiptap/pm/history")
+type TiptapPmTransformModule = typeof import("@tiptap/pm/transform")
+type TiptapPmViewModule = typeof import("@tiptap/pm/view")
+type ProseMirrorNode = import("@tiptap/pm/model").Node
+type ProseMirrorSchema = import("@tiptap/pm/model").Schema
+type DecorationSet = import("@tiptap/pm/view").DecorationSet
+type ProseMirrorDecoration = import("@tiptap/pm/view").Decoration
+type PluginKey<T> = import("@tiptap/pm/state").PluginKey<T>
+type Transaction = import("@tiptap/pm/state").Transaction
+type EditorState = import("@tiptap/pm/state").EditorState
+type NodeView = import("@tiptap/pm/view").NodeView
+type EditorView = import("@tiptap/pm/view").EditorView
+type NodeViewRendererProps = import("@tiptap/core").NodeViewRendererProps
 type DocumentLookup = Pick<Document, "getElementById">
 type CreateEditorOptions = Partial<EditorOptions>
 type EditorConstructor = new (options?: CreateEditorOptions) => Editor
 type EditorFactory = (options: CreateEditorOptions) => Editor
 type OnSelection = (selectionState: SelectionState) => void
-type DescriptorCommandHandler = (editor: Editor, command: ExtensionCommand) => boolean | void
+type DescriptorCommandHandler = (editor: Editor, command: ExtensionCommand, options: CommandOptions) => boolean | void
+type SpecCommandHandler = (editor: Editor, attributes: AttributesPayload | undefined, options: CommandOptions) => boolean
 type CoreCommandHandler<K extends CoreCommandKind> = (
     editor: Editor,
     command: Extract<CoreCommand, { kind: K }>,
@@ -47,14 +104,43 @@ type CoreCommandHandler<K extends CoreCommandKind> = (
 type RuntimeCommandHandler<K extends RuntimeCommandKind> = (
     editor: Editor,
     command: Extract<RuntimeCommand, { kind: K }>,
+    options: CommandOptions,
 ) => BridgeResult<EmptyResponse>
 
 type EditorEntry = {
     editor: Editor
     onSelection: OnSelection
+    onEvent: OnEditorEvent
     commandHandlers: Map<ExtensionCommandKind, DescriptorCommandHandler>
     activeStateContributors: Array<(editor: Editor) => ActiveState>
+    customCommandHandlers: Map<string, CustomCommandHandler>
+    customActiveStateContributors: Array<(editor: Editor) => CustomActiveState>
+    specCommandHandlers: Map<string, SpecCommandHandler>
+    nodeViews: Map<number, NodeViewEntry>
     lastSelectionState?: SelectionState
+    documentChanges?: DocumentChangeTracker
+    collab?: CollabEntry
+    collaboration?: CollaborationDocument
+    // Only tracked when the host asked for local selection events.
+    lastLocalSelection?: SelectionRange
+    // Only tracked when the host asked for transaction events.
+    transactions?: TransactionTracker
+    lastHistoryDepth: HistoryDepth
+}
+
+type HistoryDepth = {
+    undo_depth: number
+    redo_depth: number
+}
+
+type TransactionTracker = {
+    lastSelection: SelectionRange
+    // The meta keys written through `set_meta`, which are reported with each transaction.
+    metaKeys: Set<string>
+}
+
+type CollabEntry = {
+    lastSendableKey?: string
 }
 
 type EditorSlot = {
@@ -71,12 +157,52 @@ type RuntimeConfiguration = {
     extensions: NonNullable<EditorOptions["extensions"]>
     commandHandlers: Map<ExtensionCommandKind, DescriptorCommandHandler>
     activeStateContributors: Array<(editor: Editor) => ActiveState>
+    customCommandHandlers: Map<string, CustomCommandHandler>
+    customActiveStateContributors: Array<(editor: Editor) => CustomActiveState>
+    specCommandHandlers: Map<string, SpecCommandHandler>
+    nodeViews: Map<number, NodeViewEntry>
+}
+
+// A node view rendered by a Leptos component, addressed by its id when Rust updates its attributes.
+type NodeViewEntry = {
+    getPos: () => number | undefined
+    node: () => ProseMirrorNode
+}
+
+type NodeViewHost = {
+    names: Set<string>
+    emit: OnNodeViewEvent
+    views: Map<number, NodeViewEntry>
 }
 
+// Transaction metadata carrying the remote cursors to render.
+const REMOTE_CURSORS_META = "leptosTiptapRemoteCursors"
+const DECORATIONS_META = "leptosTiptapDecorations"
+const DIFF_LAYER = "leptos-tiptap-diff"
+// The editability of editors showing a diff, restored when the diff is hidden.
+// Diffs are shown in a separate view in place of the editor, which keeps its document and the
+// editability to restore once the diff is hidden.
+type ShownDiff = {
+    view: EditorView
+    editable: boolean
+    display: string
+}
+const shownDiffs = new WeakMap<Editor, ShownDiff>()
+// Transaction metadata starting a search or selecting one of its matches.
+const SEARCH_META = "leptosTiptapSearch"
+// Transaction metadata adding, updating, or removing the placeholder of an image upload.
+const IMAGE_UPLOADS_META = "leptosTiptapImageUploads"
+
 const editorSlots = new Map<string, EditorSlot>()
 const extensionRegistry = new Map<string, ExtensionDescriptor>()
 const bridgeBindings = getOrCreateBridgeBindings()
 let nextGeneration = 1
+let nextNodeViewId = 1
+let searchPluginKey: PluginKey<SearchState> | undefined
+let imageUploadsPluginKey: PluginKey<DecorationSet> | undefined
+// Greater than zero while Rust calls into an editor, so that its transactions are reported as
+// originating from the API.
+let hostCallDepth = 0
 
 let documentOverride: DocumentLookup | undefined
 let editorFactory: EditorFactory = createDefaultEditor
@@ -92,6 +218,51 @@ function getTiptapCoreModule(): TiptapCoreModule {
     return module as TiptapCoreModule
 }
 
+function getTiptapPmStateModule(): TiptapPmStateModule {
+    const module = bridgeBindings.modules["@tiptap/pm/state"]
+    if (module == null) {
+        throw new Error("leptos-tiptap tiptap_pm_state runtime is not initialized")
+    }
+
+    return module as TiptapPmStateModule
+}
+
+function getTiptapPmCollabModule(): TiptapPmCollabModule {
+    const module = bridgeBindings.modules["@tiptap/pm/collab"]
+    if (module == null) {
+        throw new Error("leptos-tiptap @tiptap/pm/collab module is not installed, enable the collab feature")
+    }
+
+    return module as TiptapPmCollabModule
+}
+
+function getTiptapPmHistoryModule(): TiptapPmHistoryModule {
+    const module = bridgeBindings.modules["@tiptap/pm/history"]
+    if (module == null) {
+        throw new Error("leptos-tiptap tiptap_pm_history runtime is not initialized")
+    }
+
+    return module as TiptapPmHistoryModule
+}
+
+function getTiptapPmTransformModule(): TiptapPmTransformModule {
+    const module = bridgeBindings.modules["@tiptap/pm/transform"]
+    if (module == null) {
+        throw new Error("leptos-tiptap tiptap_pm_transform runtime is not initialized")
+    }
+
+    return module as TiptapPmTransformModule
+}
+
+function getTiptapPmViewModule(): TiptapPmViewModule {
+    const module = bridgeBindings.modules["@tiptap/pm/view"]
+    if (module == null) {
+        throw new Error("leptos-tiptap tiptap_pm_view runtime is not initialized")
+    }
+
+    return module as TiptapPmViewModule
+}
+
 function createDefaultEditor(options: CreateEditorOptions): Editor {
     const EditorClass = getTiptapCoreModule().Editor as EditorConstructor
     return new EditorClass(options)
@@ -148,7 +319,9 @@ function destroySlot(id: string): void {
     }
 
     if (slot.entry != null) {
+        removeDiffView(slot.entry.editor)
         destroyEditorInstance(slot.entry.editor)
+        slot.entry.collaboration?.destroy()
     }
 
     editorSlots.delete(id)
@@ -159,6 +332,7 @@ function setEditorEntry(
     generation: number,
     editor: Editor,
     onSelection: OnSelection,
+    onEvent: OnEditorEvent,
     runtimeConfig: RuntimeConfiguration,
 ): EditorEntry | undefined {
     const slot = editorSlots.get(id)
@@ -170,8 +344,14 @@ function setEditorEntry(
     const editorEntry = {
         editor,
         onSelection,
+        onEvent,
         commandHandlers: runtimeConfig.commandHandlers,
         activeStateContributors: runtimeConfig.activeStateContributors,
+        customCommandHandlers: runtimeConfig.customCommandHandlers,
+        customActiveStateContributors: runtimeConfig.customActiveStateContributors,
+        specCommandHandlers: runtimeConfig.specCommandHandlers,
+        nodeViews: runtimeConfig.nodeViews,
+        lastHistoryDepth: {undo_depth: 0, redo_depth: 0},
     }
     slot.entry = editorEntry
     return editorEntry
@@ -186,10 +366,15 @@ function getCurrentEditorEntry(id: string, editor: Editor): EditorEntry | undefi
     return editorEntry?.editor === editor ? editorEntry : undefined
 }
 
-function emitSelectionForCurrentEditor(id: string, editor: Editor): void {
+function emitTransactionEffectsForCurrentEditor(id: string, editor: Editor, transaction: Transaction): void {
     const editorEntry = getCurrentEditorEntry(id, editor)
     if (editorEntry != null) {
+        emitDocumentChange(editorEntry)
+        emitCollabSendable(editorEntry)
         emitSelectionState(editorEntry)
+        emitLocalSelection(editorEntry)
+        emitTransaction(editorEntry, transaction)
+        emitHistoryDepth(editorEntry)
     }
 }
 
@@ -202,6 +387,13 @@ function registerExtension(descriptor: ExtensionDescriptor): void {
     extensionRegistry.set(descriptor.name, descriptor)
 }
 
+function isCustomExtensionDescriptor(value: unknown): value is CustomExtensionDescriptor {
+    return typeof value === "object"
+        && value != null
+        && typeof (value as Partial<CustomExtensionDescriptor>).create === "function"
+}
+
+
 function withEditor<T>(
     id: string,
     generation: number,
@@ -216,7 +408,46 @@ function withEditor<T>(
         return errorResult("editor_unavailable", message, operation)
     }
 
-    return onReady(editorEntry)
+    hostCallDepth += 1
+    try {
+        return onReady(editorEntry)
+    } finally {
+        hostCallDepth -= 1
+    }
+}
+
+function withCollabEditor<T>(
+    id: string,
+    generation: number,
+    operation: string,
+    onReady: (editorEntry: EditorEntry) => BridgeResult<T>,
+): BridgeResult<T> {
+    return withEditor(id, generation, operation, (editorEntry) => {
+        if (editorEntry.collab == null) {
+            const message = `Can not execute ${operation} for Tiptap instance "${id}", as it was created without collaboration.`
+            console.error(message)
+            return errorResult("operation_failed", message, operation)
+        }
+
+        return onReady(editorEntry)
+    })
+}
+
+function withCollaborationEditor<T>(
+    id: string,
+    generation: number,
+    operation: string,
+    onReady: (collaboration: CollaborationDocument) => BridgeResult<T>,
+): BridgeResult<T> {
+    return withEditor(id, generation, operation, (editorEntry) => {
+        if (editorEntry.collaboration == null) {
+            const message = `Can not execute ${operation} for Tiptap instance "${id}", as it was created without a collaboration document.`
+            console.error(message)
+            return errorResult("operation_failed", message, operation)
+        }
+
+        return onReady(editorEntry.collaboration)
+    })
 }
 
 function requireEditorElement(id: string): BridgeResult<HTMLElement> {
@@ -289,36 +520,928 @@ function getSelectionState(editorEntry: EditorEntry): SelectionState {
         Object.assign(state.active, contribute(editorEntry.editor))
     }
 
+    if (editorEntry.customActiveStateContributors.length > 0) {
+        const custom: CustomActiveState = {}
+        for (const contribute of editorEntry.customActiveStateContributors) {
+            Object.assign(custom, contribute(editorEntry.editor))
+        }
+        state.custom = custom
+    }
+
     return state
 }
 
-function selectionStatesEqual(left: SelectionState, right: SelectionState): boolean {
-    const keys = new Set<ActiveKey>([
-        ...(Object.keys(left.active) as ActiveKey[]),
-        ...(Object.keys(right.active) as ActiveKey[]),
-    ])
+function selectionStatesEqual(left: SelectionState, right: SelectionState): boolean {
+    const keys = new Set<ActiveKey>([
+        ...(Object.keys(left.active) as ActiveKey[]),
+        ...(Object.keys(right.active) as ActiveKey[]),
+    ])
+
+    for (const key of keys) {
+        if ((left.active[key] ?? false) !== (right.active[key] ?? false)) {
+            return false
+        }
+    }
+
+    const leftCustom = left.custom ?? {}
+    const rightCustom = right.custom ?? {}
+    for (const key of new Set([...Object.keys(leftCustom), ...Object.keys(rightCustom)])) {
+        if ((leftCustom[key] ?? false) !== (rightCustom[key] ?? false)) {
+            return false
+        }
+    }
+
+    return true
+}
+
+function emitSelectionState(editorEntry: EditorEntry, options: { force?: boolean } = {}): void {
+    const nextState = getSelectionState(editorEntry)
+    if (
+        options.force !== true
+        && editorEntry.lastSelectionState != null
+        && selectionStatesEqual(editorEntry.lastSelectionState, nextState)
+    ) {
+        return
+    }
+
+    editorEntry.lastSelectionState = nextState
+    editorEntry.onSelection(nextState)
+}
+
+function readSelectionRange(editor: Editor): SelectionRange {
+    const {anchor, head} = editor.state.selection
+    return {anchor, head}
+}
+
+function emitLocalSelection(editorEntry: EditorEntry): void {
+    const last = editorEntry.lastLocalSelection
+    if (last == null) {
+        return
+    }
+
+    const selection = readSelectionRange(editorEntry.editor)
+    if (selection.anchor === last.anchor && selection.head === last.head) {
+        return
+    }
+
+    editorEntry.lastLocalSelection = selection
+    editorEntry.onEvent({kind: "local_selection", selection})
+}
+
+// Both depths are 0 while no history plugin is active, for example while collaborating.
+function emitHistoryDepth(editorEntry: EditorEntry): void {
+    const {undoDepth, redoDepth} = getTiptapPmHistoryModule()
+    const {state} = editorEntry.editor
+    const depth = {undo_depth: undoDepth(state), redo_depth: redoDepth(state)}
+    const last = editorEntry.lastHistoryDepth
+    if (depth.undo_depth === last.undo_depth && depth.redo_depth === last.redo_depth) {
+        return
+    }
+
+    editorEntry.lastHistoryDepth = depth
+    editorEntry.onEvent({kind: "history_change", ...depth})
+}
+
+function emitTransaction(editorEntry: EditorEntry, transaction: Transaction): void {
+    const tracker = editorEntry.transactions
+    if (tracker == null) {
+        return
+    }
+
+    const {editor} = editorEntry
+    const selection = readSelectionRange(editor)
+    const selectionChanged = selection.anchor !== tracker.lastSelection.anchor
+        || selection.head !== tracker.lastSelection.head
+    tracker.lastSelection = selection
+
+    const meta: Record<string, unknown> = {}
+    for (const key of tracker.metaKeys) {
+        const value: unknown = transaction.getMeta(key)
+        if (value !== undefined) {
+            meta[key] = value
+        }
+    }
+
+    const info: TransactionInfo = {
+        doc_changed: transaction.docChanged,
+        selection: selectionChanged ? selection : null,
+        meta,
+        add_to_history: transaction.getMeta("addToHistory") !== false,
+        origin: readTransactionOrigin(editor, transaction),
+    }
+    editorEntry.onEvent({kind: "transaction", transaction: info})
+}
+
+function readTransactionOrigin(editor: Editor, transaction: Transaction): TransactionOrigin {
+    if (hostCallDepth > 0) {
+        return "api"
+    }
+
+    // ProseMirror tags the transactions of clipboard and drag and drop events.
+    const uiEvent: unknown = transaction.getMeta("uiEvent")
+    if (uiEvent === "paste" || uiEvent === "drop" || uiEvent === "cut") {
+        return uiEvent
+    }
+
+    // Tiptap tags the transactions of its input rules with their plugin.
+    const inputRules = editor.state.plugins.find(
+        (plugin) => (plugin.spec as { isInputRules?: boolean }).isInputRules === true,
+    )
+    if (inputRules != null && transaction.getMeta(inputRules) != null) {
+        return "input_rule"
+    }
+
+    return "other"
+}
+
+function emitDocumentChange(editorEntry: EditorEntry): void {
+    const change = editorEntry.documentChanges?.flush(editorEntry.editor.state.doc)
+    if (change != null) {
+        editorEntry.onEvent({kind: "document_change", change})
+    }
+}
+
+// Records the steps of every applied transaction, including those appended by plugins, which
+// Tiptap's `transaction` event does not report individually.
+function createStepRecorderExtension(record: (steps: unknown[]) => void): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+    const {Plugin, PluginKey} = getTiptapPmStateModule()
+
+    return Extension.create({
+        name: "leptosTiptapStepRecorder",
+        addProseMirrorPlugins: () => [
+            new Plugin({
+                key: new PluginKey("leptosTiptapStepRecorder"),
+                appendTransaction: (transactions) => {
+                    for (const transaction of transactions) {
+                        if (transaction.docChanged) {
+                            record(transaction.steps.map((step) => step.toJSON()))
+                        }
+                    }
+                    return null
+                },
+            }),
+        ],
+    })
+}
+
+// Renders the cursors of remote users. They are replaced as a whole through transaction metadata
+// and otherwise mapped through local changes, so they stay in place until the next update.
+function createRemoteCursorsExtension(): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+    const {Plugin, PluginKey} = getTiptapPmStateModule()
+    const {DecorationSet} = getTiptapPmViewModule()
+    const key = new PluginKey<DecorationSet>("leptosTiptapRemoteCursors")
+
+    return Extension.create({
+        name: "leptosTiptapRemoteCursors",
+        addProseMirrorPlugins: () => [
+            new Plugin<DecorationSet>({
+                key,
+                state: {
+                    init: () => DecorationSet.empty,
+                    apply: (transaction, decorations) => {
+                        const cursors = transaction.getMeta(REMOTE_CURSORS_META) as RemoteCursor[] | undefined
+                        return cursors == null
+                            ? decorations.map(transaction.mapping, transaction.doc)
+                            : createRemoteCursorDecorations(transaction.doc, cursors)
+                    },
+                },
+                props: {
+                    decorations: (state) => key.getState(state),
+                },
+            }),
+        ],
+    })
+}
+
+function createRemoteCursorDecorations(doc: ProseMirrorNode, cursors: RemoteCursor[]): DecorationSet {
+    const {Decoration, DecorationSet} = getTiptapPmViewModule()
+    // Remote positions may refer to a document state this editor has not received yet.
+    const clamp = (position: number) => Math.min(Math.max(position, 0), doc.content.size)
+
+    const decorations = cursors.flatMap(({user, selection}) => {
+        const anchor = clamp(selection.anchor)
+        const head = clamp(selection.head)
+        const caret = Decoration.widget(head, (view) => renderRemoteCaret(view.dom.ownerDocument, user), {
+            key: JSON.stringify(user),
+            side: 10,
+        })
+        if (anchor === head) {
+            return [caret]
+        }
+
+        return [
+            caret,
+            Decoration.inline(Math.min(anchor, head), Math.max(anchor, head), {
+                class: "collaboration-cursor__selection",
+                style: `background-color: color-mix(in srgb, ${user.color} 30%, transparent)`,
+            }),
+        ]
+    })
+
+    return DecorationSet.create(doc, decorations)
+}
+
+function renderRemoteCaret(ownerDocument: Document, user: CollaborationUser): HTMLElement {
+    const caret = ownerDocument.createElement("span")
+    caret.classList.add("collaboration-cursor__caret")
+    caret.style.setProperty("border-color", user.color)
+
+    const label = ownerDocument.createElement("span")
+    label.classList.add("collaboration-cursor__label")
+    label.style.setProperty("background-color", user.color)
+    label.textContent = user.name
+
+    caret.append(label)
+    return caret
+}
+
+type DecorationLayers = {
+    layers: Map<string, DecorationSet>
+    all: DecorationSet
+}
+
+// Renders the decorations set from Rust. Each layer is replaced as a whole through transaction
+// metadata, and all layers are mapped through document changes. Decorated elements carry their
+// layer and id, which identify them when they are clicked.
+function createDecorationsExtension(onClick: ((click: DecorationClick) => void) | null): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+    const {Plugin, PluginKey} = getTiptapPmStateModule()
+    const {DecorationSet} = getTiptapPmViewModule()
+    const key = new PluginKey<DecorationLayers>("leptosTiptapDecorations")
+
+    return Extension.create({
+        name: "leptosTiptapDecorations",
+        addProseMirrorPlugins: () => [
+            new Plugin<DecorationLayers>({
+                key,
+                state: {
+                    init: () => ({layers: new Map(), all: DecorationSet.empty}),
+                    apply: (transaction, state) => {
+                        const update = transaction.getMeta(DECORATIONS_META) as
+                            | { layer_id: string; decorations: Decoration[] }
+                            | undefined
+                        if (update == null && !transaction.docChanged) {
+                            return state
+                        }
+
+                        const {mapping, doc} = transaction
+                        const layers = new Map(
+                            [...state.layers].map(([layerId, layer]) => [layerId, layer.map(mapping, doc)]),
+                        )
+                        if (update == null) {
+                            return {layers, all: state.all.map(mapping, doc)}
+                        }
+
+                        if (update.decorations.length === 0) {
+                            layers.delete(update.layer_id)
+                        } else {
+                            layers.set(update.layer_id, createLayerDecorations(doc, update.layer_id, update.decorations))
+                        }
+                        return {
+                            layers,
+                            all: DecorationSet.create(doc, [...layers.values()].flatMap((layer) => layer.find())),
+                        }
+                    },
+                },
+                props: {
+                    decorations: (state) => key.getState(state)?.all,
+                    handleDOMEvents: {
+                        click: (view, event) => {
+                            const target = event.target as Element | null
+                            const decorated = target?.closest?.("[data-decoration-id]")
+                            if (onClick == null || decorated == null || !view.dom.contains(decorated)) {
+                                return false
+                            }
+
+                            onClick({
+                                layer_id: decorated.getAttribute("data-decoration-layer") ?? "",
+                                decoration_id: decorated.getAttribute("data-decoration-id") ?? "",
+                            })
+                            return false
+                        },
+                    },
+                },
+            }),
+        ],
+    })
+}
+
+function createLayerDecorations(doc: ProseMirrorNode, layerId: string, decorations: Decoration[]): DecorationSet {
+    const {Decoration, DecorationSet} = getTiptapPmViewModule()
+    // Positions may be stale when the document changed since Rust computed them.
+    const clamp = (position: number) => Math.min(Math.max(position, 0), doc.content.size)
+
+    const created = decorations.flatMap((decoration) => {
+        const attributes: Record<string, string> = {
+            ...decoration.attributes,
+            ...(decoration.class != null ? {class: decoration.class} : {}),
+            ...(decoration.style != null ? {style: decoration.style} : {}),
+            "data-decoration-layer": layerId,
+            "data-decoration-id": decoration.id,
+        }
+        const {target} = decoration
+        switch (target.kind) {
+            case "inline": {
+                const from = clamp(target.from)
+                const to = clamp(target.to)
+                return from < to ? [Decoration.inline(from, to, attributes)] : []
+            }
+            case "node": {
+                const position = clamp(target.position)
+                const node = doc.nodeAt(position)
+                return node != null ? [Decoration.node(position, position + node.nodeSize, attributes)] : []
+            }
+            case "widget":
+                return [
+                    Decoration.widget(
+                        clamp(target.position),
+                        (view) => renderDecorationWidget(view.dom.ownerDocument, attributes, decoration.text ?? null),
+                        {key: `${layerId}:${decoration.id}`},
+                    ),
+                ]
+        }
+    })
+
+    return DecorationSet.create(doc, created)
+}
+
+function renderDecorationWidget(
+    ownerDocument: Document,
+    attributes: Record<string, string>,
+    text: string | null,
+): HTMLElement {
+    const widget = ownerDocument.createElement("span")
+    for (const [name, value] of Object.entries(attributes)) {
+        widget.setAttribute(name, value)
+    }
+    if (text != null) {
+        widget.textContent = text
+    }
+    return widget
+}
+
+type SearchMatch = Range & {
+    text: string
+    captures: string[]
+}
+
+type SearchState = {
+    pattern: RegExp | null
+    // Whether replacements may refer to the capture groups of the pattern.
+    regex: boolean
+    matches: SearchMatch[]
+    current: number | null
+    decorations: DecorationSet
+}
+
+type SearchUpdate =
+    | { kind: "find"; pattern: RegExp | null; regex: boolean }
+    | { kind: "select"; current: number }
+
+function getSearchPluginKey(): PluginKey<SearchState> {
+    searchPluginKey ??= new (getTiptapPmStateModule().PluginKey)<SearchState>("leptosTiptapSearch")
+    return searchPluginKey
+}
+
+function readSearchState(editor: Editor): SearchState {
+    const state = getSearchPluginKey().getState(editor.state)
+    if (state == null) {
+        throw new Error("the search plugin is not installed")
+    }
+
+    return state
+}
+
+// Keeps the matches of the active search up to date and highlights them. Matches are searched
+// again on every document change, and the current match is kept when it is still matched at its
+// mapped position.
+function createSearchExtension(onMatches: (matches: SearchMatches) => void): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+    const {Plugin} = getTiptapPmStateModule()
+    const {DecorationSet} = getTiptapPmViewModule()
+    const key = getSearchPluginKey()
+
+    return Extension.create({
+        name: "leptosTiptapSearch",
+        addProseMirrorPlugins: () => [
+            new Plugin<SearchState>({
+                key,
+                state: {
+                    init: () => ({
+                        pattern: null,
+                        regex: false,
+                        matches: [],
+                        current: null,
+                        decorations: DecorationSet.empty,
+                    }),
+                    apply: (transaction, state) => {
+                        const update = transaction.getMeta(SEARCH_META) as SearchUpdate | undefined
+                        if (update == null && !transaction.docChanged) {
+                            return state
+                        }
+
+                        const {pattern, regex} = update?.kind === "find" ? update : state
+                        if (pattern == null) {
+                            return {pattern, regex, matches: [], current: null, decorations: DecorationSet.empty}
+                        }
+
+                        const {doc, mapping} = transaction
+                        const matches = transaction.docChanged || update?.kind === "find"
+                            ? findSearchMatches(doc, pattern)
+                            : state.matches
+                        let current: number | null = null
+                        if (update?.kind === "select") {
+                            current = update.current
+                        } else if (update == null && state.current != null) {
+                            const previous = state.matches[state.current]
+                            const index = previous == null ? -1 : matches.findIndex((match) =>
+                                match.from === mapping.map(previous.from) && match.to === mapping.map(previous.to, -1),
+                            )
+                            current = index === -1 ? null : index
+                        }
+
+                        const decorations = createSearchDecorations(doc, matches, current)
+                        return {pattern, regex, matches, current, decorations}
+                    },
+                },
+                props: {
+                    decorations: (state) => key.getState(state)?.decorations,
+                },
+                view: (view) => {
+                    let reported = toSearchMatches(key.getState(view.state))
+                    return {
+                        update: (updatedView) => {
+                            const matches = toSearchMatches(key.getState(updatedView.state))
+                            if (matches.count !== reported.count || matches.current !== reported.current) {
+                                reported = matches
+                                onMatches(matches)
+                            }
+                        },
+                    }
+                },
+            }),
+        ],
+    })
+}
+
+function toSearchMatches(state: SearchState | undefined): SearchMatches {
+    return {count: state?.matches.length ?? 0, current: state?.current ?? null}
+}
+
+function createSearchPattern(query: string, options: SearchOptions): RegExp | null {
+    if (query === "") {
+        return null
+    }
+
+    const source = options.regex ? query : query.replace(/[.*+?^${}()|[\]\\]/g, "\\$&")
+    const bounded = options.whole_word ? `(?<![\\p{L}\\p{N}_])(?:${source})(?![\\p{L}\\p{N}_])` : source
+    return new RegExp(bounded, options.case_sensitive ? "gu" : "giu")
+}
+
+// Matches `pattern` against the text of each textblock. Inline nodes other than text stand for as
+// many object replacement characters as their size, so offsets in the text are offsets in the
+// document.
+function findSearchMatches(doc: ProseMirrorNode, pattern: RegExp): SearchMatch[] {
+    const matches: SearchMatch[] = []
+    doc.descendants((node, position) => {
+        if (!node.isTextblock) {
+            return true
+        }
+
+        let text = ""
+        node.forEach((child) => {
+            text += child.isText ? child.text ?? "" : "\ufffc".repeat(child.nodeSize)
+        })
+        for (const match of text.matchAll(pattern)) {
+            if (match[0].length === 0) {
+                continue
+            }
+
+            const from = position + 1 + match.index
+            matches.push({
+                from,
+                to: from + match[0].length,
+                text: match[0],
+                captures: match.slice(1).map((capture) => capture ?? ""),
+            })
+        }
+        return false
+    })
+    return matches
+}
+
+function createSearchDecorations(doc: ProseMirrorNode, matches: SearchMatch[], current: number | null): DecorationSet {
+    const {Decoration, DecorationSet} = getTiptapPmViewModule()
+    return DecorationSet.create(doc, matches.map(({from, to}, index) =>
+        Decoration.inline(from, to, {
+            class: index === current ? "search-match search-match-current" : "search-match",
+        }),
+    ))
+}
+
+// Expands `$1`, `$&`, and `$$` in the replacement of a regular expression search.
+function expandReplacement(replacement: string, {text, captures}: SearchMatch): string {
+    return replacement.replace(/\$(\$|&|\d{1,2})/g, (reference, name: string) => {
+        if (name === "$") {
+            return "$"
+        }
+        if (name === "&") {
+            return text
+        }
+
+        return captures[Number(name) - 1] ?? reference
+    })
+}
+
+// Scrolls the selected match into view, unless the command was dispatched without focus.
+function selectSearchMatch(editor: Editor, step: 1 | -1, options: CommandOptions): boolean {
+    const {matches, current} = readSearchState(editor)
+    if (matches.length === 0) {
+        return false
+    }
+
+    let index: number
+    if (current != null) {
+        index = (current + step + matches.length) % matches.length
+    } else if (step === 1) {
+        const {from} = editor.state.selection
+        index = Math.max(matches.findIndex((match) => match.from >= from), 0)
+    } else {
+        const {to} = editor.state.selection
+        // Matches are sorted, so the last one before the cursor is at the count of those before it.
+        const before = matches.filter((match) => match.to <= to).length
+        index = before === 0 ? matches.length - 1 : before - 1
+    }
+
+    const match = matches[index]
+    if (match == null) {
+        return false
+    }
+
+    const {TextSelection} = getTiptapPmStateModule()
+    const {from, to} = match
+    const transaction = editor.state.tr
+        .setSelection(TextSelection.create(editor.state.doc, from, to))
+        .setMeta(SEARCH_META, {kind: "select", current: index})
+    editor.view.dispatch(options.focus ? transaction.scrollIntoView() : transaction)
+    return true
+}
+
+function replaceCurrentMatch(editor: Editor, replacement: string, options: CommandOptions): boolean {
+    const {pattern, regex, matches, current} = readSearchState(editor)
+    if (current == null) {
+        return false
+    }
+
+    const match = matches[current]
+    if (pattern == null || match == null) {
+        return false
+    }
+
+    const text = regex ? expandReplacement(replacement, match) : replacement
+    const transaction = editor.state.tr.insertText(text, match.from, match.to)
+    // Select the next match in the same transaction, so that the replacement is undone with it.
+    const following = findSearchMatches(transaction.doc, pattern)
+    const next = Math.max(following.findIndex((candidate) => candidate.from >= match.from + text.length), 0)
+    const nextMatch = following[next]
+    if (nextMatch != null) {
+        const {TextSelection} = getTiptapPmStateModule()
+        transaction
+            .setSelection(TextSelection.create(transaction.doc, nextMatch.from, nextMatch.to))
+            .setMeta(SEARCH_META, {kind: "select", current: next})
+        if (options.focus) {
+            transaction.scrollIntoView()
+        }
+    }
+    editor.view.dispatch(transaction)
+    return true
+}
+
+function replaceAllMatches(editor: Editor, replacement: string): boolean {
+    const {regex, matches} = readSearchState(editor)
+    if (matches.length === 0) {
+        return false
+    }
+
+    const {tr} = editor.state
+    // Replacing from the end keeps the positions of the earlier matches valid.
+    for (const match of [...matches].reverse()) {
+        tr.insertText(regex ? expandReplacement(replacement, match) : replacement, match.from, match.to)
+    }
+    editor.view.dispatch(tr)
+    return true
+}
+
+// Selects from `anchor` to `head`, keeping the direction of the selection. Positions outside of
+// the document are moved to its nearest text position.
+function selectionTransaction(state: EditorState, anchor: number, head: number): Transaction {
+    const {TextSelection} = getTiptapPmStateModule()
+    const {doc} = state
+    const min = TextSelection.atStart(doc).from
+    const max = TextSelection.atEnd(doc).to
+    const clamp = (position: number) => Math.min(Math.max(position, min), max)
+    return state.tr.setSelection(TextSelection.create(doc, clamp(anchor), clamp(head)))
+}
+
+// Shows the merged document of a diff computed in Rust in a separate read-only view, which takes
+// the place of the editor and highlights the changes. The document of the editor is not touched,
+// so showing a diff causes no updates, document changes, collaboration steps, or drafts. The editor
+// is read-only until the diff is hidden.
+function showDiff(editor: Editor, document: unknown, highlights: DiffHighlight[]): boolean {
+    const {EditorState} = getTiptapPmStateModule()
+    const {EditorView} = getTiptapPmViewModule()
+    const doc = editor.schema.nodeFromJSON(document)
+    const decorations = createLayerDecorations(doc, DIFF_LAYER, diffDecorations(doc, highlights))
+    const props = {
+        state: EditorState.create({doc}),
+        editable: () => false,
+        decorations: () => decorations,
+        attributes: {class: "tiptap leptos-tiptap-diff"},
+    }
+
+    const shown = shownDiffs.get(editor)
+    if (shown != null) {
+        shown.view.setProps(props)
+        return true
+    }
+
+    const editorDom = editor.view.dom
+    const view = new EditorView((dom) => editorDom.after(dom), props)
+    shownDiffs.set(editor, {view, editable: editor.isEditable, display: editorDom.style.display})
+    editorDom.style.display = "none"
+    editor.setEditable(false, false)
+    return true
+}
+
+function diffDecorations(doc: ProseMirrorNode, highlights: DiffHighlight[]): Decoration[] {
+    return highlights.flatMap((highlight, index): Decoration[] => {
+        if (highlight.block >= doc.childCount) {
+            return []
+        }
+
+        const block = doc.child(highlight.block)
+        let position = 0
+        for (let previous = 0; previous < highlight.block; previous += 1) {
+            position += doc.child(previous).nodeSize
+        }
+        const id = `diff-${index}`
+        switch (highlight.kind) {
+            case "block":
+                return [diffDecoration(id, {kind: "node", position}, `diff-${highlight.change}`)]
+            case "text":
+                return [
+                    diffDecoration(
+                        id,
+                        {
+                            kind: "inline",
+                            from: resolveTextOffset(block, position, highlight.from),
+                            to: resolveTextOffset(block, position, highlight.to),
+                        },
+                        `diff-${highlight.change}`,
+                    ),
+                ]
+            case "deleted_text":
+                return [{
+                    ...diffDecoration(
+                        id,
+                        {kind: "widget", position: resolveTextOffset(block, position, highlight.offset)},
+                        "diff-deleted",
+                    ),
+                    text: highlight.text,
+                }]
+        }
+    })
+}
+
+// Removes the view of a shown diff and shows the editor again, with its document unchanged.
+function removeDiffView(editor: Editor): ShownDiff | null {
+    const shown = shownDiffs.get(editor)
+    if (shown == null) {
+        return null
+    }
+
+    shownDiffs.delete(editor)
+    shown.view.destroy()
+    editor.view.dom.style.display = shown.display
+    return shown
+}
+
+// Hides a shown diff and restores the editability.
+function hideDiff(editor: Editor): void {
+    const shown = removeDiffView(editor)
+    if (shown != null) {
+        editor.setEditable(shown.editable, false)
+    }
+}
+
+function diffDecoration(id: string, target: Decoration["target"], className: string): Decoration {
+    return {id, target, class: className, style: null, attributes: {}}
+}
+
+// Returns the document position of the text offset `offset` within `block`, which starts at
+// `position`. Offsets past the text of the block resolve to the end of its last text node.
+function resolveTextOffset(block: ProseMirrorNode, position: number, offset: number): number {
+    let resolved = position + 1
+    let remaining = offset
+    block.descendants((node, nodePosition) => {
+        if (remaining < 0 || !node.isText) {
+            return remaining >= 0
+        }
+
+        const length = node.text?.length ?? 0
+        resolved = position + 1 + nodePosition + Math.min(remaining, length)
+        remaining = remaining <= length ? -1 : remaining - length
+        return false
+    })
+    return resolved
+}
+
+type ImageUploadUpdate =
+    | { kind: "start"; upload_id: number; position: number }
+    | { kind: "progress"; upload_id: number; progress: number }
+    | { kind: "remove"; upload_id: number }
+
+type ImageUploadSpec = {
+    uploadId: number
+    progress: number | null
+}
+
+function getImageUploadsPluginKey(): PluginKey<DecorationSet> {
+    imageUploadsPluginKey ??= new (getTiptapPmStateModule().PluginKey)<DecorationSet>("leptosTiptapImageUploads")
+    return imageUploadsPluginKey
+}
+
+// Shows a placeholder with the progress of each running image upload. Placeholders are widget
+// decorations, so they never become part of the document, and they follow document changes
+// until the upload finishes.
+function createImageUploadsExtension(): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+    const {Plugin} = getTiptapPmStateModule()
+    const {DecorationSet} = getTiptapPmViewModule()
+    const key = getImageUploadsPluginKey()
+
+    return Extension.create({
+        name: "leptosTiptapImageUploads",
+        addProseMirrorPlugins: () => [
+            new Plugin<DecorationSet>({
+                key,
+                state: {
+                    init: () => DecorationSet.empty,
+                    apply: (transaction, decorations) => {
+                        const update = transaction.getMeta(IMAGE_UPLOADS_META) as ImageUploadUpdate | undefined
+                        const {doc, mapping} = transaction
+                        const mapped = decorations.map(mapping, doc)
+                        if (update == null) {
+                            return mapped
+                        }
+
+                        const existing = findImageUpload(mapped, update.upload_id)
+                        const removed = existing == null ? mapped : mapped.remove([existing])
+                        switch (update.kind) {
+                            case "start":
+                                return removed.add(doc, [createImageUploadWidget(update.position, update.upload_id, null)])
+                            case "progress":
+                                return existing == null
+                                    ? mapped
+                                    : removed.add(doc, [createImageUploadWidget(existing.from, update.upload_id, update.progress)])
+                            case "remove":
+                                return removed
+                        }
+                    },
+                },
+                props: {
+                    decorations: (state) => key.getState(state),
+                },
+            }),
+        ],
+    })
+}
+
+function findImageUpload(decorations: DecorationSet, uploadId: number): ProseMirrorDecoration | undefined {
+    return decorations.find(undefined, undefined, (spec) => (spec as ImageUploadSpec).uploadId === uploadId)[0]
+}
+
+function createImageUploadWidget(position: number, uploadId: number, progress: number | null): ProseMirrorDecoration {
+    const {Decoration} = getTiptapPmViewModule()
+    const spec: ImageUploadSpec = {uploadId, progress}
+    return Decoration.widget(
+        position,
+        (view) => renderImageUploadPlaceholder(view.dom.ownerDocument, uploadId, progress),
+        // The key changes with the progress, so that the placeholder is rendered again.
+        {...spec, key: `image-upload:${uploadId}:${progress ?? ""}`, side: -1},
+    )
+}
+
+function renderImageUploadPlaceholder(ownerDocument: Document, uploadId: number, progress: number | null): HTMLElement {
+    const placeholder = ownerDocument.createElement("span")
+    placeholder.classList.add("image-upload-placeholder")
+    placeholder.setAttribute("data-upload-id", String(uploadId))
+
+    // Without a value, the progress bar shows that the progress is unknown.
+    const bar = ownerDocument.createElement("progress")
+    bar.max = 1
+    if (progress != null) {
+        bar.value = progress
+    }
+
+    placeholder.append(bar)
+    return placeholder
+}
+
+function readImageUpload(editor: Editor, uploadId: number): ProseMirrorDecoration | undefined {
+    const decorations = getImageUploadsPluginKey().getState(editor.state)
+    if (decorations == null) {
+        throw new Error("the image upload plugin is not installed")
+    }
+
+    return findImageUpload(decorations, uploadId)
+}
+
+// Shows the placeholder at `position`, or replaces the selection with it.
+function startImageUpload(editor: Editor, uploadId: number, position: number | null): void {
+    const {tr} = editor.state
+    let start: number
+    if (position == null) {
+        tr.deleteSelection()
+        start = tr.selection.from
+    } else {
+        start = Math.min(Math.max(position, 0), tr.doc.content.size)
+    }
+
+    editor.view.dispatch(tr.setMeta(IMAGE_UPLOADS_META, {kind: "start", upload_id: uploadId, position: start}))
+}
+
+// Replaces the placeholder with the uploaded image. When the placeholder was deleted together
+// with its surrounding content, the upload counts as cancelled and nothing is inserted.
+function finishImageUpload(editor: Editor, uploadId: number, image: ImageResource): void {
+    const placeholder = readImageUpload(editor, uploadId)
+    if (placeholder == null) {
+        return
+    }
+
+    const imageType = editor.schema.nodes.image
+    if (imageType == null) {
+        editor.view.dispatch(
+            editor.state.tr
+                .setMeta(IMAGE_UPLOADS_META, {kind: "remove", upload_id: uploadId})
+                .setMeta("addToHistory", false),
+        )
+        throw new Error("image uploads need the image extension")
+    }
+
+    const node = imageType.create({src: image.src, alt: image.alt ?? null, title: image.title ?? null})
+    editor.view.dispatch(
+        editor.state.tr
+            .setMeta(IMAGE_UPLOADS_META, {kind: "remove", upload_id: uploadId})
+            .replaceRangeWith(placeholder.from, placeholder.from, node),
+    )
+}
+
+function createCollabExtension(config: CollabConfig): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+    const {collab} = getTiptapPmCollabModule()
 
-    for (const key of keys) {
-        if ((left.active[key] ?? false) !== (right.active[key] ?? false)) {
-            return false
-        }
+    return Extension.create({
+        name: "leptosTiptapCollab",
+        addProseMirrorPlugins: () => [
+            collab({version: config.version, clientID: config.client_id}),
+        ],
+    })
+}
+
+function readSendableSteps(editor: Editor): CollabSteps | null {
+    const sendable = getTiptapPmCollabModule().sendableSteps(editor.state)
+    if (sendable == null) {
+        return null
     }
 
-    return true
+    return {
+        version: sendable.version,
+        steps: sendable.steps.map((step) => step.toJSON()),
+        client_id: String(sendable.clientID),
+    }
 }
 
-function emitSelectionState(editorEntry: EditorEntry, options: { force?: boolean } = {}): void {
-    const nextState = getSelectionState(editorEntry)
-    if (
-        options.force !== true
-        && editorEntry.lastSelectionState != null
-        && selectionStatesEqual(editorEntry.lastSelectionState, nextState)
-    ) {
+// Notifies the host once per distinct set of unconfirmed steps. Local edits add steps, while
+// received steps advance the version and rebase the remaining ones, so both change the key.
+function emitCollabSendable(editorEntry: EditorEntry): void {
+    const collab = editorEntry.collab
+    if (collab == null) {
         return
     }
 
-    editorEntry.lastSelectionState = nextState
-    editorEntry.onSelection(nextState)
+    const sendable = readSendableSteps(editorEntry.editor)
+    const key = sendable == null ? undefined : `${sendable.version}:${sendable.steps.length}`
+    if (key === collab.lastSendableKey) {
+        return
+    }
+
+    collab.lastSendableKey = key
+    if (sendable != null) {
+        editorEntry.onEvent({kind: "collab_sendable", steps: sendable})
+    }
 }
 
 function runOperation<T>(operation: string, work: () => T): BridgeResult<T> {
@@ -496,7 +1619,76 @@ const runtimeCommandHandlers: {
 } = {
     set_editable: (editor, command) =>
         runCommand(command.kind, () => {
-            editor.setEditable(command.editable)
+            // Editors showing a diff apply the editability once the diff is hidden.
+            const shown = shownDiffs.get(editor)
+            if (shown != null) {
+                shown.editable = command.editable
+            } else {
+                editor.setEditable(command.editable)
+            }
+        }),
+    set_remote_cursors: (editor, command) =>
+        runCommand(command.kind, () => {
+            editor.view.dispatch(
+                editor.state.tr
+                    .setMeta(REMOTE_CURSORS_META, command.cursors)
+                    .setMeta("addToHistory", false),
+            )
+        }),
+    set_decorations: (editor, command) =>
+        runCommand(command.kind, () => {
+            editor.view.dispatch(
+                editor.state.tr
+                    .setMeta(DECORATIONS_META, {layer_id: command.layer_id, decorations: command.decorations})
+                    .setMeta("addToHistory", false),
+            )
+        }),
+    set_selection: (editor, command) =>
+        runCommand(command.kind, () => {
+            editor.view.dispatch(selectionTransaction(editor.state, command.anchor, command.head))
+        }),
+    show_diff: (editor, command) =>
+        runCommand(command.kind, () => showDiff(editor, command.document, command.highlights)),
+    hide_diff: (editor, command) => runCommand(command.kind, () => hideDiff(editor)),
+    clear_search: (editor, command) =>
+        runCommand(command.kind, () => {
+            editor.view.dispatch(
+                editor.state.tr
+                    .setMeta(SEARCH_META, {kind: "find", pattern: null, regex: false})
+                    .setMeta("addToHistory", false),
+            )
+        }),
+    select_next_match: (editor, command, options) =>
+        runCommand(command.kind, () => selectSearchMatch(editor, 1, options)),
+    select_previous_match: (editor, command, options) =>
+        runCommand(command.kind, () => selectSearchMatch(editor, -1, options)),
+    replace_current_match: (editor, command, options) =>
+        runCommand(command.kind, () => replaceCurrentMatch(editor, command.replacement, options)),
+    replace_all_matches: (editor, command) =>
+        runCommand(command.kind, () => replaceAllMatches(editor, command.replacement)),
+    start_image_upload: (editor, command) =>
+        runCommand(command.kind, () => startImageUpload(editor, command.upload_id, command.position ?? null)),
+    set_image_upload_progress: (editor, command) =>
+        runCommand(command.kind, () => {
+            editor.view.dispatch(
+                editor.state.tr
+                    .setMeta(IMAGE_UPLOADS_META, {
+                        kind: "progress",
+                        upload_id: command.upload_id,
+                        progress: Math.min(Math.max(command.progress, 0), 1),
+                    })
+                    .setMeta("addToHistory", false),
+            )
+        }),
+    finish_image_upload: (editor, command) =>
+        runCommand(command.kind, () => finishImageUpload(editor, command.upload_id, command.image)),
+    cancel_image_upload: (editor, command) =>
+        runCommand(command.kind, () => {
+            editor.view.dispatch(
+                editor.state.tr
+                    .setMeta(IMAGE_UPLOADS_META, {kind: "remove", upload_id: command.upload_id})
+                    .setMeta("addToHistory", false),
+            )
         }),
 }
 
@@ -516,12 +1708,57 @@ function executeCoreCommand(editor: Editor, command: CoreCommand): BridgeResult<
     return handler(editor, command)
 }
 
-function executeRuntimeCommand(editor: Editor, command: RuntimeCommand): BridgeResult<EmptyResponse> {
+function executeRuntimeCommand(
+    editor: Editor,
+    command: RuntimeCommand,
+    options: CommandOptions,
+): BridgeResult<EmptyResponse> {
     const handler = runtimeCommandHandlers[command.kind] as (
         editor: Editor,
         command: RuntimeCommand,
+        options: CommandOptions,
     ) => BridgeResult<EmptyResponse>
-    return handler(editor, command)
+    return handler(editor, command, options)
+}
+
+function executeSpecCommand(
+    editorEntry: EditorEntry,
+    id: string,
+    command: SpecCommand,
+    options: CommandOptions,
+): BridgeResult<EmptyResponse> {
+    const operation = `${command.action}_${command.name}`
+    const handler = editorEntry.specCommandHandlers.get(operation)
+    if (handler == null) {
+        return extensionError(
+            `Can not execute ${operation} for Tiptap instance "${id}", as no node or mark spec provides this command.`,
+            operation,
+        )
+    }
+
+    return runCommand(operation, () => handler(editorEntry.editor, command.attributes ?? undefined, options))
+}
+
+function executeNodeViewCommand(
+    editorEntry: EditorEntry,
+    id: string,
+    command: NodeViewCommand,
+): BridgeResult<EmptyResponse> {
+    const operation = command.kind
+    const view = editorEntry.nodeViews.get(command.view_id)
+    const position = view?.getPos()
+    if (view == null || position == null) {
+        return extensionError(
+            `Can not execute ${operation} for Tiptap instance "${id}", as node view ${command.view_id} is no longer mounted.`,
+            operation,
+        )
+    }
+
+    return runCommand(operation, () => {
+        const {editor} = editorEntry
+        const attributes = {...view.node().attrs, ...command.attributes}
+        editor.view.dispatch(editor.state.tr.setNodeMarkup(position, undefined, attributes))
+    })
 }
 
 function getContentOperationName(format: ContentFormat): string {
@@ -548,11 +1785,437 @@ function resolveDescriptors(extensionNames: string[]): BridgeResult<RuntimeDescr
     return okResult(descriptors)
 }
 
+function createExtensions(
+    name: string,
+    create: () => TiptapExtension | TiptapExtension[],
+): BridgeResult<TiptapExtension[]> {
+    try {
+        const created = create()
+        return okResult(Array.isArray(created) ? created : [created])
+    } catch (error) {
+        const message = `Can not create Tiptap instance, as extension "${name}" failed to initialize.`
+        console.error(message, error)
+        return errorResult(
+            "extension_registration_failed",
+            `${message} ${error instanceof Error ? error.message : String(error)}`,
+        )
+    }
+}
+
+function buildCustomConfiguration(
+    customExtensions: CustomExtensionRequest[],
+    configuration: RuntimeConfiguration,
+): BridgeResult<RuntimeConfiguration> {
+    const seenNames = new Set<string>()
+    const seenActiveKeys = new Set<string>()
+
+    for (const {name, descriptor} of customExtensions) {
+        if (!isCustomExtensionDescriptor(descriptor)) {
+            return registrationError(
+                `Can not create Tiptap instance, as custom extension "${name}" does not provide a create function.`,
+            )
+        }
+        if (descriptor.name != null && descriptor.name !== name) {
+            return registrationError(
+                `Can not create Tiptap instance, as custom extension "${name}" provides a descriptor named "${descriptor.name}".`,
+            )
+        }
+        if (seenNames.has(name)) {
+            return registrationError(
+                `Can not create Tiptap instance, as custom extension "${name}" was selected more than once.`,
+            )
+        }
+        seenNames.add(name)
+
+        const created = createExtensions(name, () => descriptor.create({modules: bridgeBindings.modules}))
+        if (!created.ok) {
+            return created
+        }
+        configuration.extensions.push(...created.value)
+
+        for (const activeKey of descriptor.active_keys ?? []) {
+            if (seenActiveKeys.has(activeKey)) {
+                return registrationError(
+                    `Can not create Tiptap instance, as multiple selected custom extensions contribute active key "${activeKey}".`,
+                )
+            }
+            seenActiveKeys.add(activeKey)
+        }
+
+        if (descriptor.active_state != null) {
+            configuration.customActiveStateContributors.push(descriptor.active_state)
+        }
+
+        for (const [commandName, handler] of Object.entries(descriptor.commands ?? {})) {
+            if (configuration.customCommandHandlers.has(commandName)) {
+                return registrationError(
+                    `Can not create Tiptap instance, as multiple selected custom extensions handle command "${commandName}".`,
+                )
+            }
+
+            configuration.customCommandHandlers.set(commandName, handler)
+        }
+    }
+
+    return okResult(configuration)
+}
+
+function specRenderTemplate(
+    name: string,
+    template: RenderTemplate | null | undefined,
+    defaultTag: string,
+): RenderTemplate {
+    return template ?? {tag: defaultTag, attributes: {"data-type": name}}
+}
+
+function specParseRules(name: string, parseHtml: string[], template: RenderTemplate): Array<{ tag: string }> {
+    if (parseHtml.length > 0) {
+        return parseHtml.map((tag) => ({tag}))
+    }
+
+    // Without explicit rules, the element rendered by the default template is parsed back.
+    return [{tag: `${template.tag}[data-type="${name}"]`}]
+}
+
+function specAttributes(attributes: AttributesPayload): Record<string, { default: unknown }> {
+    return Object.fromEntries(
+        Object.entries(attributes).map(([name, value]) => [name, {default: value}]),
+    )
+}
+
+function attributesEqual(left: AttributesPayload, right: AttributesPayload): boolean {
+    const keys = new Set([...Object.keys(left), ...Object.keys(right)])
+    return [...keys].every((key) => JSON.stringify(left[key]) === JSON.stringify(right[key]))
+}
+
+// Rust mounts the Leptos component into `dom`, and places `contentDOM` through
+// `TiptapNodeViewContent`. Events and mutations inside of the component are left to it.
+function createLeptosNodeView(
+    host: NodeViewHost,
+    spec: NodeSpecRequest,
+    template: RenderTemplate,
+    {editor, node, getPos, HTMLAttributes}: NodeViewRendererProps,
+): NodeView {
+    const {mergeAttributes} = getTiptapCoreModule()
+    const ownerDocument = editor.view.dom.ownerDocument
+    const viewId = nextNodeViewId++
+    const dom = ownerDocument.createElement(template.tag)
+    for (const [name, value] of Object.entries(mergeAttributes(template.attributes, HTMLAttributes))) {
+        if (value != null) {
+            dom.setAttribute(name, String(value))
+        }
+    }
+    dom.setAttribute("data-node-view", spec.name)
+
+    const contentDOM = !spec.atom && spec.content != null
+        ? ownerDocument.createElement(spec.inline ? "span" : "div")
+        : null
+    contentDOM?.setAttribute("data-node-view-content", "")
+
+    let current = node
+    host.views.set(viewId, {
+        getPos: () => typeof getPos === "function" ? getPos() : undefined,
+        node: () => current,
+    })
+    host.emit({kind: "mount", view_id: viewId, name: spec.name, attributes: node.attrs, selected: false}, dom, contentDOM)
+
+    const insideComponent = (target: EventTarget | null) =>
+        target != null
+        && dom.contains(target as globalThis.Node)
+        && !(contentDOM?.contains(target as globalThis.Node) ?? false)
+
+    return {
+        dom,
+        contentDOM,
+        update: (updated) => {
+            if (updated.type !== current.type) {
+                return false
+            }
+            if (!attributesEqual(updated.attrs, current.attrs)) {
+                host.emit({kind: "update", view_id: viewId, attributes: updated.attrs})
+            }
+            current = updated
+            return true
+        },
+        selectNode: () => {
+            dom.classList.add("ProseMirror-selectednode")
+            host.emit({kind: "select", view_id: viewId, selected: true})
+        },
+        deselectNode: () => {
+            dom.classList.remove("ProseMirror-selectednode")
+            host.emit({kind: "select", view_id: viewId, selected: false})
+        },
+        stopEvent: (event) => {
+            if (!insideComponent(event.target)) {
+                return false
+            }
+            const interactive = (event.target as Element).closest?.("input, textarea, select, button, [contenteditable=\"true\"]")
+            return interactive != null && dom.contains(interactive)
+        },
+        ignoreMutation: (mutation) => mutation.type !== "selection" && insideComponent(mutation.target),
+        destroy: () => {
+            host.views.delete(viewId)
+            host.emit({kind: "destroy", view_id: viewId})
+        },
+    }
+}
+
+function createNodeSpecExtension(spec: NodeSpecRequest, nodeViews: NodeViewHost): TiptapExtension {
+    const {Node, mergeAttributes} = getTiptapCoreModule()
+    const template = specRenderTemplate(spec.name, spec.render_html, spec.inline ? "span" : "div")
+    const hasContent = !spec.atom && spec.content != null
+    const nodeView = nodeViews.names.has(spec.name)
+        ? {addNodeView: () => (props: NodeViewRendererProps) => createLeptosNodeView(nodeViews, spec, template, props)}
+        : {}
+
+    return Node.create({
+        ...nodeView,
+        name: spec.name,
+        group: spec.group ?? undefined,
+        content: spec.content ?? undefined,
+        inline: spec.inline,
+        atom: spec.atom,
+        addAttributes: () => specAttributes(spec.attributes),
+        parseHTML: () => specParseRules(spec.name, spec.parse_html, template),
+        renderHTML: ({HTMLAttributes}) => hasContent
+            ? [template.tag, mergeAttributes(template.attributes, HTMLAttributes), 0]
+            : [template.tag, mergeAttributes(template.attributes, HTMLAttributes)],
+    })
+}
+
+function createMarkSpecExtension(spec: MarkSpecRequest): TiptapExtension {
+    const {Mark, mergeAttributes} = getTiptapCoreModule()
+    const template = specRenderTemplate(spec.name, spec.render_html, "span")
+
+    return Mark.create({
+        name: spec.name,
+        inclusive: spec.inclusive ?? undefined,
+        addAttributes: () => specAttributes(spec.attributes),
+        parseHTML: () => specParseRules(spec.name, spec.parse_html, template),
+        renderHTML: ({HTMLAttributes}) => [template.tag, mergeAttributes(template.attributes, HTMLAttributes), 0],
+    })
+}
+
+// Textblocks are set like headings, leaves are inserted, and other nodes wrap the selected blocks.
+function nodeSpecCommands(spec: NodeSpecRequest): Record<string, SpecCommandHandler> {
+    const {name} = spec
+    if (spec.atom || spec.content == null) {
+        return {
+            [`set_${name}`]: (editor, attrs, options) =>
+                commandChain(editor, options).insertContent({type: name, attrs}).run(),
+        }
+    }
+
+    return {
+        [`set_${name}`]: (editor, attrs, options) => editor.schema.nodes[name]?.isTextblock === true
+            ? commandChain(editor, options).setNode(name, attrs).run()
+            : commandChain(editor, options).wrapIn(name, attrs).run(),
+        [`toggle_${name}`]: (editor, attrs, options) => editor.schema.nodes[name]?.isTextblock === true
+            ? commandChain(editor, options).toggleNode(name, "paragraph", attrs).run()
+            : commandChain(editor, options).toggleWrap(name, attrs).run(),
+    }
+}
+
+function markSpecCommands({name}: MarkSpecRequest): Record<string, SpecCommandHandler> {
+    return {
+        [`set_${name}`]: (editor, attrs, options) => commandChain(editor, options).setMark(name, attrs).run(),
+        [`toggle_${name}`]: (editor, attrs, options) => commandChain(editor, options).toggleMark(name, attrs).run(),
+        [`unset_${name}`]: (editor, _attrs, options) => commandChain(editor, options).unsetMark(name).run(),
+    }
+}
+
+function buildSpecConfiguration(
+    nodeSpecs: NodeSpecRequest[],
+    markSpecs: MarkSpecRequest[],
+    nodeViews: NodeViewHost,
+    configuration: RuntimeConfiguration,
+): BridgeResult<RuntimeConfiguration> {
+    for (const name of nodeViews.names) {
+        if (!nodeSpecs.some((spec) => spec.name === name)) {
+            return registrationError(
+                `Can not create Tiptap instance, as node view "${name}" has no node spec of the same name.`,
+            )
+        }
+    }
+
+    const specs = [
+        ...nodeSpecs.map((spec) => ({
+            name: spec.name,
+            create: () => createNodeSpecExtension(spec, nodeViews),
+            commands: nodeSpecCommands(spec),
+        })),
+        ...markSpecs.map((spec) => ({
+            name: spec.name,
+            create: () => createMarkSpecExtension(spec),
+            commands: markSpecCommands(spec),
+        })),
+    ]
+    const seenNames = new Set<string>()
+
+    for (const {name, create, commands} of specs) {
+        if (seenNames.has(name)) {
+            return registrationError(
+                `Can not create Tiptap instance, as multiple node or mark specs are named "${name}".`,
+            )
+        }
+        seenNames.add(name)
+
+        const created = createExtensions(name, create)
+        if (!created.ok) {
+            return created
+        }
+        configuration.extensions.push(...created.value)
+
+        for (const [commandName, handler] of Object.entries(commands)) {
+            configuration.specCommandHandlers.set(commandName, handler)
+        }
+    }
+
+    if (specs.length > 0) {
+        configuration.customActiveStateContributors.push((editor) =>
+            Object.fromEntries(specs.map(({name}) => [name, editor.isActive(name)])),
+        )
+    }
+
+    return okResult(configuration)
+}
+
+function createShortcutExtension(shortcuts: ShortcutsRequest, onHandled: OnHandledEvent): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+
+    return Extension.create({
+        name: "leptosShortcuts",
+        priority: shortcuts.priority,
+        addKeyboardShortcuts: () =>
+            Object.fromEntries(shortcuts.keys.map((keys) => [keys, () => onHandled({kind: "shortcut", keys})])),
+    })
+}
+
+// Passes pastes and drops to Rust before Tiptap handles them. Rust answers whether it handled the
+// content itself, in which case ProseMirror prevents the browser event.
+function createTransferExtension(handlers: TransferHandlers, onHandled: OnHandledEvent): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+    const {Plugin} = getTiptapPmStateModule()
+
+    return Extension.create({
+        name: "leptosTransferHandlers",
+        // Run before the paste and drop handlers of other extensions, such as the link extension.
+        priority: 1000,
+        addProseMirrorPlugins: () => [
+            new Plugin({
+                props: {
+                    handlePaste: handlers.paste
+                        ? (_view, event) => onHandled({kind: "paste", transfer: readDataTransfer(event.clipboardData)})
+                        : undefined,
+                    handleDrop: handlers.drop
+                        ? (view, event, _slice, moved) => onHandled({
+                            kind: "drop",
+                            transfer: readDataTransfer(event.dataTransfer),
+                            position: view.posAtCoords({left: event.clientX, top: event.clientY})?.pos ?? null,
+                            moved,
+                        })
+                        : undefined,
+                },
+            }),
+        ],
+    })
+}
+
+function readDataTransfer(data: DataTransfer | null): TransferPayload {
+    // Browsers return an empty string for formats the transfer does not contain.
+    return {
+        html: data?.getData("text/html") || null,
+        text: data?.getData("text/plain") || null,
+        files: Array.from(data?.files ?? [], (file) => ({name: file.name, file})),
+    }
+}
+
+function ruleSchemaType<T>(types: Record<string, T>, kind: "node" | "mark", name: string, find: RegExp): T {
+    const type = types[name]
+    if (type == null) {
+        throw new Error(`the rule ${find} uses the ${kind} "${name}", which is not in the schema`)
+    }
+    return type
+}
+
+function createInputRule(schema: ProseMirrorSchema, find: RegExp, action: RuleAction) {
+    const core = getTiptapCoreModule()
+    switch (action.kind) {
+        case "replace":
+            return core.textInputRule({find, replace: action.text})
+        case "mark":
+            return core.markInputRule({
+                find,
+                type: ruleSchemaType(schema.marks, "mark", action.name, find),
+                getAttributes: action.attributes ?? undefined,
+            })
+        case "wrap_in":
+            return core.wrappingInputRule({
+                find,
+                type: ruleSchemaType(schema.nodes, "node", action.name, find),
+                getAttributes: action.attributes ?? undefined,
+            })
+        case "set_block":
+            return core.textblockTypeInputRule({
+                find,
+                type: ruleSchemaType(schema.nodes, "node", action.name, find),
+                getAttributes: action.attributes ?? undefined,
+            })
+    }
+}
+
+function createPasteRule(schema: ProseMirrorSchema, find: RegExp, action: PasteRuleRequest["action"]) {
+    const core = getTiptapCoreModule()
+    switch (action.kind) {
+        case "replace":
+            return core.textPasteRule({find, replace: action.text})
+        case "mark":
+            return core.markPasteRule({
+                find,
+                type: ruleSchemaType(schema.marks, "mark", action.name, find),
+                getAttributes: action.attributes ?? undefined,
+            })
+    }
+}
+
+// Patterns are compiled up front, so invalid ones fail the registration instead of the editor.
+function createRulesExtension(inputRules: InputRuleRequest[], pasteRules: PasteRuleRequest[]): TiptapExtension {
+    const {Extension} = getTiptapCoreModule()
+    const compiledInputRules = inputRules.map(({find, action}) => ({find: new RegExp(find), action}))
+    const compiledPasteRules = pasteRules.map(({find, action}) => ({find: new RegExp(find, "g"), action}))
+
+    return Extension.create({
+        name: "leptosRules",
+        addInputRules() {
+            return compiledInputRules.map(({find, action}) => createInputRule(this.editor.schema, find, action))
+        },
+        addPasteRules() {
+            return compiledPasteRules.map(({find, action}) => createPasteRule(this.editor.schema, find, action))
+        },
+    })
+}
+
+type RuntimeHandlers = {
+    onNodeView?: OnNodeViewEvent
+    onHandled?: OnHandledEvent
+}
+
 function buildRuntimeConfiguration(
-    extensionNames: string[],
+    request: Pick<
+        CreateRequest,
+        | "extensions"
+        | "custom_extensions"
+        | "node_specs"
+        | "mark_specs"
+        | "node_views"
+        | "shortcuts"
+        | "input_rules"
+        | "paste_rules"
+    >,
     context: ExtensionCreateContext,
+    {onNodeView = () => {}, onHandled = () => false}: RuntimeHandlers = {},
 ): BridgeResult<RuntimeConfiguration> {
-    const resolvedDescriptors = resolveDescriptors(extensionNames)
+    const resolvedDescriptors = resolveDescriptors(request.extensions)
     if (!resolvedDescriptors.ok) {
         return resolvedDescriptors
     }
@@ -563,23 +2226,11 @@ function buildRuntimeConfiguration(
     const seenActiveKeys = new Set<ActiveKey>()
 
     for (const {descriptor, activeKeys} of resolvedDescriptors.value) {
-        let created: ReturnType<ExtensionDescriptor["create"]>
-        try {
-            created = descriptor.create(context)
-        } catch (error) {
-            const message = `Can not create Tiptap instance, as extension "${descriptor.name}" failed to initialize.`
-            console.error(message, error)
-            return errorResult(
-                "extension_registration_failed",
-                `${message} ${error instanceof Error ? error.message : String(error)}`,
-            )
-        }
-
-        if (Array.isArray(created)) {
-            extensions.push(...created)
-        } else {
-            extensions.push(created)
+        const created = createExtensions(descriptor.name, () => descriptor.create(context))
+        if (!created.ok) {
+            return created
         }
+        extensions.push(...created.value)
 
         for (const activeKey of activeKeys) {
             if (seenActiveKeys.has(activeKey)) {
@@ -611,11 +2262,67 @@ function buildRuntimeConfiguration(
         }
     }
 
-    return okResult({
+    const customConfiguration = buildCustomConfiguration(request.custom_extensions ?? [], {
         extensions,
         commandHandlers,
         activeStateContributors,
+        customCommandHandlers: new Map(),
+        customActiveStateContributors: [],
+        specCommandHandlers: new Map(),
+        nodeViews: new Map(),
     })
+    if (!customConfiguration.ok) {
+        return customConfiguration
+    }
+
+    const configuration = customConfiguration.value
+    const nodeViews: NodeViewHost = {
+        names: new Set(request.node_views ?? []),
+        emit: onNodeView,
+        views: configuration.nodeViews,
+    }
+    const specConfiguration = buildSpecConfiguration(
+        request.node_specs ?? [],
+        request.mark_specs ?? [],
+        nodeViews,
+        configuration,
+    )
+    if (!specConfiguration.ok) {
+        return specConfiguration
+    }
+
+    const inputRules = request.input_rules ?? []
+    const pasteRules = request.paste_rules ?? []
+    if (inputRules.length > 0 || pasteRules.length > 0) {
+        const rulesExtensions = createExtensions("rules", () => createRulesExtension(inputRules, pasteRules))
+        if (!rulesExtensions.ok) {
+            return rulesExtensions
+        }
+        configuration.extensions.push(...rulesExtensions.value)
+    }
+
+    const {shortcuts} = request
+    if (shortcuts != null) {
+        const shortcutExtensions = createExtensions("shortcuts", () => createShortcutExtension(shortcuts, onHandled))
+        if (!shortcutExtensions.ok) {
+            return shortcutExtensions
+        }
+        configuration.extensions.push(...shortcutExtensions.value)
+    }
+
+    const transferHandlers = request.transfer_handlers
+    if (transferHandlers != null && (transferHandlers.paste || transferHandlers.drop)) {
+        const transferExtensions = createExtensions(
+            "transfer_handlers",
+            () => createTransferExtension(transferHandlers, onHandled),
+        )
+        if (!transferExtensions.ok) {
+            return transferExtensions
+        }
+        configuration.extensions.push(...transferExtensions.value)
+    }
+
+    return specConfiguration
 }
 
 export function init_bridge_runtime(): void {
@@ -626,6 +2333,11 @@ export function create(
     request: CreateRequest,
     onChange: () => void,
     onSelection: OnSelection,
+    onEvent: OnEditorEvent = () => {
+    },
+    onNodeView: OnNodeViewEvent = () => {
+    },
+    onHandled: OnHandledEvent = () => false,
 ): BridgeResult<ReadyPayload> {
     const unusedEditorId = requireUnusedEditorId(request.id)
     if (!unusedEditorId.ok) {
@@ -637,16 +2349,61 @@ export function create(
         return editorElement
     }
 
-    const runtimeConfig = buildRuntimeConfiguration(request.extensions, {
+    const bound: { collaboration?: CollaborationDocument } = {}
+    const collaborationContext: CollaborationCreateContext | null = request.collaboration == null
+        ? null
+        : {
+            ...request.collaboration,
+            on_local_update: (update) => onEvent({kind: "collaboration_update", update}),
+            bind: (document) => {
+                bound.collaboration = document
+            },
+        }
+    // Releases the collaboration document of an editor that will not be created.
+    const abandon = <T>(result: BridgeResult<T>): BridgeResult<T> => {
+        bound.collaboration?.destroy()
+        return result
+    }
+    const runtimeConfig = buildRuntimeConfiguration(request, {
         placeholder: request.placeholder,
-    })
+        collaboration: collaborationContext,
+        on_tracked_changes: (changes) => onEvent({kind: "tracked_changes", changes}),
+        on_comments: (threads) => onEvent({kind: "comments", threads}),
+        on_active_comments: (threadIds) => onEvent({kind: "active_comments", thread_ids: threadIds}),
+    }, {onNodeView, onHandled})
     if (!runtimeConfig.ok) {
-        return runtimeConfig
+        return abandon(runtimeConfig)
+    }
+
+    if (collaborationContext != null && bound.collaboration == null) {
+        return extensionError(
+            `Can not create Tiptap instance "${request.id}" with a collaboration document, as the collaboration extension is not enabled.`,
+        )
     }
 
     const parsedContent = parseContent(request.content)
     if (!parsedContent.ok) {
-        return parsedContent
+        return abandon(parsedContent)
+    }
+
+    const documentChangeFormat: DocumentChangeFormat | undefined = request.document_changes ?? undefined
+    const collabConfig: CollabConfig | undefined = request.collab ?? undefined
+    let documentChanges: DocumentChangeTracker | undefined
+    const extensions = runOperation("create_runtime_extensions", () => [
+        ...runtimeConfig.value.extensions,
+        ...(documentChangeFormat === "steps"
+            ? [createStepRecorderExtension((steps) => documentChanges?.recordSteps(steps))]
+            : []),
+        ...(collabConfig != null ? [createCollabExtension(collabConfig)] : []),
+        createRemoteCursorsExtension(),
+        createDecorationsExtension(
+            request.decoration_clicks === true ? (click) => onEvent({kind: "decoration_click", click}) : null,
+        ),
+        createSearchExtension((matches) => onEvent({kind: "search_matches", matches})),
+        createImageUploadsExtension(),
+    ])
+    if (!extensions.ok) {
+        return abandon(extensions)
     }
 
     const generation = allocateGeneration()
@@ -656,14 +2413,21 @@ export function create(
         editorFactory({
             element: editorElement.value,
             editable: request.editable,
-            extensions: runtimeConfig.value.extensions,
+            extensions: extensions.value,
             injectCSS: false,
-            content: parsedContent.value,
+            // The collaboration document is the source of truth for the content of bound editors.
+            content: bound.collaboration == null ? parsedContent.value : undefined,
             onUpdate: () => {
                 onChange()
             },
-            onTransaction: ({editor}) => {
-                emitSelectionForCurrentEditor(request.id, editor)
+            onTransaction: ({editor, transaction}) => {
+                emitTransactionEffectsForCurrentEditor(request.id, editor, transaction)
+            },
+            onFocus: () => {
+                onEvent({kind: "focus_change", focused: true})
+            },
+            onBlur: () => {
+                onEvent({kind: "focus_change", focused: false})
             },
         }),
     )
@@ -672,7 +2436,7 @@ export function create(
         if (slot?.generation === generation && slot.entry == null) {
             editorSlots.delete(request.id)
         }
-        return createdEditor
+        return abandon(createdEditor)
     }
 
     const editorEntry = setEditorEntry(
@@ -680,13 +2444,19 @@ export function create(
         generation,
         createdEditor.value,
         onSelection,
+        onEvent,
         runtimeConfig.value,
     )
     if (editorEntry == null) {
         const message = `Can not finish creating Tiptap instance "${request.id}", as its registration was replaced during creation.`
         console.error(message)
-        return errorResult("operation_failed", message, "create_editor")
+        return abandon(errorResult("operation_failed", message, "create_editor"))
+    }
+
+    if (collabConfig != null) {
+        editorEntry.collab = {}
     }
+    editorEntry.collaboration = bound.collaboration
 
     const initialSelection = runOperation(
         "read_initial_selection_state",
@@ -697,10 +2467,31 @@ export function create(
         return initialSelection
     }
 
+    const documentSnapshot = runOperation("read_initial_document_snapshot", () => {
+        if (documentChangeFormat == null) {
+            return null
+        }
+
+        documentChanges = createDocumentChangeTracker(documentChangeFormat, editorEntry.editor.state.doc)
+        editorEntry.documentChanges = documentChanges
+        return documentChanges.snapshot(editorEntry.editor.state.doc)
+    })
+    if (!documentSnapshot.ok) {
+        destroySlot(request.id)
+        return documentSnapshot
+    }
+
     editorEntry.lastSelectionState = initialSelection.value
+    if (request.local_selection === true) {
+        editorEntry.lastLocalSelection = readSelectionRange(editorEntry.editor)
+    }
+    if (request.transactions === true) {
+        editorEntry.transactions = {lastSelection: readSelectionRange(editorEntry.editor), metaKeys: new Set()}
+    }
     return okResult({
         generation,
         selection_state: initialSelection.value,
+        document_snapshot: documentSnapshot.value,
     })
 }
 
@@ -712,12 +2503,26 @@ export function command(request: {
     id: string
     generation: number
     command: EditorCommand
+    focus?: boolean
 }): BridgeResult<EmptyResponse> {
     const {id, generation, command} = request
+    const options: CommandOptions = {focus: request.focus ?? true}
 
     return withEditor(id, generation, command.kind, (editorEntry) => {
+        if (command.kind === "set_meta") {
+            editorEntry.transactions?.metaKeys.add(command.key)
+        }
+
+        if (command.kind === "spec_command") {
+            return executeSpecCommand(editorEntry, id, command, options)
+        }
+
+        if (command.kind === "update_node_view_attributes") {
+            return executeNodeViewCommand(editorEntry, id, command)
+        }
+
         if (isRuntimeCommand(command)) {
-            return executeRuntimeCommand(editorEntry.editor, command)
+            return executeRuntimeCommand(editorEntry.editor, command, options)
         }
 
         if (isCoreCommand(command)) {
@@ -733,7 +2538,7 @@ export function command(request: {
             )
         }
 
-        return runCommand(extensionCommand.kind, () => handler(editorEntry.editor, extensionCommand))
+        return runCommand(extensionCommand.kind, () => handler(editorEntry.editor, extensionCommand, options))
     })
 }
 
@@ -753,6 +2558,13 @@ export function document(call: DocumentCall): BridgeResult<DocumentResponse> {
                     content: serializedContent.value,
                 })
             })
+        case "get_text":
+            return withEditor(id, generation, request.kind, ({editor}) =>
+                runOperation(request.kind, (): DocumentResponse => ({
+                    kind: "text",
+                    text: editor.getText(),
+                })),
+            )
         case "set_content":
             return withEditor(id, generation, request.kind, (editorEntry) => {
                 const parsedContent = parseContent(request.content)
@@ -761,24 +2573,117 @@ export function document(call: DocumentCall): BridgeResult<DocumentResponse> {
                 }
 
                 return runCommand(request.kind, () =>
-                    editorEntry.editor.commands.setContent(
-                        parsedContent.value,
-                        // Match Tiptap's own default: emit an update so the host can read the
-                        // new document back through `on_change`. Callers who want a silent
-                        // replacement opt out via `TiptapSetContentOptions { emit_update: false }`.
-                        request.options?.emit_update ?? true,
-                        toParseOptions(request.options?.parse_options),
-                        {
-                            errorOnInvalidContent: request.options?.error_on_invalid_content,
-                        },
-                    ),
+                    editorEntry.editor
+                        .chain()
+                        // Lets extensions tell the replacement apart from edits that happen to
+                        // span the whole document, such as typing over a select-all.
+                        .setMeta(SET_CONTENT_META, true)
+                        .setContent(
+                            parsedContent.value,
+                            // Match Tiptap's own default: emit an update so the host can read the
+                            // new document back through `on_change`. Callers who want a silent
+                            // replacement opt out via `TiptapSetContentOptions { emit_update: false }`.
+                            request.options?.emit_update ?? true,
+                            toParseOptions(request.options?.parse_options),
+                            {
+                                errorOnInvalidContent: request.options?.error_on_invalid_content,
+                            },
+                        )
+                        .run(),
                 )
             })
+        case "get_collab_state":
+            return withCollabEditor(id, generation, request.kind, (editorEntry) =>
+                runOperation(request.kind, (): DocumentResponse => ({
+                    kind: "collab_state",
+                    version: getTiptapPmCollabModule().getVersion(editorEntry.editor.state),
+                    sendable: readSendableSteps(editorEntry.editor),
+                })))
+        case "receive_transaction":
+            return withCollabEditor(id, generation, request.kind, ({editor}) => {
+                if (request.steps.length !== request.client_ids.length) {
+                    const message = `Can not receive ${request.steps.length} collaboration steps with ${request.client_ids.length} client ids.`
+                    console.error(message)
+                    return errorResult("operation_failed", message, request.kind)
+                }
+
+                return runCommand(request.kind, () => {
+                    const {Step} = getTiptapPmTransformModule()
+                    const {receiveTransaction} = getTiptapPmCollabModule()
+                    const steps = request.steps.map((step) => Step.fromJSON(editor.schema, step))
+                    editor.view.dispatch(receiveTransaction(editor.state, steps, request.client_ids))
+                })
+            })
+        case "apply_collaboration_update":
+            return withCollaborationEditor(id, generation, request.kind, (collaboration) =>
+                runCommand(request.kind, () => collaboration.apply_update(request.update)))
+        case "get_collaboration_state_vector":
+            return withCollaborationEditor(id, generation, request.kind, (collaboration) =>
+                runOperation(request.kind, (): DocumentResponse => ({
+                    kind: "collaboration_state_vector",
+                    state_vector: collaboration.encode_state_vector(),
+                })))
+        case "encode_collaboration_state":
+            return withCollaborationEditor(id, generation, request.kind, (collaboration) =>
+                runOperation(request.kind, (): DocumentResponse => ({
+                    kind: "collaboration_update",
+                    update: collaboration.encode_state_as_update(request.state_vector),
+                })))
+        case "custom_command":
+            return withEditor(id, generation, request.name, (editorEntry) => {
+                const handler = editorEntry.customCommandHandlers.get(request.name)
+                if (handler == null) {
+                    return extensionError(
+                        `Can not execute ${request.name} for Tiptap instance "${id}", as no active custom extension provides this command.`,
+                        request.name,
+                    )
+                }
+
+                return runOperation(request.name, (): DocumentResponse => ({
+                    kind: "custom_command",
+                    value: handler(editorEntry.editor, request.payload) ?? null,
+                }))
+            })
+        case "find":
+            return withEditor(id, generation, request.kind, ({editor}) =>
+                runOperation(request.kind, (): DocumentResponse => {
+                    const pattern = createSearchPattern(request.query, request.options)
+                    editor.view.dispatch(
+                        editor.state.tr
+                            .setMeta(SEARCH_META, {kind: "find", pattern, regex: request.options.regex})
+                            .setMeta("addToHistory", false),
+                    )
+                    return {
+                        kind: "search_matches",
+                        matches: readSearchState(editor).matches.map(({from, to}) => ({from, to})),
+                    }
+                }),
+            )
+        case "get_document_snapshot":
+            return withEditor(id, generation, request.kind, (editorEntry) => {
+                const documentChanges = editorEntry.documentChanges
+                if (documentChanges == null) {
+                    const message = `Can not read a document snapshot for Tiptap instance "${id}", as it was created without a document change stream.`
+                    console.error(message)
+                    return errorResult("operation_failed", message, request.kind)
+                }
+
+                return runOperation(request.kind, (): DocumentResponse => {
+                    emitDocumentChange(editorEntry)
+                    return {
+                        kind: "document_snapshot",
+                        change: documentChanges.snapshot(editorEntry.editor.state.doc),
+                    }
+                })
+            })
     }
 }
 
 export const __testing = {
+    createSearchPattern,
+    findSearchMatches,
     getEditorEntry,
+    selectionTransaction,
     getRegisteredExtensionNames(): string[] {
         return [...extensionRegistry.keys()]
     },
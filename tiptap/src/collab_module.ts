@@ -0,0 +1,9 @@
+import * as collabModule from "@tiptap/pm/collab"
+
+import {getBridgeBindings} from "./bridge_api.ts"
+
+// Only the `collab` feature ships `prosemirror-collab`. The runtime reads it from the hosted
+// modules once a collab editor is created.
+export function install_collab_module(): void {
+    getBridgeBindings().modules["@tiptap/pm/collab"] = collabModule
+}
@@ -0,0 +1,132 @@
+import assert from "node:assert/strict"
+import test from "node:test"
+
+import {createDocumentChangeTracker, diffDocuments, type DocumentNode} from "./document_changes.ts"
+
+function node(
+    type: string,
+    options: { attrs?: Record<string, unknown>; children?: DocumentNode[]; text?: string } = {},
+): DocumentNode {
+    const attrs = options.attrs ?? {}
+    const children = options.children ?? []
+
+    return {
+        type,
+        isText: options.text != null,
+        text: options.text,
+        attrs,
+        marks: [],
+        childCount: children.length,
+        child(index) {
+            const child = children[index]
+            if (child == null) {
+                throw new Error(`fake node has no child at ${index}`)
+            }
+            return child
+        },
+        toJSON() {
+            return {
+                type,
+                ...(Object.keys(attrs).length > 0 ? {attrs} : {}),
+                ...(children.length > 0 ? {content: children.map((child) => child.toJSON())} : {}),
+                ...(options.text != null ? {text: options.text} : {}),
+            }
+        },
+    }
+}
+
+function paragraph(text: string): DocumentNode {
+    return node("paragraph", {children: [node("text", {text})]})
+}
+
+function doc(...children: DocumentNode[]): DocumentNode {
+    return node("doc", {children})
+}
+
+test("diffs only the changed text node when siblings are shared", () => {
+    const first = paragraph("first")
+    const last = paragraph("last")
+
+    assert.deepEqual(
+        diffDocuments(doc(first, paragraph("middle"), last), doc(first, paragraph("middle!"), last)),
+        [{op: "replace", path: "/content/1/content/0/text", value: "middle!"}],
+    )
+})
+
+test("emits array insertions and removals at the changed index", () => {
+    const first = paragraph("first")
+    const last = paragraph("last")
+
+    assert.deepEqual(
+        diffDocuments(doc(first, last), doc(first, paragraph("inserted"), last)),
+        [{op: "add", path: "/content/1", value: paragraph("inserted").toJSON()}],
+    )
+    assert.deepEqual(
+        diffDocuments(doc(first, paragraph("removed"), paragraph("also removed"), last), doc(first, last)),
+        [
+            {op: "remove", path: "/content/1"},
+            {op: "remove", path: "/content/1"},
+        ],
+    )
+})
+
+test("adds, replaces, and removes optional node members", () => {
+    const text = node("text", {text: "a"})
+    const plain = node("paragraph", {children: [text]})
+    const centered = node("paragraph", {attrs: {textAlign: "center"}, children: [text]})
+    const right = node("paragraph", {attrs: {textAlign: "right"}, children: [text]})
+
+    assert.deepEqual(diffDocuments(doc(plain), doc(centered)), [
+        {op: "add", path: "/content/0/attrs", value: {textAlign: "center"}},
+    ])
+    assert.deepEqual(diffDocuments(doc(centered), doc(right)), [
+        {op: "replace", path: "/content/0/attrs", value: {textAlign: "right"}},
+    ])
+    assert.deepEqual(diffDocuments(doc(plain), doc(node("paragraph"))), [
+        {op: "remove", path: "/content/0/content"},
+    ])
+    assert.deepEqual(diffDocuments(doc(plain), doc(node("heading", {children: [text]}))), [
+        {op: "replace", path: "/content/0", value: node("heading", {children: [text]}).toJSON()},
+    ])
+})
+
+test("numbers json patch changes consecutively and skips unchanged documents", () => {
+    const initial = doc(paragraph("a"))
+    const tracker = createDocumentChangeTracker("json_patch", initial)
+
+    assert.deepEqual(tracker.snapshot(initial), {
+        version: 0,
+        payload: {format: "snapshot", document: initial.toJSON()},
+    })
+    assert.equal(tracker.flush(initial), undefined)
+
+    const next = doc(paragraph("ab"))
+    assert.deepEqual(tracker.flush(next), {
+        version: 1,
+        payload: {
+            format: "json_patch",
+            operations: [{op: "replace", path: "/content/0/content/0/text", value: "ab"}],
+        },
+    })
+    assert.equal(tracker.version, 1)
+    assert.equal(tracker.snapshot(next).version, 1)
+    assert.throws(() => tracker.snapshot(doc()), /must be flushed/)
+})
+
+test("emits recorded steps once per changed document", () => {
+    const tracker = createDocumentChangeTracker("steps", doc())
+
+    tracker.recordSteps([{stepType: "replace", from: 0, to: 0}])
+    tracker.recordSteps([{stepType: "addMark", from: 1, to: 2}])
+
+    assert.deepEqual(tracker.flush(doc(paragraph("a"))), {
+        version: 1,
+        payload: {
+            format: "steps",
+            steps: [
+                {stepType: "replace", from: 0, to: 0},
+                {stepType: "addMark", from: 1, to: 2},
+            ],
+        },
+    })
+})
@@ -0,0 +1,214 @@
+import type {
+    DocumentChange,
+    DocumentChangeFormat,
+    JsonPatchOperation,
+} from "./bridge_api.ts"
+
+// The subset of ProseMirror's `Node` that the change stream relies on. ProseMirror shares unchanged
+// subtrees between document versions, so identity checks keep diffs proportional to the edit.
+export type DocumentNode = {
+    readonly type: unknown
+    readonly isText: boolean
+    readonly text?: string | null
+    readonly attrs: Readonly<Record<string, unknown>>
+    readonly marks: ReadonlyArray<{ toJSON(): unknown }>
+    readonly childCount: number
+    child(index: number): DocumentNode
+    toJSON(): unknown
+}
+
+export type DocumentChangeTracker = {
+    readonly format: DocumentChangeFormat
+    readonly version: number
+    recordSteps(steps: unknown[]): void
+    flush(document: DocumentNode): DocumentChange | undefined
+    snapshot(document: DocumentNode): DocumentChange
+}
+
+export function createDocumentChangeTracker(
+    format: DocumentChangeFormat,
+    initialDocument: DocumentNode,
+): DocumentChangeTracker {
+    let version = 0
+    let lastDocument = initialDocument
+    let pendingSteps: unknown[] = []
+
+    return {
+        format,
+        get version() {
+            return version
+        },
+        recordSteps(steps) {
+            pendingSteps.push(...steps)
+        },
+        flush(document) {
+            if (document === lastDocument) {
+                pendingSteps = []
+                return undefined
+            }
+
+            const previousDocument = lastDocument
+            lastDocument = document
+            version += 1
+
+            if (format === "steps") {
+                const steps = pendingSteps
+                pendingSteps = []
+                return {version, payload: {format, steps}}
+            }
+
+            return {version, payload: {format, operations: diffDocuments(previousDocument, document)}}
+        },
+        snapshot(document) {
+            if (document !== lastDocument) {
+                throw new Error("document changes must be flushed before taking a snapshot")
+            }
+
+            return {version, payload: {format: "snapshot", document: document.toJSON()}}
+        },
+    }
+}
+
+// Produces RFC 6902 operations that transform `previous.toJSON()` into `next.toJSON()`.
+export function diffDocuments(previous: DocumentNode, next: DocumentNode): JsonPatchOperation[] {
+    const operations: JsonPatchOperation[] = []
+    diffNode(previous, next, "", operations)
+    return operations
+}
+
+function diffNode(
+    previous: DocumentNode,
+    next: DocumentNode,
+    path: string,
+    operations: JsonPatchOperation[],
+): void {
+    if (previous === next) {
+        return
+    }
+
+    if (previous.type !== next.type || previous.isText !== next.isText) {
+        operations.push({op: "replace", path, value: next.toJSON()})
+        return
+    }
+
+    if (previous.isText && previous.text !== next.text) {
+        operations.push({op: "replace", path: `${path}/text`, value: next.text})
+    }
+
+    diffMember(`${path}/attrs`, attrsJson(previous), attrsJson(next), operations)
+    diffMember(`${path}/marks`, marksJson(previous), marksJson(next), operations)
+    diffContent(previous, next, path, operations)
+}
+
+function diffContent(
+    previous: DocumentNode,
+    next: DocumentNode,
+    path: string,
+    operations: JsonPatchOperation[],
+): void {
+    const contentPath = `${path}/content`
+
+    if (previous.childCount === 0 || next.childCount === 0) {
+        diffMember(contentPath, contentJson(previous), contentJson(next), operations)
+        return
+    }
+
+    let prefix = 0
+    while (
+        prefix < previous.childCount
+        && prefix < next.childCount
+        && previous.child(prefix) === next.child(prefix)
+    ) {
+        prefix += 1
+    }
+
+    let suffix = 0
+    while (
+        suffix < previous.childCount - prefix
+        && suffix < next.childCount - prefix
+        && previous.child(previous.childCount - 1 - suffix) === next.child(next.childCount - 1 - suffix)
+    ) {
+        suffix += 1
+    }
+
+    const previousChanged = previous.childCount - prefix - suffix
+    const nextChanged = next.childCount - prefix - suffix
+    const paired = Math.min(previousChanged, nextChanged)
+
+    for (let offset = 0; offset < paired; offset += 1) {
+        const index = prefix + offset
+        diffNode(previous.child(index), next.child(index), `${contentPath}/${index}`, operations)
+    }
+
+    for (let offset = paired; offset < previousChanged; offset += 1) {
+        operations.push({op: "remove", path: `${contentPath}/${prefix + paired}`})
+    }
+
+    for (let offset = paired; offset < nextChanged; offset += 1) {
+        const index = prefix + offset
+        operations.push({op: "add", path: `${contentPath}/${index}`, value: next.child(index).toJSON()})
+    }
+}
+
+function diffMember(
+    path: string,
+    previous: unknown,
+    next: unknown,
+    operations: JsonPatchOperation[],
+): void {
+    if (previous === undefined && next === undefined) {
+        return
+    }
+
+    if (previous === undefined) {
+        operations.push({op: "add", path, value: next})
+    } else if (next === undefined) {
+        operations.push({op: "remove", path})
+    } else if (!jsonEqual(previous, next)) {
+        operations.push({op: "replace", path, value: next})
+    }
+}
+
+// Mirror ProseMirror's `Node.toJSON`, which omits empty attributes, marks, and content.
+function attrsJson(node: DocumentNode): unknown {
+    return Object.keys(node.attrs).length > 0 ? node.attrs : undefined
+}
+
+function marksJson(node: DocumentNode): unknown {
+    return node.marks.length > 0 ? node.marks.map((mark) => mark.toJSON()) : undefined
+}
+
+function contentJson(node: DocumentNode): unknown {
+    if (node.childCount === 0) {
+        return undefined
+    }
+
+    const content: unknown[] = []
+    for (let index = 0; index < node.childCount; index += 1) {
+        content.push(node.child(index).toJSON())
+    }
+    return content
+}
+
+function jsonEqual(left: unknown, right: unknown): boolean {
+    if (left === right) {
+        return true
+    }
+
+    if (typeof left !== "object" || typeof right !== "object" || left == null || right == null) {
+        return false
+    }
+
+    if (Array.isArray(left) || Array.isArray(right)) {
+        return Array.isArray(left)
+            && Array.isArray(right)
+            && left.length === right.length
+            && left.every((value, index) => jsonEqual(value, right[index]))
+    }
+
+    const leftRecord = left as Record<string, unknown>
+    const rightRecord = right as Record<string, unknown>
+    const leftKeys = Object.keys(leftRecord)
+    return leftKeys.length === Object.keys(rightRecord).length
+        && leftKeys.every((key) => key in rightRecord && jsonEqual(leftRecord[key], rightRecord[key]))
+}
@@ -1,15 +1,15 @@
 import {Blockquote} from "@tiptap/extension-blockquote"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "blockquote",
     create: () => Blockquote,
     commands: {
-        set_blockquote: (editor) => editor.chain().focus().setBlockquote().run(),
-        toggle_blockquote: (editor) => editor.chain().focus().toggleBlockquote().run(),
-        unset_blockquote: (editor) => editor.chain().focus().unsetBlockquote().run(),
+        set_blockquote: (editor, _command, options) => commandChain(editor, options).setBlockquote().run(),
+        toggle_blockquote: (editor, _command, options) => commandChain(editor, options).toggleBlockquote().run(),
+        unset_blockquote: (editor, _command, options) => commandChain(editor, options).unsetBlockquote().run(),
     },
     ...activeState([
         ["blockquote", (editor) => editor.isActive("blockquote")],
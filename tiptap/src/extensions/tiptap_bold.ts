@@ -1,15 +1,15 @@
 import {Bold} from "@tiptap/extension-bold"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "bold",
     create: () => Bold,
     commands: {
-        set_bold: (editor) => editor.chain().focus().setBold().run(),
-        toggle_bold: (editor) => editor.chain().focus().toggleBold().run(),
-        unset_bold: (editor) => editor.chain().focus().unsetBold().run(),
+        set_bold: (editor, _command, options) => commandChain(editor, options).setBold().run(),
+        toggle_bold: (editor, _command, options) => commandChain(editor, options).toggleBold().run(),
+        unset_bold: (editor, _command, options) => commandChain(editor, options).unsetBold().run(),
     },
     ...activeState([
         ["bold", (editor) => editor.isActive("bold")],
@@ -1,13 +1,13 @@
 import {BulletList} from "@tiptap/extension-bullet-list"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "bullet_list",
     create: () => BulletList,
     commands: {
-        toggle_bullet_list: (editor) => editor.chain().focus().toggleBulletList().run(),
+        toggle_bullet_list: (editor, _command, options) => commandChain(editor, options).toggleBulletList().run(),
     },
     ...activeState([
         ["bullet_list", (editor) => editor.isActive("bulletList")],
@@ -1,15 +1,15 @@
 import {Code} from "@tiptap/extension-code"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "code",
     create: () => Code,
     commands: {
-        set_code: (editor) => editor.chain().focus().setCode().run(),
-        toggle_code: (editor) => editor.chain().focus().toggleCode().run(),
-        unset_code: (editor) => editor.chain().focus().unsetCode().run(),
+        set_code: (editor, _command, options) => commandChain(editor, options).setCode().run(),
+        toggle_code: (editor, _command, options) => commandChain(editor, options).toggleCode().run(),
+        unset_code: (editor, _command, options) => commandChain(editor, options).unsetCode().run(),
     },
 }
 
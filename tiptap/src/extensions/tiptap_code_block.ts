@@ -1,7 +1,7 @@
 import {CodeBlock} from "@tiptap/extension-code-block"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 function buildCodeBlockAttributes(attributes?: { language?: string | null } | null) {
     if (attributes?.language == null) {
@@ -17,10 +17,10 @@ const descriptor: ExtensionDescriptor = {
     name: "code_block",
     create: () => CodeBlock,
     commands: {
-        set_code_block: (editor, command) =>
-            editor.chain().focus().setCodeBlock(buildCodeBlockAttributes(command.attributes)).run(),
-        toggle_code_block: (editor, command) =>
-            editor.chain().focus().toggleCodeBlock(buildCodeBlockAttributes(command.attributes)).run(),
+        set_code_block: (editor, command, options) =>
+            commandChain(editor, options).setCodeBlock(buildCodeBlockAttributes(command.attributes)).run(),
+        toggle_code_block: (editor, command, options) =>
+            commandChain(editor, options).toggleCodeBlock(buildCodeBlockAttributes(command.attributes)).run(),
     },
 }
 
@@ -0,0 +1,47 @@
+import {Collaboration} from "@tiptap/extension-collaboration"
+import * as Y from "yjs"
+
+import type {ExtensionDescriptor} from "../bridge_api.ts"
+import {registerOfficialExtension} from "../bridge_extension_helpers.ts"
+
+// Marks updates received from Rust, so they are not echoed back as local updates.
+const remoteOrigin = Symbol("leptos-tiptap remote update")
+
+const descriptor: ExtensionDescriptor = {
+    name: "collaboration",
+    create: ({collaboration}) => {
+        // Editors without a collaboration configuration keep their regular document and history.
+        if (collaboration == null) {
+            return []
+        }
+
+        const document = new Y.Doc()
+        if (collaboration.initial_update != null) {
+            Y.applyUpdate(document, Uint8Array.from(collaboration.initial_update), remoteOrigin)
+        }
+
+        document.on("update", (update: Uint8Array, origin: unknown) => {
+            if (origin !== remoteOrigin) {
+                collaboration.on_local_update(Array.from(update))
+            }
+        })
+
+        collaboration.bind({
+            apply_update: (update) => Y.applyUpdate(document, Uint8Array.from(update), remoteOrigin),
+            encode_state_vector: () => Array.from(Y.encodeStateVector(document)),
+            encode_state_as_update: (stateVector) => Array.from(
+                Y.encodeStateAsUpdate(document, stateVector == null ? undefined : Uint8Array.from(stateVector)),
+            ),
+            destroy: () => document.destroy(),
+        })
+
+        return Collaboration.configure({
+            document,
+            field: collaboration.field,
+        })
+    },
+}
+
+export function register_collaboration(): void {
+    registerOfficialExtension(descriptor)
+}
@@ -0,0 +1,193 @@
+import {Extension, Mark, mergeAttributes, type Editor} from "@tiptap/core"
+import type {Mark as ProseMirrorMark, Node as ProseMirrorNode} from "@tiptap/pm/model"
+import {Plugin, PluginKey, type EditorState} from "@tiptap/pm/state"
+
+import type {CommentThread, ExtensionDescriptor} from "../bridge_api.ts"
+import {registerOfficialExtension} from "../bridge_extension_helpers.ts"
+
+const COMMENT = "comment"
+
+const commentsKey = new PluginKey("leptosTiptapComments")
+
+const commentMark = Mark.create({
+    name: COMMENT,
+    // Text typed at the end of an anchor is not part of the comment.
+    inclusive: false,
+    // Comments of different threads may overlap.
+    excludes: "",
+    addAttributes: () => ({
+        threadId: {
+            default: null,
+            parseHTML: (element) => element.getAttribute("data-thread-id"),
+            renderHTML: (attributes) => ({"data-thread-id": attributes.threadId}),
+        },
+        resolved: {
+            default: false,
+            parseHTML: (element) => element.getAttribute("data-resolved") === "true",
+            renderHTML: (attributes) => (attributes.resolved === true ? {"data-resolved": "true"} : {}),
+        },
+    }),
+    parseHTML: () => [{tag: "span[data-thread-id]"}],
+    renderHTML: ({mark, HTMLAttributes}) => [
+        "span",
+        mergeAttributes({class: mark.attrs.resolved === true ? "comment comment-resolved" : "comment"}, HTMLAttributes),
+        0,
+    ],
+})
+
+// Lists the threads anchored in `doc`, ordered by their first range. Adjacent anchored nodes
+// form a single range.
+function collectCommentThreads(doc: ProseMirrorNode): CommentThread[] {
+    const threads = new Map<string, CommentThread>()
+    doc.descendants((node, position) => {
+        if (!node.isInline) {
+            return true
+        }
+
+        for (const mark of node.marks) {
+            if (mark.type.name !== COMMENT) {
+                continue
+            }
+
+            const threadId = String(mark.attrs.threadId)
+            const range = {from: position, to: position + node.nodeSize}
+            const thread = threads.get(threadId)
+            if (thread == null) {
+                threads.set(threadId, {thread_id: threadId, ranges: [range], resolved: mark.attrs.resolved === true})
+                continue
+            }
+
+            const last = thread.ranges[thread.ranges.length - 1]!
+            if (last.to === range.from) {
+                last.to = range.to
+            } else {
+                thread.ranges.push(range)
+            }
+        }
+        return false
+    })
+    return [...threads.values()]
+}
+
+// Lists the threads of the comments at the cursor, or within a non-empty selection. A cursor at
+// either edge of an anchor is outside of it.
+function activeCommentThreads(state: EditorState): string[] {
+    const {from, to, empty, $from} = state.selection
+    const threadIds = new Set<string>()
+    const collect = (marks: readonly ProseMirrorMark[]) => {
+        for (const mark of marks) {
+            if (mark.type.name === COMMENT) {
+                threadIds.add(String(mark.attrs.threadId))
+            }
+        }
+    }
+
+    if (empty) {
+        collect($from.marks().filter((mark) => $from.nodeAfter != null && mark.isInSet($from.nodeAfter.marks)))
+    } else {
+        state.doc.nodesBetween(from, to, (node) => {
+            collect(node.isInline ? node.marks : [])
+        })
+    }
+    return [...threadIds]
+}
+
+function updateThread(editor: Editor, threadId: string, resolved: boolean | null): boolean {
+    const {state} = editor
+    const type = state.schema.marks[COMMENT]!
+    const tr = state.tr
+    state.doc.descendants((node, position) => {
+        if (!node.isInline) {
+            return true
+        }
+
+        const mark = node.marks.find((candidate) => candidate.type === type && candidate.attrs.threadId === threadId)
+        if (mark != null) {
+            const to = position + node.nodeSize
+            tr.removeMark(position, to, mark)
+            if (resolved != null) {
+                tr.addMark(position, to, type.create({...mark.attrs, resolved}))
+            }
+        }
+        return false
+    })
+    if (!tr.docChanged) {
+        return false
+    }
+
+    editor.view.dispatch(tr)
+    return true
+}
+
+function createCommentsPlugin(
+    onThreads: (threads: CommentThread[]) => void,
+    onActive: (threadIds: string[]) => void,
+): Plugin {
+    return new Plugin({
+        key: commentsKey,
+        view: (view) => {
+            let lastThreads = JSON.stringify([])
+            let lastActive = JSON.stringify([])
+            const report = (state: EditorState) => {
+                const threads = collectCommentThreads(state.doc)
+                const serializedThreads = JSON.stringify(threads)
+                if (serializedThreads !== lastThreads) {
+                    lastThreads = serializedThreads
+                    onThreads(threads)
+                }
+
+                const active = activeCommentThreads(state)
+                const serializedActive = JSON.stringify(active)
+                if (serializedActive !== lastActive) {
+                    lastActive = serializedActive
+                    onActive(active)
+                }
+            }
+            report(view.state)
+
+            return {
+                update: (updated, previous) => {
+                    if (!updated.state.doc.eq(previous.doc) || !updated.state.selection.eq(previous.selection)) {
+                        report(updated.state)
+                    }
+                },
+            }
+        },
+    })
+}
+
+const descriptor: ExtensionDescriptor = {
+    name: "comments",
+    create: ({on_comments, on_active_comments}) => [
+        commentMark,
+        Extension.create({
+            name: "comments",
+            addProseMirrorPlugins: () => [
+                createCommentsPlugin(on_comments ?? (() => {}), on_active_comments ?? (() => {})),
+            ],
+        }),
+    ],
+    commands: {
+        add_comment: (editor, command) => {
+            if (editor.state.selection.empty) {
+                return false
+            }
+
+            const {from, to} = editor.state.selection
+            const type = editor.state.schema.marks[COMMENT]!
+            editor.view.dispatch(editor.state.tr.addMark(from, to, type.create({threadId: command.thread_id})))
+            return true
+        },
+        remove_comment: (editor, command) => updateThread(editor, command.thread_id, null),
+        set_comment_resolved: (editor, command) => updateThread(editor, command.thread_id, command.resolved),
+    },
+}
+
+export function register_comments(): void {
+    registerOfficialExtension(descriptor)
+}
+
+export const __testing = {
+    activeCommentThreads,
+    collectCommentThreads,
+}
@@ -1,18 +1,18 @@
 import {Heading} from "@tiptap/extension-heading"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "heading",
     create: () => Heading,
     commands: {
-        set_heading: (editor, command) =>
-            editor.chain().focus().setHeading({
+        set_heading: (editor, command, options) =>
+            commandChain(editor, options).setHeading({
                 level: command.level as 1 | 2 | 3 | 4 | 5 | 6,
             }).run(),
-        toggle_heading: (editor, command) =>
-            editor.chain().focus().toggleHeading({
+        toggle_heading: (editor, command, options) =>
+            commandChain(editor, options).toggleHeading({
                 level: command.level as 1 | 2 | 3 | 4 | 5 | 6,
             }).run(),
     },
@@ -1,7 +1,7 @@
 import {Highlight} from "@tiptap/extension-highlight"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 function buildHighlightAttributes(attributes?: { color?: string | null } | null) {
     if (attributes?.color == null) {
@@ -17,11 +17,11 @@ const descriptor: ExtensionDescriptor = {
     name: "highlight",
     create: () => Highlight,
     commands: {
-        set_highlight: (editor, command) =>
-            editor.chain().focus().setHighlight(buildHighlightAttributes(command.attributes)).run(),
-        toggle_highlight: (editor, command) =>
-            editor.chain().focus().toggleHighlight(buildHighlightAttributes(command.attributes)).run(),
-        unset_highlight: (editor) => editor.chain().focus().unsetHighlight().run(),
+        set_highlight: (editor, command, options) =>
+            commandChain(editor, options).setHighlight(buildHighlightAttributes(command.attributes)).run(),
+        toggle_highlight: (editor, command, options) =>
+            commandChain(editor, options).toggleHighlight(buildHighlightAttributes(command.attributes)).run(),
+        unset_highlight: (editor, _command, options) => commandChain(editor, options).unsetHighlight().run(),
     },
     ...activeState([
         ["highlight", (editor) => editor.isActive("highlight")],
@@ -1,14 +1,37 @@
 import {History} from "@tiptap/extension-history"
+import type {Editor} from "@tiptap/core"
+import type {Plugin} from "@tiptap/pm/state"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+
+// ProseMirror names plugin state fields after their key, and the history keeps its key private.
+function isHistoryPlugin(plugin: Plugin): boolean {
+    return (plugin as unknown as { key: string }).key === "history$"
+}
+
+// Reconfiguring without the history plugin drops its state, so adding it back starts an empty
+// history. The other plugins keep their state.
+function clearHistory(editor: Editor): boolean {
+    const {state} = editor
+    const plugins = state.plugins.filter((plugin) => !isHistoryPlugin(plugin))
+    if (plugins.length === state.plugins.length) {
+        return false
+    }
+
+    editor.view.updateState(state.reconfigure({plugins}).reconfigure({plugins: state.plugins}))
+    // Report the cleared history, as updating the state does not dispatch a transaction.
+    editor.view.dispatch(editor.state.tr.setMeta("addToHistory", false))
+    return true
+}
 
 const descriptor: ExtensionDescriptor = {
     name: "history",
     create: () => History,
     commands: {
-        undo: (editor) => editor.chain().focus().undo().run(),
-        redo: (editor) => editor.chain().focus().redo().run(),
+        undo: (editor, _command, options) => commandChain(editor, options).undo().run(),
+        redo: (editor, _command, options) => commandChain(editor, options).redo().run(),
+        clear_history: (editor) => clearHistory(editor),
     },
 }
 
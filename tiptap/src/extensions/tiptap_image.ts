@@ -1,7 +1,7 @@
 import {Image} from "@tiptap/extension-image"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 function buildImageAttributes(command: {
     src: string
@@ -19,8 +19,8 @@ const descriptor: ExtensionDescriptor = {
     name: "image",
     create: () => Image,
     commands: {
-        set_image: (editor, command) =>
-            editor.chain().focus().setImage(buildImageAttributes(command)).run(),
+        set_image: (editor, command, options) =>
+            commandChain(editor, options).setImage(buildImageAttributes(command)).run(),
     },
 }
 
@@ -1,15 +1,15 @@
 import {Italic} from "@tiptap/extension-italic"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "italic",
     create: () => Italic,
     commands: {
-        set_italic: (editor) => editor.chain().focus().setItalic().run(),
-        toggle_italic: (editor) => editor.chain().focus().toggleItalic().run(),
-        unset_italic: (editor) => editor.chain().focus().unsetItalic().run(),
+        set_italic: (editor, _command, options) => commandChain(editor, options).setItalic().run(),
+        toggle_italic: (editor, _command, options) => commandChain(editor, options).toggleItalic().run(),
+        unset_italic: (editor, _command, options) => commandChain(editor, options).unsetItalic().run(),
     },
     ...activeState([
         ["italic", (editor) => editor.isActive("italic")],
@@ -1,7 +1,7 @@
 import Link from "@tiptap/extension-link"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 function buildLinkAttributes(
     href: string,
@@ -26,15 +26,15 @@ const descriptor: ExtensionDescriptor = {
     name: "link",
     create: () => Link,
     commands: {
-        set_link: (editor, command) =>
-            editor.chain().focus().setLink(
+        set_link: (editor, command, options) =>
+            commandChain(editor, options).setLink(
                 buildLinkAttributes(command.href, command.target, command.rel, command.class),
             ).run(),
-        toggle_link: (editor, command) =>
-            editor.chain().focus().toggleLink(
+        toggle_link: (editor, command, options) =>
+            commandChain(editor, options).toggleLink(
                 buildLinkAttributes(command.href, command.target, command.rel, command.class),
             ).run(),
-        unset_link: (editor) => editor.chain().focus().unsetLink().run(),
+        unset_link: (editor, _command, options) => commandChain(editor, options).unsetLink().run(),
     },
     ...activeState([
         ["link", (editor) => editor.isActive("link")],
@@ -1,13 +1,13 @@
 import {OrderedList} from "@tiptap/extension-ordered-list"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "ordered_list",
     create: () => OrderedList,
     commands: {
-        toggle_ordered_list: (editor) => editor.chain().focus().toggleOrderedList().run(),
+        toggle_ordered_list: (editor, _command, options) => commandChain(editor, options).toggleOrderedList().run(),
     },
     ...activeState([
         ["ordered_list", (editor) => editor.isActive("orderedList")],
@@ -1,13 +1,13 @@
 import {Paragraph} from "@tiptap/extension-paragraph"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "paragraph",
     create: () => Paragraph,
     commands: {
-        set_paragraph: (editor) => editor.chain().focus().setParagraph().run(),
+        set_paragraph: (editor, _command, options) => commandChain(editor, options).setParagraph().run(),
     },
     ...activeState([
         ["paragraph", (editor) => editor.isActive("paragraph")],
@@ -1,15 +1,15 @@
 import {Strike} from "@tiptap/extension-strike"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const descriptor: ExtensionDescriptor = {
     name: "strike",
     create: () => Strike,
     commands: {
-        set_strike: (editor) => editor.chain().focus().setStrike().run(),
-        toggle_strike: (editor) => editor.chain().focus().toggleStrike().run(),
-        unset_strike: (editor) => editor.chain().focus().unsetStrike().run(),
+        set_strike: (editor, _command, options) => commandChain(editor, options).setStrike().run(),
+        toggle_strike: (editor, _command, options) => commandChain(editor, options).toggleStrike().run(),
+        unset_strike: (editor, _command, options) => commandChain(editor, options).unsetStrike().run(),
     },
     ...activeState([
         ["strike", (editor) => editor.isActive("strike")],
@@ -1,7 +1,7 @@
 import {TextAlign} from "@tiptap/extension-text-align"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 const allowedAlignments = ["left", "center", "right", "justify"] as const
 
@@ -12,15 +12,15 @@ const descriptor: ExtensionDescriptor = {
             types: ["heading", "paragraph"],
         }),
     commands: {
-        set_text_align: (editor, command) =>
+        set_text_align: (editor, command, options) =>
             allowedAlignments.includes(command.alignment)
-                ? editor.chain().focus().setTextAlign(command.alignment).run()
+                ? commandChain(editor, options).setTextAlign(command.alignment).run()
                 : false,
-        toggle_text_align: (editor, command) =>
+        toggle_text_align: (editor, command, options) =>
             allowedAlignments.includes(command.alignment)
-                ? editor.chain().focus().toggleTextAlign(command.alignment).run()
+                ? commandChain(editor, options).toggleTextAlign(command.alignment).run()
                 : false,
-        unset_text_align: (editor) => editor.chain().focus().unsetTextAlign().run(),
+        unset_text_align: (editor, _command, options) => commandChain(editor, options).unsetTextAlign().run(),
     },
     ...activeState([
         ["align_left", (editor) => editor.isActive({textAlign: "left"})],
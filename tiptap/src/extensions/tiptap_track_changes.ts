@@ -0,0 +1,331 @@
+import {Extension, Mark, mergeAttributes, type Editor} from "@tiptap/core"
+import {Fragment, Slice, type Mark as ProseMirrorMark, type MarkType, type Node as ProseMirrorNode} from "@tiptap/pm/model"
+import {Plugin, PluginKey, TextSelection, type EditorState, type Transaction} from "@tiptap/pm/state"
+import {Mapping, ReplaceStep} from "@tiptap/pm/transform"
+
+import {SET_CONTENT_META, type ExtensionDescriptor, type TrackedChange} from "../bridge_api.ts"
+import {registerOfficialExtension} from "../bridge_extension_helpers.ts"
+
+const INSERTION = "insertion"
+const DELETION = "deletion"
+
+type TrackChangesState = {
+    // The author changes are tracked for, or null while changes are not tracked.
+    author: string | null
+}
+
+type TrackChangesMeta =
+    | { kind: "configure"; author: string | null }
+    // Transactions that resolve changes, or record them, are not tracked themselves.
+    | { kind: "untracked" }
+
+const trackChangesKey = new PluginKey<TrackChangesState>("leptosTiptapTrackChanges")
+
+let nextChangeId = 1
+
+function createChangeId(): string {
+    const id = `${Date.now().toString(36)}-${nextChangeId.toString(36)}`
+    nextChangeId += 1
+    return id
+}
+
+function createChangeMark(name: string, tag: "ins" | "del") {
+    return Mark.create({
+        name,
+        // Text typed next to a change is a change of its own.
+        inclusive: false,
+        addAttributes: () => ({
+            id: {
+                default: null,
+                parseHTML: (element) => element.getAttribute("data-change-id"),
+                renderHTML: (attributes) => ({"data-change-id": attributes.id}),
+            },
+            author: {
+                default: "",
+                parseHTML: (element) => element.getAttribute("data-author") ?? "",
+                renderHTML: (attributes) => ({"data-author": attributes.author}),
+            },
+            timestamp: {
+                default: 0,
+                parseHTML: (element) => Number(element.getAttribute("data-timestamp") ?? 0),
+                renderHTML: (attributes) => ({"data-timestamp": String(attributes.timestamp)}),
+            },
+        }),
+        parseHTML: () => [{tag: `${tag}[data-change-id]`}],
+        renderHTML: ({HTMLAttributes}) => [tag, mergeAttributes({class: `tracked-${name}`}, HTMLAttributes), 0],
+    })
+}
+
+function changeMarkTypes(state: EditorState): { insertion: MarkType; deletion: MarkType } {
+    return {insertion: state.schema.marks[INSERTION]!, deletion: state.schema.marks[DELETION]!}
+}
+
+function isTracked(transaction: Transaction): boolean {
+    return transaction.docChanged
+        && transaction.getMeta(trackChangesKey) == null
+        // Replacing the whole document, as `set_content` does, is not an edit to review.
+        && transaction.getMeta(SET_CONTENT_META) == null
+        // Undo and redo, and steps received from collaborators, are not local edits.
+        && transaction.getMeta("history$") == null
+        && transaction.getMeta("collab$") == null
+        && transaction.getMeta("y-sync$") == null
+}
+
+// Marks the inline content of `fragment` as deleted. Own pending insertions are dropped instead,
+// and content that is already deleted keeps its change.
+function markDeleted(
+    fragment: Fragment,
+    author: string,
+    deletion: MarkType,
+    insertion: MarkType,
+    mark: () => ProseMirrorMark,
+): Fragment {
+    const nodes: ProseMirrorNode[] = []
+    fragment.forEach((node) => {
+        if (!node.isInline) {
+            nodes.push(node.copy(markDeleted(node.content, author, deletion, insertion, mark)))
+            return
+        }
+
+        if (node.marks.some((existing) => existing.type === insertion && existing.attrs.author === author)) {
+            return
+        }
+        nodes.push(deletion.isInSet(node.marks) != null ? node : node.mark(mark().addToSet(node.marks)))
+    })
+    return Fragment.fromArray(nodes)
+}
+
+// Turns the edits of `transactions` into tracked changes: inserted content is marked as an
+// insertion, and deleted content is restored and marked as a deletion.
+function trackTransactions(
+    transactions: readonly Transaction[],
+    oldState: EditorState,
+    newState: EditorState,
+    author: string,
+): Transaction | null {
+    const {insertion, deletion} = changeMarkTypes(newState)
+    const tr = newState.tr
+    const timestamp = Date.now()
+    // Maps positions after any step of `transactions` to the new state.
+    const mapping = new Mapping()
+    for (const transaction of transactions) {
+        for (const map of transaction.mapping.maps) {
+            mapping.appendMap(map)
+        }
+    }
+    const toCurrent = (stepIndex: number, position: number, assoc: number) =>
+        tr.mapping.map(mapping.slice(stepIndex + 1).map(position, assoc), assoc)
+
+    let stepIndex = -1
+    for (const transaction of transactions) {
+        transaction.steps.forEach((step, index) => {
+            stepIndex += 1
+            if (!isTracked(transaction) || !(step instanceof ReplaceStep)) {
+                return
+            }
+
+            const before = transaction.docs[index]!
+            const start = toCurrent(stepIndex, step.from, -1)
+            if (step.slice.size > 0) {
+                const end = toCurrent(stepIndex, step.from + step.slice.size, 1)
+                // Continue the insertion the new text is typed after, if it is an own one.
+                const previous = tr.doc.resolve(start).nodeBefore?.marks
+                    .find((mark) => mark.type === insertion && mark.attrs.author === author)
+                tr.removeMark(start, end, deletion)
+                tr.addMark(start, end, insertion.create({
+                    id: previous?.attrs.id ?? createChangeId(),
+                    author,
+                    timestamp,
+                }))
+            }
+
+            if (step.to > step.from) {
+                const deleted = before.slice(step.from, step.to)
+                const id = createChangeId()
+                const content = markDeleted(
+                    deleted.content,
+                    author,
+                    deletion,
+                    insertion,
+                    () => deletion.create({id, author, timestamp}),
+                )
+                if (content.size === 0) {
+                    return
+                }
+
+                try {
+                    tr.replace(start, start, new Slice(content, deleted.openStart, deleted.openEnd))
+                } catch {
+                    // The deleted content can not be marked here, e.g. in a code block, so the
+                    // deletion stays untracked.
+                    return
+                }
+                // Keep the cursor in front of text deleted backwards, so deleting continues there.
+                const {selection} = oldState
+                if (step.slice.size === 0 && selection.empty && selection.head === step.to) {
+                    tr.setSelection(TextSelection.create(tr.doc, start))
+                }
+            }
+        })
+    }
+
+    return tr.docChanged ? tr.setMeta(trackChangesKey, {kind: "untracked"} satisfies TrackChangesMeta) : null
+}
+
+// Accepts or rejects the changes with the given ids, or all changes without ids. Accepting keeps
+// insertions and removes deletions; rejecting does the opposite.
+function resolveChanges(state: EditorState, ids: Set<string> | null, accept: boolean): Transaction | null {
+    const {insertion, deletion} = changeMarkTypes(state)
+    const tr = state.tr
+    const removed: Array<{ from: number; to: number }> = []
+    state.doc.descendants((node, position) => {
+        if (!node.isInline) {
+            return true
+        }
+
+        for (const mark of node.marks) {
+            if ((mark.type !== insertion && mark.type !== deletion)
+                || (ids != null && !ids.has(String(mark.attrs.id)))) {
+                continue
+            }
+
+            if ((mark.type === insertion) === accept) {
+                tr.removeMark(position, position + node.nodeSize, mark)
+            } else {
+                removed.push({from: position, to: position + node.nodeSize})
+            }
+        }
+        return false
+    })
+    if (removed.length === 0 && !tr.docChanged) {
+        return null
+    }
+
+    // Removing marks keeps positions, and deleting from the end keeps the earlier ranges valid.
+    for (const {from, to} of removed.reverse()) {
+        tr.delete(tr.mapping.map(from), tr.mapping.map(to))
+    }
+    return tr.setMeta(trackChangesKey, {kind: "untracked"} satisfies TrackChangesMeta)
+}
+
+// Lists the pending changes in document order. A change spans from its first to its last
+// marked node.
+function collectTrackedChanges(doc: ProseMirrorNode): TrackedChange[] {
+    const changes = new Map<string, TrackedChange>()
+    doc.descendants((node, position) => {
+        if (!node.isInline) {
+            return true
+        }
+
+        for (const mark of node.marks) {
+            const kind = mark.type.name === INSERTION ? "insertion" : mark.type.name === DELETION ? "deletion" : null
+            if (kind == null) {
+                continue
+            }
+
+            const key = `${kind}:${String(mark.attrs.id)}`
+            const text = node.text ?? ""
+            const existing = changes.get(key)
+            if (existing != null) {
+                existing.range.to = position + node.nodeSize
+                existing.text += text
+                continue
+            }
+            changes.set(key, {
+                id: String(mark.attrs.id),
+                kind,
+                author: String(mark.attrs.author),
+                timestamp: Number(mark.attrs.timestamp),
+                range: {from: position, to: position + node.nodeSize},
+                text,
+            })
+        }
+        return false
+    })
+    return [...changes.values()]
+}
+
+function createTrackChangesPlugin(onChanges: (changes: TrackedChange[]) => void): Plugin<TrackChangesState> {
+    return new Plugin<TrackChangesState>({
+        key: trackChangesKey,
+        state: {
+            init: () => ({author: null}),
+            apply: (transaction, state) => {
+                const meta = transaction.getMeta(trackChangesKey) as TrackChangesMeta | undefined
+                return meta?.kind === "configure" ? {author: meta.author} : state
+            },
+        },
+        appendTransaction: (transactions, oldState, newState) => {
+            const author = trackChangesKey.getState(newState)?.author
+            return author == null ? null : trackTransactions(transactions, oldState, newState, author)
+        },
+        view: (view) => {
+            let last = JSON.stringify([])
+            const report = (doc: ProseMirrorNode) => {
+                const changes = collectTrackedChanges(doc)
+                const serialized = JSON.stringify(changes)
+                if (serialized !== last) {
+                    last = serialized
+                    onChanges(changes)
+                }
+            }
+            report(view.state.doc)
+
+            return {
+                update: (updated, previous) => {
+                    if (!updated.state.doc.eq(previous.doc)) {
+                        report(updated.state.doc)
+                    }
+                },
+            }
+        },
+    })
+}
+
+function dispatchResolved(editor: Editor, ids: Set<string> | null, accept: boolean): boolean {
+    const transaction = resolveChanges(editor.state, ids, accept)
+    if (transaction == null) {
+        return false
+    }
+
+    editor.view.dispatch(transaction)
+    return true
+}
+
+const descriptor: ExtensionDescriptor = {
+    name: "track_changes",
+    create: ({on_tracked_changes}) => [
+        createChangeMark(INSERTION, "ins"),
+        createChangeMark(DELETION, "del"),
+        Extension.create({
+            name: "trackChanges",
+            addProseMirrorPlugins: () => [createTrackChangesPlugin(on_tracked_changes ?? (() => {}))],
+        }),
+    ],
+    commands: {
+        set_track_changes: (editor, command) => {
+            editor.view.dispatch(
+                editor.state.tr
+                    .setMeta(trackChangesKey, {kind: "configure", author: command.author ?? null} satisfies TrackChangesMeta)
+                    .setMeta("addToHistory", false),
+            )
+            return true
+        },
+        accept_change: (editor, command) => dispatchResolved(editor, new Set([command.change_id]), true),
+        reject_change: (editor, command) => dispatchResolved(editor, new Set([command.change_id]), false),
+        accept_all_changes: (editor) => dispatchResolved(editor, null, true),
+        reject_all_changes: (editor) => dispatchResolved(editor, null, false),
+    },
+}
+
+export function register_track_changes(): void {
+    registerOfficialExtension(descriptor)
+}
+
+export const __testing = {
+    collectTrackedChanges,
+    createChangeMark,
+    createTrackChangesPlugin,
+    resolveChanges,
+    trackChangesKey,
+}
@@ -1,7 +1,7 @@
 import {Youtube} from "@tiptap/extension-youtube"
 
 import type {ExtensionDescriptor} from "../bridge_api.ts"
-import {activeState, registerOfficialExtension} from "../bridge_extension_helpers.ts"
+import {activeState, commandChain, registerOfficialExtension} from "../bridge_extension_helpers.ts"
 
 function buildYoutubeAttributes(command: {
     src: string
@@ -28,8 +28,8 @@ const descriptor: ExtensionDescriptor = {
     name: "youtube",
     create: () => Youtube,
     commands: {
-        set_youtube_video: (editor, command) =>
-            editor.chain().focus().setYoutubeVideo(buildYoutubeAttributes(command)).run(),
+        set_youtube_video: (editor, command, options) =>
+            commandChain(editor, options).setYoutubeVideo(buildYoutubeAttributes(command)).run(),
     },
     ...activeState([
         ["youtube", (editor) => editor.isActive("youtube")],